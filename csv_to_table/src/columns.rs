@@ -0,0 +1,114 @@
+use csv::StringRecord;
+
+use crate::Error;
+
+/// Selects, reorders and renames columns based on a header (a first record).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Projection {
+    select: Vec<String>,
+    exclude: Vec<String>,
+    rename: Vec<(String, String)>,
+    indexes: Option<Vec<usize>>,
+    is_header_seen: bool,
+}
+
+impl Projection {
+    pub(crate) fn new(
+        select: Vec<String>,
+        exclude: Vec<String>,
+        rename: Vec<(String, String)>,
+    ) -> Self {
+        Self {
+            select,
+            exclude,
+            rename,
+            indexes: None,
+            is_header_seen: false,
+        }
+    }
+
+    /// Projects a record.
+    ///
+    /// It fails on a header (a first record) if any given name is not present in it.
+    pub(crate) fn apply(&mut self, record: StringRecord) -> Result<StringRecord, Error> {
+        if self.is_header_seen {
+            return Ok(self.project(record));
+        }
+
+        self.is_header_seen = true;
+
+        let unknown = self.find_unknown(&record);
+        if !unknown.is_empty() {
+            return Err(Error::from_unknown_columns(unknown));
+        }
+
+        self.indexes = self.find_indexes(&record);
+
+        let header = self.project(record);
+        if self.rename.is_empty() {
+            return Ok(header);
+        }
+
+        let header = header
+            .iter()
+            .map(|name| {
+                self.rename
+                    .iter()
+                    .find(|(from, _)| from == name)
+                    .map_or(name, |(_, to)| to.as_str())
+            })
+            .collect();
+
+        Ok(header)
+    }
+
+    fn find_unknown(&self, header: &StringRecord) -> Vec<String> {
+        let names = self
+            .select
+            .iter()
+            .chain(&self.exclude)
+            .chain(self.rename.iter().map(|(from, _)| from));
+
+        let mut unknown: Vec<String> = Vec::new();
+        for name in names {
+            let is_known = header.iter().any(|column| column == name);
+            if !is_known && !unknown.contains(name) {
+                unknown.push(name.clone());
+            }
+        }
+
+        unknown
+    }
+
+    fn project(&self, record: StringRecord) -> StringRecord {
+        match &self.indexes {
+            Some(indexes) => indexes
+                .iter()
+                .map(|&i| record.get(i).unwrap_or_default())
+                .collect(),
+            None => record,
+        }
+    }
+
+    fn find_indexes(&self, header: &StringRecord) -> Option<Vec<usize>> {
+        if self.select.is_empty() && self.exclude.is_empty() {
+            return None;
+        }
+
+        let mut indexes = if self.select.is_empty() {
+            (0..header.len()).collect::<Vec<_>>()
+        } else {
+            self.select
+                .iter()
+                .filter_map(|name| header.iter().position(|column| column == name))
+                .collect()
+        };
+
+        indexes.retain(|&i| {
+            let name = &header[i];
+            !self.exclude.iter().any(|excluded| excluded == name)
+        });
+
+        Some(indexes)
+    }
+}
@@ -0,0 +1,336 @@
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+
+use csv::ReaderBuilder;
+
+use crate::ColumnType;
+
+/// A csv format description.
+///
+/// It can be set explicitly or detected from a sample by [`Dialect::sniff`].
+///
+/// # Example
+///
+/// ```
+/// use csv_to_table::Dialect;
+///
+/// let csv = "name;year\n\
+///            C;1972\n\
+///            Rust;2010";
+///
+/// let dialect = Dialect::sniff(csv.as_bytes());
+///
+/// assert_eq!(dialect.get_delimiter(), b';');
+/// assert!(dialect.has_header());
+/// assert!(!dialect.is_flexible());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dialect {
+    delimiter: u8,
+    quote: u8,
+    has_header: bool,
+    flexible: bool,
+}
+
+/// Delimiters which are considered by [`Dialect::sniff`] in order of a priority.
+const DELIMITERS: [u8; 4] = [b',', b';', b'\t', b'|'];
+
+impl Dialect {
+    /// Creates a default dialect,
+    /// which is comma separated, `"` quoted with a header and a fixed number of fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Detects a dialect out of a sample of a csv.
+    ///
+    /// The delimiter is chosen among `,` `;` `\t` `|`
+    /// as the one which gives the most consistent number of fields per line.
+    pub fn sniff(sample: &[u8]) -> Self {
+        let quote = sniff_quote(sample);
+
+        let mut best = None;
+        for delimiter in DELIMITERS {
+            let counts = count_fields(sample, delimiter, quote);
+            let (mode, frequency) = mode(&counts);
+            if mode < 2 {
+                continue;
+            }
+
+            let is_better = !matches!(&best, Some((_, best, _)) if *best >= frequency);
+            if is_better {
+                best = Some((delimiter, frequency, counts));
+            }
+        }
+
+        let (delimiter, counts) = match best {
+            Some((delimiter, _, counts)) => (delimiter, counts),
+            None => (b',', count_fields(sample, b',', quote)),
+        };
+
+        let flexible = counts.windows(2).any(|w| w[0] != w[1]);
+
+        let mut dialect = Self {
+            delimiter,
+            quote,
+            has_header: true,
+            flexible,
+        };
+
+        dialect.has_header = sniff_header(sample, &dialect);
+
+        dialect
+    }
+
+    /// Set a field delimiter.
+    pub fn set_delimiter(&mut self, delimiter: u8) {
+        self.delimiter = delimiter;
+    }
+
+    /// Set a quote character.
+    pub fn set_quote(&mut self, quote: u8) {
+        self.quote = quote;
+    }
+
+    /// Set whether a first row is a header.
+    pub fn set_header(&mut self, on: bool) {
+        self.has_header = on;
+    }
+
+    /// Set whether rows may have a different number of fields.
+    pub fn set_flexible(&mut self, on: bool) {
+        self.flexible = on;
+    }
+
+    /// Returns a field delimiter.
+    pub fn get_delimiter(&self) -> u8 {
+        self.delimiter
+    }
+
+    /// Returns a quote character.
+    pub fn get_quote(&self) -> u8 {
+        self.quote
+    }
+
+    /// Verifies whether a first row is a header.
+    pub fn has_header(&self) -> bool {
+        self.has_header
+    }
+
+    /// Verifies whether rows may have a different number of fields.
+    pub fn is_flexible(&self) -> bool {
+        self.flexible
+    }
+
+    /// Creates a [`ReaderBuilder`] configured by the dialect.
+    ///
+    /// Notice that a header is always read as a regular record,
+    /// so it's shown in a table.
+    pub fn reader_builder(&self) -> ReaderBuilder {
+        let mut builder = ReaderBuilder::new();
+        builder
+            .has_headers(false)
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .flexible(self.flexible);
+
+        builder
+    }
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            has_header: true,
+            flexible: false,
+        }
+    }
+}
+
+/// A [`Read`]er which returns bytes inspected by a sniffer before the rest of an underlying reader.
+#[derive(Debug)]
+pub struct PeekReader<R> {
+    head: Cursor<Vec<u8>>,
+    err: Option<io::Error>,
+    reader: R,
+}
+
+impl<R> PeekReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            head: Cursor::new(Vec::new()),
+            err: None,
+            reader,
+        }
+    }
+
+    /// Returns a sample of a given size which was read from an underlying reader.
+    pub(crate) fn peek(&mut self, size: usize) -> &[u8]
+    where
+        R: Read,
+    {
+        let mut buf = Vec::with_capacity(size);
+        if let Err(err) = self.reader.by_ref().take(size as u64).read_to_end(&mut buf) {
+            // the error will be returned by a first read
+            self.err = Some(err);
+        }
+
+        self.head = Cursor::new(buf);
+
+        let sample = self.head.get_ref();
+        if sample.len() < size {
+            return sample;
+        }
+
+        // drop the last line as it's likely cut
+        match sample.iter().rposition(|&b| b == b'\n') {
+            Some(i) => &sample[..i],
+            None => sample,
+        }
+    }
+}
+
+impl<R> Read for PeekReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(err) = self.err.take() {
+            return Err(err);
+        }
+
+        let n = self.head.read(buf)?;
+        if n > 0 || buf.is_empty() {
+            return Ok(n);
+        }
+
+        self.reader.read(buf)
+    }
+}
+
+/// Reads a sample of a given size from a seekable reader and rewinds it back.
+pub(crate) fn sniff_seekable<R>(reader: &mut R, size: usize) -> io::Result<Dialect>
+where
+    R: Read + Seek,
+{
+    let mut sample = Vec::with_capacity(size);
+    reader.by_ref().take(size as u64).read_to_end(&mut sample)?;
+    reader.seek(SeekFrom::Start(0))?;
+
+    if sample.len() == size {
+        if let Some(i) = sample.iter().rposition(|&b| b == b'\n') {
+            sample.truncate(i);
+        }
+    }
+
+    Ok(Dialect::sniff(&sample))
+}
+
+fn sniff_quote(sample: &[u8]) -> u8 {
+    // count quotes which start a field
+    let mut double = 0;
+    let mut single = 0;
+    let mut prev = b'\n';
+    for &b in sample {
+        let is_field_start = prev == b'\n' || DELIMITERS.contains(&prev);
+        if is_field_start && b == b'"' {
+            double += 1;
+        }
+
+        if is_field_start && b == b'\'' {
+            single += 1;
+        }
+
+        prev = b;
+    }
+
+    if single > double {
+        b'\''
+    } else {
+        b'"'
+    }
+}
+
+fn count_fields(sample: &[u8], delimiter: u8, quote: u8) -> Vec<usize> {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .quote(quote)
+        .from_reader(sample);
+
+    reader
+        .byte_records()
+        .filter_map(Result::ok)
+        .map(|record| record.len())
+        .collect()
+}
+
+// Returns the most common value and its frequency.
+fn mode(list: &[usize]) -> (usize, usize) {
+    let mut mode = (0, 0);
+    for &value in list {
+        let frequency = list.iter().filter(|&&v| v == value).count();
+        if frequency > mode.1 || (frequency == mode.1 && value > mode.0) {
+            mode = (value, frequency);
+        }
+    }
+
+    mode
+}
+
+// A header is considered present if its cells have a different type than the rest of a column,
+// or in case all columns are textual, if its cells are distinct and non empty.
+fn sniff_header(sample: &[u8], dialect: &Dialect) -> bool {
+    let mut builder = dialect.reader_builder();
+    builder.flexible(true);
+
+    let rows = builder
+        .from_reader(sample)
+        .into_records()
+        .filter_map(Result::ok)
+        .collect::<Vec<_>>();
+
+    let (header, body) = match rows.split_first() {
+        Some((header, body)) if !body.is_empty() => (header, body),
+        _ => return true,
+    };
+
+    let mut votes = 0isize;
+    for (col, name) in header.iter().enumerate() {
+        let kind = body
+            .iter()
+            .filter_map(|row| row.get(col))
+            .filter_map(ColumnType::detect)
+            .reduce(ColumnType::merge);
+
+        let kind = match kind {
+            Some(ColumnType::Text) | None => continue,
+            Some(kind) => kind,
+        };
+
+        let header_kind = ColumnType::detect(name);
+        let is_same_kind = header_kind == Some(kind)
+            || (kind.is_numeric() && matches!(header_kind, Some(k) if k.is_numeric()));
+
+        if is_same_kind {
+            votes -= 1;
+        } else {
+            votes += 1;
+        }
+    }
+
+    if votes != 0 {
+        return votes > 0;
+    }
+
+    let names = header.iter().map(str::trim).collect::<Vec<_>>();
+    let is_distinct = names
+        .iter()
+        .enumerate()
+        .all(|(i, name)| !names[..i].contains(name));
+    let is_filled = names.iter().all(|name| !name.is_empty());
+
+    is_distinct && is_filled
+}
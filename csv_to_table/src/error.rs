@@ -0,0 +1,174 @@
+use std::{fmt, io};
+
+use csv::{ErrorKind, Position};
+
+/// An error which may occur while reading a csv.
+///
+/// Unlike a bare [`csv::Error`] it gives an easy access to a position of a malformed record.
+///
+/// # Example
+///
+/// ```
+/// use csv_to_table::{ErrorMode, Options};
+///
+/// let csv = "name,year\n\
+///            C,1972\n\
+///            Rust,2010,Graydon Hoare";
+///
+/// let options = Options::new().on_error(ErrorMode::Strict);
+/// let err = csv_to_table::from_reader_with(csv.as_bytes(), options).unwrap_err();
+///
+/// assert_eq!(err.line(), Some(3));
+/// assert_eq!(err.byte(), Some(17));
+/// assert_eq!(err.message(), "found 3 fields, expected 2");
+/// assert_eq!(err.to_string(), "line 3 (byte 17): found 3 fields, expected 2");
+/// ```
+#[derive(Debug)]
+pub struct Error {
+    inner: csv::Error,
+    unknown_columns: Vec<String>,
+}
+
+impl Error {
+    /// Creates an error of columns which are not present in a header.
+    pub(crate) fn from_unknown_columns(columns: Vec<String>) -> Self {
+        let message = format!("unknown columns: {}", columns.join(", "));
+        let inner = csv::Error::from(io::Error::new(io::ErrorKind::InvalidInput, message));
+
+        Self {
+            inner,
+            unknown_columns: columns,
+        }
+    }
+
+    /// Returns a position of a malformed record if it's known.
+    pub fn position(&self) -> Option<&Position> {
+        self.inner.position()
+    }
+
+    /// Returns a line number (starting from 1) of a malformed record if it's known.
+    pub fn line(&self) -> Option<u64> {
+        self.position().map(|pos| pos.line())
+    }
+
+    /// Returns a byte offset of a malformed record if it's known.
+    pub fn byte(&self) -> Option<u64> {
+        self.position().map(|pos| pos.byte())
+    }
+
+    /// Returns an index (starting from 0) of a malformed record if it's known.
+    pub fn record(&self) -> Option<u64> {
+        self.position().map(|pos| pos.record())
+    }
+
+    /// Returns names of columns which are given to [`Options`] but are not present in a header.
+    ///
+    /// ```
+    /// use csv_to_table::Options;
+    ///
+    /// let csv = "name,year\n\
+    ///            C,1972";
+    ///
+    /// let options = Options::new().select(["name", "designed_by"]);
+    /// let err = csv_to_table::from_reader_with(csv.as_bytes(), options).unwrap_err();
+    ///
+    /// assert_eq!(err.unknown_columns(), ["designed_by"]);
+    /// assert_eq!(err.to_string(), "unknown columns: designed_by");
+    /// ```
+    ///
+    /// [`Options`]: crate::Options
+    pub fn unknown_columns(&self) -> &[String] {
+        &self.unknown_columns
+    }
+
+    /// Returns a kind of an error.
+    pub fn kind(&self) -> &ErrorKind {
+        self.inner.kind()
+    }
+
+    /// Returns a description of an error without a position.
+    pub fn message(&self) -> String {
+        match self.inner.kind() {
+            ErrorKind::UnequalLengths {
+                expected_len, len, ..
+            } => format!("found {} fields, expected {}", len, expected_len),
+            ErrorKind::Utf8 { err, .. } => {
+                format!("invalid UTF-8 in field {}: {}", err.field() + 1, err)
+            }
+            ErrorKind::Io(err) => err.to_string(),
+            _ => self.inner.to_string(),
+        }
+    }
+
+    /// Returns an underlying [`csv::Error`].
+    pub fn into_inner(self) -> csv::Error {
+        self.inner
+    }
+}
+
+impl From<csv::Error> for Error {
+    fn from(inner: csv::Error) -> Self {
+        Self {
+            inner,
+            unknown_columns: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position() {
+            Some(pos) => write!(
+                f,
+                "line {} (byte {}): {}",
+                pos.line(),
+                pos.byte(),
+                self.message()
+            ),
+            None => self.message().fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+/// Creates a row which shows an error in its first cell.
+pub(crate) fn error_row(err: &Error, count_columns: usize) -> Vec<String> {
+    let text = match err.line() {
+        Some(line) => format!("line {}: {}", line, err.message()),
+        None => err.message(),
+    };
+
+    highlighted_row(text, count_columns)
+}
+
+/// Creates a row which lists all malformed lines.
+pub(crate) fn summary_row(lines: &[Option<u64>], count_columns: usize) -> Vec<String> {
+    let lines = lines
+        .iter()
+        .map(|line| line.map_or_else(|| String::from("?"), |line| line.to_string()))
+        .collect::<Vec<_>>();
+
+    let text = format!(
+        "skipped {} malformed {}: {}",
+        lines.len(),
+        if lines.len() == 1 { "line" } else { "lines" },
+        lines.join(", ")
+    );
+
+    highlighted_row(text, count_columns)
+}
+
+fn highlighted_row(text: String, count_columns: usize) -> Vec<String> {
+    #[cfg(feature = "ansi")]
+    let text = format!("\u{1b}[31m{}\u{1b}[39m", text);
+
+    let mut row = vec![String::new(); count_columns.max(1)];
+    row[0] = text;
+
+    row
+}
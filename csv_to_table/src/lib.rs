@@ -55,19 +55,38 @@
 //! assert_eq!(table, expected);
 //! ```
 
+mod columns;
+mod dialect;
+mod error;
+mod options;
 mod records;
+mod types;
 
-use std::{io::Read, path::Path};
+use std::{fs::File, io::Read, path::Path};
 
 use csv::{Reader, ReaderBuilder};
-use tabled::{builder::Builder, tables::IterTable, Table};
+use tabled::{
+    builder::Builder,
+    settings::{object::Columns, Alignment, Span},
+    tables::IterTable,
+    Table,
+};
+
+use dialect::{sniff_seekable, PeekReader};
+use error::{error_row, summary_row};
+use types::{format_rows, infer_columns};
+
+pub use dialect::Dialect;
+pub use error::Error;
+pub use options::{ErrorMode, Options};
+pub use types::ColumnType;
 
 pub mod iter {
     //! The module contains [`CsvRecords`] which is an [`Iterator`] abstraction for [`IterTable`].
 
     use super::*;
-    use std::fs::File;
 
+    pub use super::dialect::PeekReader;
     pub use super::records::*;
 
     /// Creates [`IterTable`] from a csv [`Read`]er.
@@ -105,6 +124,45 @@ pub mod iter {
         IterTable::new(CsvRecords::new(rdr))
     }
 
+    /// Creates [`IterTable`] from a csv [`Read`]er using a given [`Options`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use csv_to_table::{iter::from_reader_with, Options};
+    ///
+    /// let csv = r#"Name,Number
+    /// Maxim,12345
+    /// Alex,678"#;
+    ///
+    /// let table = from_reader_with(csv.as_bytes(), Options::new().infer_types(100));
+    ///
+    /// let table = table.to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-------+--------+\n\
+    ///      | Name  | Number |\n\
+    ///      +-------+--------+\n\
+    ///      | Maxim |  12345 |\n\
+    ///      +-------+--------+\n\
+    ///      | Alex  |    678 |\n\
+    ///      +-------+--------+",
+    /// );
+    /// ```
+    pub fn from_reader_with<R>(
+        reader: R,
+        mut options: Options,
+    ) -> IterTable<CsvRecords<PeekReader<R>>>
+    where
+        R: Read,
+    {
+        let reader = peek_reader(reader, &mut options);
+        let rdr = options.get_dialect().reader_builder().from_reader(reader);
+
+        IterTable::new(CsvRecords::with_options(rdr, options))
+    }
+
     /// Creates [`IterTable`] from a [`File`] which suppose to have a csv.
     ///
     /// # Example
@@ -126,6 +184,32 @@ pub mod iter {
         Ok(table)
     }
 
+    /// Creates [`IterTable`] from a [`File`] which suppose to have a csv using a given [`Options`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use csv_to_table::{iter::from_path_with, Options};
+    ///
+    /// let table = from_path_with("path/to/a/file", Options::new().infer_types(100))
+    ///     .expect("success read");
+    /// let table = table.to_string();
+    /// ```
+    pub fn from_path_with<P>(
+        path: P,
+        mut options: Options,
+    ) -> Result<IterTable<CsvRecords<File>>, csv::Error>
+    where
+        P: AsRef<Path>,
+    {
+        let file = open_file(path, &mut options)?;
+        let rdr = options.get_dialect().reader_builder().from_reader(file);
+
+        let table = IterTable::new(CsvRecords::with_options(rdr, options));
+
+        Ok(table)
+    }
+
     /// Creates [`IterTable`] from a [`csv::Reader`].
     ///
     /// # Example
@@ -170,7 +254,42 @@ where
 {
     let rdr = ReaderBuilder::new().has_headers(false).from_reader(reader);
 
-    read_into_table(rdr)
+    read_into_table(rdr, &Options::default()).map_err(Error::into_inner)
+}
+
+/// Creates [`Table`] from [`Read`]er using a given [`Options`].
+///
+/// # Example
+///
+/// ```
+/// use csv_to_table::{from_reader_with, Options};
+///
+/// let csv = "name,price,available\n\
+///            Tea,\"1,250\",true\n\
+///            Coffee,+3.5,false";
+///
+/// let options = Options::new().infer_types(100).normalize_numbers(true);
+/// let table = from_reader_with(csv.as_bytes(), options).unwrap();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+--------+-----------+\n\
+///      | name   |  price | available |\n\
+///      +--------+--------+-----------+\n\
+///      | Tea    | 1250.0 | true      |\n\
+///      +--------+--------+-----------+\n\
+///      | Coffee |    3.5 | false     |\n\
+///      +--------+--------+-----------+",
+/// );
+/// ```
+pub fn from_reader_with<R>(reader: R, mut options: Options) -> Result<Table, Error>
+where
+    R: Read,
+{
+    let reader = peek_reader(reader, &mut options);
+    let rdr = options.get_dialect().reader_builder().from_reader(reader);
+
+    read_into_table(rdr, &options)
 }
 
 /// Creates [`Table`] from a csv [`File`].
@@ -193,7 +312,30 @@ where
 {
     let rdr = ReaderBuilder::new().has_headers(false).from_path(path)?;
 
-    read_into_table(rdr)
+    read_into_table(rdr, &Options::default()).map_err(Error::into_inner)
+}
+
+/// Creates [`Table`] from a csv [`File`] using a given [`Options`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use csv_to_table::{from_path_with, Options};
+///
+/// let table = from_path_with("path/to/a/file", Options::new().infer_types(100))
+///     .expect("success read");
+/// let table = table.to_string();
+/// ```
+///
+/// [`File`]: std::fs::File
+pub fn from_path_with<P>(path: P, mut options: Options) -> Result<Table, Error>
+where
+    P: AsRef<Path>,
+{
+    let file = open_file(path, &mut options)?;
+    let rdr = options.get_dialect().reader_builder().from_reader(file);
+
+    read_into_table(rdr, &options)
 }
 
 /// Creates [`Table`] from a [`csv::Reader`].
@@ -215,22 +357,100 @@ pub fn from_csv_reader<R>(reader: Reader<R>) -> Result<Table, csv::Error>
 where
     R: Read,
 {
-    read_into_table(reader)
+    read_into_table(reader, &Options::default()).map_err(Error::into_inner)
+}
+
+fn peek_reader<R>(reader: R, options: &mut Options) -> PeekReader<R>
+where
+    R: Read,
+{
+    let mut reader = PeekReader::new(reader);
+    if let Some(size) = options.get_sniff() {
+        let dialect = Dialect::sniff(reader.peek(size));
+        options.set_sniffed(dialect);
+    }
+
+    reader
+}
+
+fn open_file<P>(path: P, options: &mut Options) -> Result<File, csv::Error>
+where
+    P: AsRef<Path>,
+{
+    let mut file = File::open(path)?;
+    if let Some(size) = options.get_sniff() {
+        let dialect = sniff_seekable(&mut file, size)?;
+        options.set_sniffed(dialect);
+    }
+
+    Ok(file)
 }
 
-fn read_into_table<R>(reader: Reader<R>) -> Result<Table, csv::Error>
+fn read_into_table<R>(reader: Reader<R>, options: &Options) -> Result<Table, Error>
 where
     R: Read,
 {
-    let mut builder = Builder::default();
+    let mode = options.error_mode(ErrorMode::Strict);
+    let mut projection = options.projection();
 
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
     for record in reader.into_records() {
-        let record = record?;
-        let iter = record.iter().map(|s| s.to_owned());
-        builder.push_record(iter);
+        let mut record = match record {
+            Ok(record) => record,
+            Err(err) => match mode {
+                ErrorMode::Skip => continue,
+                ErrorMode::Show => {
+                    errors.push((rows.len(), Error::from(err)));
+                    continue;
+                }
+                ErrorMode::Strict => return Err(Error::from(err)),
+            },
+        };
+
+        if let Some(projection) = &mut projection {
+            record = projection.apply(record)?;
+        }
+
+        let row = record.iter().map(|s| s.to_owned()).collect::<Vec<_>>();
+        rows.push(row);
     }
 
-    let table = builder.build();
+    let mut columns = Vec::new();
+    if let Some(types) = options.type_options() {
+        let sample = types.sample.min(rows.len());
+        let has_header = options.get_dialect().has_header();
+        columns = infer_columns(&rows[..sample], has_header);
+        format_rows(&mut rows, &columns, &types, has_header);
+    }
+
+    let count_columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let mut error_rows = Vec::with_capacity(errors.len() + 1);
+    if !errors.is_empty() {
+        let lines = errors.iter().map(|(_, err)| err.line()).collect::<Vec<_>>();
+
+        for (i, (row, err)) in errors.iter().enumerate().rev() {
+            rows.insert(*row, error_row(err, count_columns));
+            error_rows.push(row + i);
+        }
+
+        rows.push(summary_row(&lines, count_columns));
+        error_rows.push(rows.len() - 1);
+    }
+
+    let mut table = Builder::from(rows).build();
+
+    for (i, column) in columns.iter().enumerate() {
+        if column.kind().is_numeric() {
+            table.modify(Columns::single(i), Alignment::right());
+        }
+    }
+
+    for row in error_rows {
+        table.modify((row, 0), Alignment::left());
+        table.modify((row, 0), Span::column(count_columns));
+    }
 
     Ok(table)
 }
@@ -0,0 +1,302 @@
+use crate::{columns::Projection, types::TypeOptions, Dialect};
+
+/// Options which control how a csv is turned into a table.
+///
+/// It's used by [`from_reader_with`] and [`iter::from_reader_with`].
+///
+/// # Example
+///
+/// ```
+/// use csv_to_table::Options;
+///
+/// let csv = "name,year\n\
+///            C,1972\n\
+///            Rust,2010";
+///
+/// let options = Options::new().infer_types(100);
+/// let table = csv_to_table::from_reader_with(csv.as_bytes(), options).unwrap();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+------+------+\n\
+///      | name | year |\n\
+///      +------+------+\n\
+///      | C    | 1972 |\n\
+///      +------+------+\n\
+///      | Rust | 2010 |\n\
+///      +------+------+",
+/// );
+/// ```
+///
+/// [`from_reader_with`]: crate::from_reader_with
+/// [`iter::from_reader_with`]: crate::iter::from_reader_with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    sample: Option<usize>,
+    normalize_numbers: bool,
+    #[cfg(feature = "ansi")]
+    colorize: bool,
+    select: Vec<String>,
+    exclude: Vec<String>,
+    rename: Vec<(String, String)>,
+    errors: Option<ErrorMode>,
+    dialect: Option<Dialect>,
+    sniff: Option<usize>,
+}
+
+/// Defines what to do with malformed records (e.g. ones with a wrong number of fields).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorMode {
+    /// Silently skip malformed records.
+    ///
+    /// It's a default for [`iter`] functions.
+    ///
+    /// [`iter`]: crate::iter
+    Skip,
+    /// Show an error row in place of a malformed record,
+    /// with a line number and a message spanning the row,
+    /// plus a summary row listing all malformed lines at the end.
+    ///
+    /// [`IterTable`] can't span cells,
+    /// so [`iter`] functions put a message into the first cell of the row.
+    ///
+    /// With `ansi` feature the rows are highlighted in red.
+    ///
+    /// [`IterTable`]: tabled::tables::IterTable
+    Show,
+    /// Stop on a first malformed record.
+    ///
+    /// In memory functions return an [`Error`] with a position of the record,
+    /// it's a default for them.
+    /// While [`iter`] functions stop rendering at the record with a final error row,
+    /// so a table is never cut silently.
+    /// The error itself is available via [`CsvRecordsIter::status`],
+    /// but only until the records are wrapped into an [`IterTable`].
+    ///
+    /// [`CsvRecordsIter::status`]: crate::iter::CsvRecordsIter::status
+    ///
+    /// [`Error`]: crate::Error
+    /// [`iter`]: crate::iter
+    Strict,
+}
+
+impl Options {
+    /// Creates default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show only given columns in a given order.
+    ///
+    /// Columns are found by a name in a header (a first row).
+    /// Names which are not present in a header cause an error,
+    /// see [`Error::unknown_columns`].
+    /// If a [`Dialect`] has no header, the selection is ignored and all columns are shown.
+    ///
+    /// ```
+    /// use csv_to_table::Options;
+    ///
+    /// let csv = "name,designed_by,invented_year\n\
+    ///            C,Dennis Ritchie,1972\n\
+    ///            Rust,Graydon Hoare,2010";
+    ///
+    /// let options = Options::new()
+    ///     .select(["invented_year", "name"])
+    ///     .rename("invented_year", "year");
+    ///
+    /// let table = csv_to_table::from_reader_with(csv.as_bytes(), options).unwrap();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+------+\n\
+    ///      | year | name |\n\
+    ///      +------+------+\n\
+    ///      | 1972 | C    |\n\
+    ///      +------+------+\n\
+    ///      | 2010 | Rust |\n\
+    ///      +------+------+",
+    /// );
+    /// ```
+    ///
+    /// [`Error::unknown_columns`]: crate::Error::unknown_columns
+    pub fn select<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.select = columns.into_iter().map(Into::into).collect();
+
+        self
+    }
+
+    /// Hide given columns.
+    ///
+    /// Columns are found by a name in a header (a first row),
+    /// names which are not present in it cause an error.
+    /// If a [`Dialect`] has no header, nothing is hidden.
+    pub fn exclude<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.exclude = columns.into_iter().map(Into::into).collect();
+
+        self
+    }
+
+    /// Rename a column in a header (a first row).
+    ///
+    /// A column is found by its original name,
+    /// a name which is not present in a header causes an error.
+    /// If a [`Dialect`] has no header, nothing is renamed.
+    pub fn rename<F, T>(mut self, from: F, to: T) -> Self
+    where
+        F: Into<String>,
+        T: Into<String>,
+    {
+        self.rename.push((from.into(), to.into()));
+
+        self
+    }
+
+    /// Set a csv format explicitly.
+    pub fn dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = Some(dialect);
+
+        self
+    }
+
+    /// Detect a csv format ([`Dialect`]) by inspecting a given number of first bytes.
+    ///
+    /// It overrides [`Options::dialect`].
+    ///
+    /// ```
+    /// use csv_to_table::Options;
+    ///
+    /// let csv = "name;designed_by;invented_year\n\
+    ///            C;Dennis Ritchie;1972\n\
+    ///            Rust;Graydon Hoare;2010";
+    ///
+    /// let options = Options::new().sniff(1024);
+    /// let table = csv_to_table::from_reader_with(csv.as_bytes(), options).unwrap();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+----------------+---------------+\n\
+    ///      | name | designed_by    | invented_year |\n\
+    ///      +------+----------------+---------------+\n\
+    ///      | C    | Dennis Ritchie | 1972          |\n\
+    ///      +------+----------------+---------------+\n\
+    ///      | Rust | Graydon Hoare  | 2010          |\n\
+    ///      +------+----------------+---------------+",
+    /// );
+    /// ```
+    pub fn sniff(mut self, bytes: usize) -> Self {
+        self.sniff = Some(bytes);
+
+        self
+    }
+
+    /// Set a way malformed records are handled.
+    ///
+    /// ```
+    /// use csv_to_table::{ErrorMode, Options};
+    ///
+    /// let csv = "name,year\n\
+    ///            C,1972\n\
+    ///            Rust,2010,Graydon Hoare\n\
+    ///            Go,2009";
+    ///
+    /// let options = Options::new().on_error(ErrorMode::Show);
+    /// let table = csv_to_table::from_reader_with(csv.as_bytes(), options).unwrap();
+    /// let table = table.to_string();
+    ///
+    /// assert!(table.contains("line 3: found 3 fields, expected 2"));
+    /// assert!(table.contains("skipped 1 malformed line: 3"));
+    /// ```
+    pub fn on_error(mut self, mode: ErrorMode) -> Self {
+        self.errors = Some(mode);
+
+        self
+    }
+
+    /// Infer a type of each column ([`ColumnType`]) out of a given number of first rows.
+    ///
+    /// Numeric columns are aligned to the right.
+    ///
+    /// The first row is considered a header, so it's not taken into account.
+    ///
+    /// [`ColumnType`]: crate::ColumnType
+    pub fn infer_types(mut self, sample: usize) -> Self {
+        self.sample = Some(sample);
+
+        self
+    }
+
+    /// Normalize numbers of numeric columns.
+    ///
+    /// Thousands separators and a leading `+` are removed,
+    /// and floats are padded by zeros to have the same number of decimals.
+    ///
+    /// It has an affect only together with [`Options::infer_types`].
+    pub fn normalize_numbers(mut self, on: bool) -> Self {
+        self.normalize_numbers = on;
+
+        self
+    }
+
+    /// Colorize booleans (green `true`, red `false`) and show empty cells as a dimmed `-`.
+    ///
+    /// It has an affect only together with [`Options::infer_types`].
+    #[cfg(feature = "ansi")]
+    pub fn colorize(mut self, on: bool) -> Self {
+        self.colorize = on;
+
+        self
+    }
+
+    pub(crate) fn projection(&self) -> Option<Projection> {
+        // names can be resolved only against a header
+        if !self.get_dialect().has_header() {
+            return None;
+        }
+
+        if self.select.is_empty() && self.exclude.is_empty() && self.rename.is_empty() {
+            return None;
+        }
+
+        Some(Projection::new(
+            self.select.clone(),
+            self.exclude.clone(),
+            self.rename.clone(),
+        ))
+    }
+
+    pub(crate) fn get_dialect(&self) -> Dialect {
+        self.dialect.unwrap_or_default()
+    }
+
+    pub(crate) fn get_sniff(&self) -> Option<usize> {
+        self.sniff
+    }
+
+    pub(crate) fn set_sniffed(&mut self, dialect: Dialect) {
+        self.dialect = Some(dialect);
+        self.sniff = None;
+    }
+
+    pub(crate) fn error_mode(&self, default: ErrorMode) -> ErrorMode {
+        self.errors.unwrap_or(default)
+    }
+
+    pub(crate) fn type_options(&self) -> Option<TypeOptions> {
+        let sample = self.sample?;
+
+        Some(TypeOptions {
+            sample,
+            normalize_numbers: self.normalize_numbers,
+            #[cfg(feature = "ansi")]
+            colorize: self.colorize,
+        })
+    }
+}
@@ -1,26 +1,43 @@
-use std::{fmt::Debug, io::Read};
+use std::{collections::VecDeque, fmt::Debug, io::Read};
 
 use csv::{Reader, StringRecord, StringRecordsIntoIter};
 use tabled::grid::records::IntoRecords;
 
+use crate::{
+    columns::Projection,
+    error::{error_row, summary_row, Error},
+    types::{format_rows, infer_columns, justify_row, justify_rows, ColumnFormat, TypeOptions},
+    ErrorMode, Options,
+};
+
 /// A [`IntoRecords`] implementation for a [`csv::Reader`].
 ///
 /// By default all errors are ignored,
-/// but you can return them using [`CsvRecordsIter::set_catch`].
+/// but you can stop on them using [`CsvRecordsIter::set_catch`].
 ///
 /// [`CsvRecordsIter::set_catch`]: CsvRecordsIter.set_catch
 pub struct CsvRecords<R> {
     rows: StringRecordsIntoIter<R>,
+    options: Options,
 }
 
 impl<R> CsvRecords<R> {
     /// Creates a new [`CsvRecords`] structure.
     pub fn new(reader: Reader<R>) -> Self
+    where
+        R: Read,
+    {
+        Self::with_options(reader, Options::default())
+    }
+
+    /// Creates a new [`CsvRecords`] structure with a given [`Options`].
+    pub fn with_options(reader: Reader<R>, options: Options) -> Self
     where
         R: Read,
     {
         Self {
             rows: reader.into_records(),
+            options,
         }
     }
 }
@@ -36,8 +53,20 @@ where
     fn iter_rows(self) -> Self::IterRows {
         CsvRecordsIter {
             iter: self.rows,
-            err_logic: ErrorLogic::Ignore,
+            err_logic: match self.options.error_mode(ErrorMode::Skip) {
+                ErrorMode::Skip => ErrorLogic::Ignore,
+                ErrorMode::Show => ErrorLogic::Show,
+                ErrorMode::Strict => ErrorLogic::Catch,
+            },
             err: None,
+            projection: self.options.projection(),
+            types: self.options.type_options(),
+            has_header: self.options.get_dialect().has_header(),
+            columns: None,
+            buf: VecDeque::new(),
+            count_columns: 0,
+            skipped: Vec::new(),
+            is_summary_shown: false,
         }
     }
 }
@@ -47,18 +76,64 @@ pub struct CsvRecordsIter<R> {
     iter: StringRecordsIntoIter<R>,
     err_logic: ErrorLogic,
     err: Option<std::io::Error>,
+    projection: Option<Projection>,
+    types: Option<TypeOptions>,
+    has_header: bool,
+    columns: Option<TypedColumns>,
+    buf: VecDeque<StringRecord>,
+    count_columns: usize,
+    skipped: Vec<Option<u64>>,
+    is_summary_shown: bool,
+}
+
+struct TypedColumns {
+    columns: Vec<ColumnFormat>,
+    widths: Vec<usize>,
+    options: TypeOptions,
+}
+
+enum Row {
+    Record(StringRecord),
+    Error(StringRecord),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum ErrorLogic {
     Ignore,
     Catch,
+    Show,
 }
 
 impl<R> CsvRecordsIter<R> {
     /// Return a status
     ///
     /// It's a cought by a catcher you can set by [`CsvRecordsIter::set_catch`].
+    /// An iteration stops on it, and the last row shows the error.
+    ///
+    /// Notice that it can be read only while you hold the iterator,
+    /// it's not reachable once the records are wrapped into an [`IterTable`].
+    ///
+    /// ```
+    /// use csv::ReaderBuilder;
+    /// use csv_to_table::{iter::CsvRecords, ErrorMode, Options};
+    /// use tabled::grid::records::IntoRecords;
+    ///
+    /// let csv = "name,year\n\
+    ///            C,1972\n\
+    ///            Rust,2010,Graydon Hoare";
+    ///
+    /// let reader = ReaderBuilder::new().has_headers(false).from_reader(csv.as_bytes());
+    /// let options = Options::new().on_error(ErrorMode::Strict);
+    ///
+    /// let mut iter = CsvRecords::with_options(reader, options).iter_rows();
+    /// let rows = iter.by_ref().map(|row| row.collect::<Vec<_>>()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(rows.len(), 3);
+    /// assert!(rows[2][0].contains("line 3: found 3 fields, expected 2"));
+    /// assert!(iter.status().is_some());
+    /// ```
+    ///
+    /// [`IterTable`]: tabled::tables::IterTable
     pub fn status(&self) -> Option<&std::io::Error> {
         self.err.as_ref()
     }
@@ -82,16 +157,139 @@ where
     type Item = CsvStringRecord;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(options) = self.types.take() {
+            self.infer_types(options);
+        }
+
+        if let Some(record) = self.buf.pop_front() {
+            return Some(CsvStringRecord::new(record));
+        }
+
+        let record = match self.next_row() {
+            Some(Row::Record(record)) => self.format_record(record),
+            Some(Row::Error(record)) => record,
+            None => self.summary()?,
+        };
+
+        Some(CsvStringRecord::new(record))
+    }
+}
+
+impl<R> CsvRecordsIter<R>
+where
+    R: Read,
+{
+    fn infer_types(&mut self, options: TypeOptions) {
+        let mut sample = Vec::new();
+        let mut count_records = 0;
+        while count_records < options.sample {
+            match self.next_row() {
+                Some(row) => {
+                    count_records += matches!(row, Row::Record(_)) as usize;
+                    sample.push(row);
+                }
+                None => break,
+            }
+        }
+
+        let mut records = sample
+            .iter()
+            .filter_map(|row| match row {
+                Row::Record(record) => Some(record.iter().map(String::from).collect::<Vec<_>>()),
+                Row::Error(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        let columns = infer_columns(&records, self.has_header);
+        format_rows(&mut records, &columns, &options, self.has_header);
+        let widths = justify_rows(&mut records, &columns);
+
+        let mut records = records.into_iter();
+        for row in sample {
+            let record = match row {
+                Row::Record(_) => records.next().map(StringRecord::from).unwrap_or_default(),
+                Row::Error(record) => record,
+            };
+
+            self.buf.push_back(record);
+        }
+
+        self.columns = Some(TypedColumns {
+            columns,
+            widths,
+            options,
+        });
+    }
+
+    fn format_record(&self, record: StringRecord) -> StringRecord {
+        let columns = match &self.columns {
+            Some(columns) => columns,
+            None => return record,
+        };
+
+        let mut row = record
+            .iter()
+            .zip(&columns.columns)
+            .map(|(text, column)| column.format(text, &columns.options))
+            .collect::<Vec<_>>();
+        row.extend(record.iter().skip(columns.columns.len()).map(String::from));
+        justify_row(&mut row, &columns.columns, &columns.widths);
+
+        StringRecord::from(row)
+    }
+
+    fn summary(&mut self) -> Option<StringRecord> {
+        if self.skipped.is_empty() || self.is_summary_shown {
+            return None;
+        }
+
+        self.is_summary_shown = true;
+
+        let row = summary_row(&self.skipped, self.count_columns);
+
+        Some(StringRecord::from(row))
+    }
+
+    /// Stops an iteration showing an error,
+    /// like a malformed record in a strict mode or an unknown column.
+    fn fail(&mut self, err: Error) -> Row {
+        let row = error_row(&err, self.count_columns);
+        self.err = Some(std::io::Error::from(err.into_inner()));
+
+        Row::Error(StringRecord::from(row))
+    }
+
+    fn next_row(&mut self) -> Option<Row> {
+        if self.err.is_some() {
+            return None;
+        }
+
         loop {
             let result = self.iter.next()?;
 
             match result {
-                Ok(record) => return Some(CsvStringRecord::new(record)),
+                Ok(mut record) => {
+                    if let Some(projection) = &mut self.projection {
+                        record = match projection.apply(record) {
+                            Ok(record) => record,
+                            Err(err) => return Some(self.fail(err)),
+                        };
+                    }
+
+                    self.count_columns = self.count_columns.max(record.len());
+
+                    return Some(Row::Record(record));
+                }
                 Err(err) => match self.err_logic {
                     ErrorLogic::Ignore => continue,
-                    ErrorLogic::Catch => {
-                        self.err = Some(std::io::Error::from(err));
-                        return None;
+                    ErrorLogic::Catch => return Some(self.fail(Error::from(err))),
+                    ErrorLogic::Show => {
+                        let err = Error::from(err);
+                        self.skipped.push(err.line());
+
+                        let row = error_row(&err, self.count_columns);
+
+                        return Some(Row::Error(StringRecord::from(row)));
                     }
                 },
             }
@@ -0,0 +1,317 @@
+use tabled::grid::util::string::string_width;
+
+/// A type of a column content, which is inferred from a sample of rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColumnType {
+    /// An integer number, like `-42` or `757,504`.
+    Integer,
+    /// A floating point number, like `3.14` or `1e-3`.
+    Float,
+    /// A boolean, either `true` or `false` in any case.
+    Bool,
+    /// A date or a date time, like `2021-12-31` or `31.12.2021 23:59`.
+    Date,
+    /// Any other text.
+    Text,
+}
+
+impl ColumnType {
+    /// Detects a type of a single value.
+    ///
+    /// It returns [`None`] in case the value is empty.
+    ///
+    /// ```
+    /// use csv_to_table::ColumnType;
+    ///
+    /// assert_eq!(ColumnType::detect("1,024"), Some(ColumnType::Integer));
+    /// assert_eq!(ColumnType::detect("-0.5"), Some(ColumnType::Float));
+    /// assert_eq!(ColumnType::detect("TRUE"), Some(ColumnType::Bool));
+    /// assert_eq!(ColumnType::detect("2021-12-31"), Some(ColumnType::Date));
+    /// assert_eq!(ColumnType::detect("Rust"), Some(ColumnType::Text));
+    /// assert_eq!(ColumnType::detect(" "), None);
+    /// ```
+    pub fn detect(value: &str) -> Option<ColumnType> {
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+
+        let kind = if is_integer(value) {
+            ColumnType::Integer
+        } else if is_float(value) {
+            ColumnType::Float
+        } else if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+            ColumnType::Bool
+        } else if is_date(value) {
+            ColumnType::Date
+        } else {
+            ColumnType::Text
+        };
+
+        Some(kind)
+    }
+
+    /// Verifies whether it's a number type.
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, ColumnType::Integer | ColumnType::Float)
+    }
+
+    pub(crate) fn merge(self, other: ColumnType) -> ColumnType {
+        use ColumnType::*;
+
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Integer, Float) | (Float, Integer) => Float,
+            _ => Text,
+        }
+    }
+}
+
+/// A per column formatting, based on an inferred type.
+#[derive(Debug, Clone)]
+pub(crate) struct ColumnFormat {
+    kind: ColumnType,
+    decimals: usize,
+}
+
+impl ColumnFormat {
+    pub(crate) fn kind(&self) -> ColumnType {
+        self.kind
+    }
+
+    pub(crate) fn format(&self, text: &str, options: &TypeOptions) -> String {
+        let kind = ColumnType::detect(text);
+
+        // only numeric columns are normalized,
+        // so ids or zip codes in a text column are left as they are
+        let is_number = self.kind.is_numeric() && matches!(kind, Some(kind) if kind.is_numeric());
+
+        #[allow(unused_mut)]
+        let mut text = match is_number && options.normalize_numbers {
+            true => normalize_number(text.trim(), self.decimals),
+            false => text.to_owned(),
+        };
+
+        #[cfg(feature = "ansi")]
+        if options.colorize {
+            text = colorize(text, kind);
+        }
+
+        text
+    }
+}
+
+/// Settings of a type inference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TypeOptions {
+    pub(crate) sample: usize,
+    pub(crate) normalize_numbers: bool,
+    #[cfg(feature = "ansi")]
+    pub(crate) colorize: bool,
+}
+
+/// Infers column types out of a sample of rows.
+///
+/// A header is not considered, unless it's the only row.
+pub(crate) fn infer_columns<T>(sample: &[Vec<T>], has_header: bool) -> Vec<ColumnFormat>
+where
+    T: AsRef<str>,
+{
+    let count_columns = sample.iter().map(|row| row.len()).max().unwrap_or(0);
+    let body = if has_header && sample.len() > 1 {
+        &sample[1..]
+    } else {
+        sample
+    };
+
+    let mut columns = Vec::with_capacity(count_columns);
+    for col in 0..count_columns {
+        let mut kind = None;
+        let mut decimals = 0;
+        for text in body.iter().filter_map(|row| row.get(col)) {
+            let text = text.as_ref().trim();
+            let next = match ColumnType::detect(text) {
+                Some(next) => next,
+                None => continue,
+            };
+
+            kind = Some(kind.map_or(next, |kind: ColumnType| kind.merge(next)));
+
+            if next == ColumnType::Float {
+                decimals = decimals.max(count_decimals(text));
+            }
+        }
+
+        let kind = kind.unwrap_or(ColumnType::Text);
+        columns.push(ColumnFormat { kind, decimals });
+    }
+
+    columns
+}
+
+/// Formats rows according to inferred types, a header is left as is.
+pub(crate) fn format_rows(
+    rows: &mut [Vec<String>],
+    columns: &[ColumnFormat],
+    options: &TypeOptions,
+    has_header: bool,
+) {
+    for row in rows.iter_mut().skip(has_header as usize) {
+        for (text, column) in row.iter_mut().zip(columns) {
+            *text = column.format(text, options);
+        }
+    }
+}
+
+/// Right justifies numeric columns by padding them with spaces,
+/// which is used by streaming tables which have no per column alignment.
+pub(crate) fn justify_rows(rows: &mut [Vec<String>], columns: &[ColumnFormat]) -> Vec<usize> {
+    let mut widths = vec![0; columns.len()];
+    for row in rows.iter() {
+        for (width, text) in widths.iter_mut().zip(row) {
+            *width = (*width).max(string_width(text));
+        }
+    }
+
+    for row in rows.iter_mut() {
+        justify_row(row, columns, &widths);
+    }
+
+    widths
+}
+
+pub(crate) fn justify_row(row: &mut [String], columns: &[ColumnFormat], widths: &[usize]) {
+    for ((text, column), width) in row.iter_mut().zip(columns).zip(widths) {
+        if !column.kind().is_numeric() {
+            continue;
+        }
+
+        let text_width = string_width(text);
+        if text_width < *width {
+            text.insert_str(0, &" ".repeat(width - text_width));
+        }
+    }
+}
+
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    is_digits_grouped(digits)
+}
+
+fn is_float(value: &str) -> bool {
+    let value = value.strip_prefix(['-', '+']).unwrap_or(value);
+
+    let (mantissa, exponent) = match value.find(['e', 'E']) {
+        Some(i) => (&value[..i], Some(&value[i + 1..])),
+        None => (value, None),
+    };
+
+    if let Some(exponent) = exponent {
+        let exponent = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+        if exponent.is_empty() || !exponent.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+    }
+
+    match mantissa.split_once('.') {
+        Some((int, frac)) => {
+            let int_ok = int.is_empty() || is_digits_grouped(int);
+            let frac_ok = frac.bytes().all(|b| b.is_ascii_digit());
+            int_ok && frac_ok && !(int.is_empty() && frac.is_empty())
+        }
+        None => exponent.is_some() && is_digits_grouped(mantissa),
+    }
+}
+
+// Checks a number which may contain a thousands separator like `1,000,000`.
+fn is_digits_grouped(value: &str) -> bool {
+    if value.is_empty() {
+        return false;
+    }
+
+    if value.bytes().all(|b| b.is_ascii_digit()) {
+        return true;
+    }
+
+    let mut groups = value.split(',');
+    let first = groups.next().unwrap_or_default();
+    let is_first_ok = (1..=3).contains(&first.len()) && first.bytes().all(|b| b.is_ascii_digit());
+
+    is_first_ok && groups.all(|group| group.len() == 3 && group.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn is_date(value: &str) -> bool {
+    let date = value.split(['T', ' ']).next().unwrap_or_default();
+    let time = value[date.len()..].trim_start_matches(['T', ' ']);
+
+    let parts = date.split(['-', '/', '.']).collect::<Vec<_>>();
+    let is_date = match parts.as_slice() {
+        [a, b, c] => {
+            let lens = (a.len(), b.len(), c.len());
+            let is_digits = parts.iter().all(|p| p.bytes().all(|b| b.is_ascii_digit()));
+            let is_ymd = lens.0 == 4 && (1..=2).contains(&lens.1) && (1..=2).contains(&lens.2);
+            let is_dmy = (1..=2).contains(&lens.0) && (1..=2).contains(&lens.1) && lens.2 == 4;
+
+            is_digits && (is_ymd || is_dmy)
+        }
+        _ => false,
+    };
+
+    if !is_date {
+        return false;
+    }
+
+    if time.is_empty() {
+        return true;
+    }
+
+    let time = time.trim_end_matches('Z');
+    let hms = time.split([':', '.', '+', '-']).collect::<Vec<_>>();
+
+    hms.len() >= 2
+        && hms
+            .iter()
+            .all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn count_decimals(value: &str) -> usize {
+    if value.contains(['e', 'E']) {
+        return 0;
+    }
+
+    value.split_once('.').map_or(0, |(_, frac)| frac.len())
+}
+
+fn normalize_number(value: &str, decimals: usize) -> String {
+    let value = value.strip_prefix('+').unwrap_or(value);
+    let mut number = value.replace(',', "");
+
+    if value.contains(['e', 'E']) {
+        return number;
+    }
+
+    let current = count_decimals(&number);
+    if current < decimals {
+        if current == 0 {
+            number.push('.');
+        }
+
+        number.push_str(&"0".repeat(decimals - current));
+    }
+
+    number
+}
+
+#[cfg(feature = "ansi")]
+fn colorize(text: String, kind: Option<ColumnType>) -> String {
+    use tabled::settings::Color;
+
+    let color = match kind {
+        None => return String::from("\u{1b}[2m-\u{1b}[22m"),
+        Some(ColumnType::Bool) if text.trim().eq_ignore_ascii_case("true") => Color::FG_GREEN,
+        Some(ColumnType::Bool) => Color::FG_RED,
+        _ => return text,
+    };
+
+    format!("{}{}{}", color.get_prefix(), text, color.get_suffix())
+}
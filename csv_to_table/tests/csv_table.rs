@@ -1,3 +1,4 @@
+use csv_to_table::{Dialect, ErrorMode, Options};
 use testing_table::test_table;
 
 test_table!(
@@ -46,6 +47,342 @@ test_table!(
     "+------+---------+----+-------------+--------------------+-----+----------------------------------------+-----------------------+---------+---------------------+"
 );
 
+test_table!(
+    test_infer_types,
+    csv_to_table::from_reader_with(csv_typed(), Options::new().infer_types(100)).unwrap(),
+    "+----+--------+---------+----------+------------------+"
+    "| id | name   |   price | in_stock | added            |"
+    "+----+--------+---------+----------+------------------+"
+    "|  1 | Apple  | 1,250.5 | true     | 2021-12-31       |"
+    "+----+--------+---------+----------+------------------+"
+    "|  2 | Banana |      +3 | false    | 2022-01-05       |"
+    "+----+--------+---------+----------+------------------+"
+    "| 13 | Cherry |         | TRUE     | 2022-01-05 10:00 |"
+    "+----+--------+---------+----------+------------------+"
+    "| -4 | Date   |    0.25 | false    |                  |"
+    "+----+--------+---------+----------+------------------+"
+);
+
+test_table!(
+    test_infer_types_normalize_numbers,
+    csv_to_table::from_reader_with(
+        csv_typed(),
+        Options::new().infer_types(100).normalize_numbers(true)
+    )
+    .unwrap(),
+    "+----+--------+---------+----------+------------------+"
+    "| id | name   |   price | in_stock | added            |"
+    "+----+--------+---------+----------+------------------+"
+    "|  1 | Apple  | 1250.50 | true     | 2021-12-31       |"
+    "+----+--------+---------+----------+------------------+"
+    "|  2 | Banana |    3.00 | false    | 2022-01-05       |"
+    "+----+--------+---------+----------+------------------+"
+    "| 13 | Cherry |         | TRUE     | 2022-01-05 10:00 |"
+    "+----+--------+---------+----------+------------------+"
+    "| -4 | Date   |    0.25 | false    |                  |"
+    "+----+--------+---------+----------+------------------+"
+);
+
+test_table!(
+    test_infer_types_normalize_numbers_in_text_column,
+    csv_to_table::from_reader_with(
+        "code,amount\n\"1,000\",\"1,000\"\n+5,+5\nA-12,7".as_bytes(),
+        Options::new().infer_types(100).normalize_numbers(true)
+    )
+    .unwrap(),
+    "+-------+--------+"
+    "| code  | amount |"
+    "+-------+--------+"
+    "| 1,000 |   1000 |"
+    "+-------+--------+"
+    "| +5    |      5 |"
+    "+-------+--------+"
+    "| A-12  |      7 |"
+    "+-------+--------+"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    test_infer_types_colorize,
+    csv_to_table::from_reader_with(
+        csv_typed(),
+        Options::new().infer_types(100).colorize(true)
+    )
+    .unwrap(),
+    "+----+--------+---------+----------+------------------+"
+    "| id | name   |   price | in_stock | added            |"
+    "+----+--------+---------+----------+------------------+"
+    "|  1 | Apple  | 1,250.5 | \u{1b}[32mtrue\u{1b}[39m     | 2021-12-31       |"
+    "+----+--------+---------+----------+------------------+"
+    "|  2 | Banana |      +3 | \u{1b}[31mfalse\u{1b}[39m    | 2022-01-05       |"
+    "+----+--------+---------+----------+------------------+"
+    "| 13 | Cherry |       \u{1b}[2m-\u{1b}[22m | \u{1b}[32mTRUE\u{1b}[39m     | 2022-01-05 10:00 |"
+    "+----+--------+---------+----------+------------------+"
+    "| -4 | Date   |    0.25 | \u{1b}[31mfalse\u{1b}[39m    | \u{1b}[2m-\u{1b}[22m                |"
+    "+----+--------+---------+----------+------------------+"
+);
+
+test_table!(
+    test_select_and_rename,
+    csv_to_table::from_reader_with(
+        csv_typed(),
+        Options::new()
+            .select(["price", "name"])
+            .rename("price", "Price, $")
+    )
+    .unwrap(),
+    "+----------+--------+"
+    "| Price, $ | name   |"
+    "+----------+--------+"
+    "| 1,250.5  | Apple  |"
+    "+----------+--------+"
+    "| +3       | Banana |"
+    "+----------+--------+"
+    "|          | Cherry |"
+    "+----------+--------+"
+    "| 0.25     | Date   |"
+    "+----------+--------+"
+);
+
+test_table!(
+    test_select_and_rename_without_header,
+    csv_to_table::from_reader_with(
+        "1,Apple,10\n2,Banana,5".as_bytes(),
+        Options::new()
+            .dialect({
+                let mut dialect = Dialect::default();
+                dialect.set_header(false);
+                dialect
+            })
+            .select(["Banana"])
+            .exclude(["1"])
+            .rename("Apple", "name")
+    )
+    .unwrap(),
+    "+---+--------+----+"
+    "| 1 | Apple  | 10 |"
+    "+---+--------+----+"
+    "| 2 | Banana | 5  |"
+    "+---+--------+----+"
+);
+
+#[test]
+fn test_select_unknown_columns() {
+    let options = Options::new()
+        .select(["name", "weight"])
+        .exclude(["id", "color"])
+        .rename("weight", "kg");
+    let err = csv_to_table::from_reader_with(csv_typed(), options).unwrap_err();
+
+    assert_eq!(err.unknown_columns(), ["weight", "color"]);
+    assert_eq!(err.line(), None);
+    assert_eq!(err.to_string(), "unknown columns: weight, color");
+}
+
+test_table!(
+    test_exclude,
+    csv_to_table::from_reader_with(
+        csv_typed(),
+        Options::new().exclude(["added", "in_stock"]).infer_types(10)
+    )
+    .unwrap(),
+    "+----+--------+---------+"
+    "| id | name   |   price |"
+    "+----+--------+---------+"
+    "|  1 | Apple  | 1,250.5 |"
+    "+----+--------+---------+"
+    "|  2 | Banana |      +3 |"
+    "+----+--------+---------+"
+    "| 13 | Cherry |         |"
+    "+----+--------+---------+"
+    "| -4 | Date   |    0.25 |"
+    "+----+--------+---------+"
+);
+
+#[cfg(not(feature = "ansi"))]
+test_table!(
+    test_show_errors,
+    csv_to_table::from_reader_with(
+        csv_broken(),
+        Options::new().on_error(ErrorMode::Show).infer_types(10)
+    )
+    .unwrap(),
+    "+---------+-------------+------------+"
+    "|      id | name        |      price |"
+    "+---------+-------------+------------+"
+    "|       1 | Apple       |         10 |"
+    "+---------+-------------+------------+"
+    "| line 3: found 2 fields, expected 3 |"
+    "+---------+-------------+------------+"
+    "|       3 | Cherry      |          5 |"
+    "+---------+-------------+------------+"
+    "| line 5: found 4 fields, expected 3 |"
+    "+---------+-------------+------------+"
+    "|       5 | Fig         |        120 |"
+    "+---------+-------------+------------+"
+    "| skipped 2 malformed lines: 3, 5    |"
+    "+---------+-------------+------------+"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    test_show_errors_ansi,
+    csv_to_table::from_reader_with(
+        csv_broken(),
+        Options::new().on_error(ErrorMode::Show).infer_types(10)
+    )
+    .unwrap(),
+    "+---------+-------------+------------+"
+    "|      id | name        |      price |"
+    "+---------+-------------+------------+"
+    "|       1 | Apple       |         10 |"
+    "+---------+-------------+------------+"
+    "| \u{1b}[31mline 3: found 2 fields, expected 3\u{1b}[39m |"
+    "+---------+-------------+------------+"
+    "|       3 | Cherry      |          5 |"
+    "+---------+-------------+------------+"
+    "| \u{1b}[31mline 5: found 4 fields, expected 3\u{1b}[39m |"
+    "+---------+-------------+------------+"
+    "|       5 | Fig         |        120 |"
+    "+---------+-------------+------------+"
+    "| \u{1b}[31mskipped 2 malformed lines: 3, 5\u{1b}[39m    |"
+    "+---------+-------------+------------+"
+);
+
+test_table!(
+    test_skip_errors,
+    csv_to_table::from_reader_with(csv_broken(), Options::new().on_error(ErrorMode::Skip)).unwrap(),
+    "+----+--------+-------+"
+    "| id | name   | price |"
+    "+----+--------+-------+"
+    "| 1  | Apple  | 10    |"
+    "+----+--------+-------+"
+    "| 3  | Cherry | 5     |"
+    "+----+--------+-------+"
+    "| 5  | Fig    | 120   |"
+    "+----+--------+-------+"
+);
+
+#[test]
+fn test_strict_errors() {
+    let err = csv_to_table::from_reader_with(csv_broken(), Options::new()).unwrap_err();
+
+    assert_eq!(err.line(), Some(3));
+    assert_eq!(err.byte(), Some(25));
+    assert_eq!(err.record(), Some(2));
+    assert_eq!(
+        err.to_string(),
+        "line 3 (byte 25): found 2 fields, expected 3"
+    );
+}
+
+test_table!(
+    test_sniff_semicolon,
+    csv_to_table::from_reader_with(
+        "name;price;date\nTea;\"1,5\";01.02.2023\nCoffee;12;02.02.2023\n".as_bytes(),
+        Options::new().sniff(1024).infer_types(10)
+    )
+    .unwrap(),
+    "+--------+-------+------------+"
+    "| name   | price | date       |"
+    "+--------+-------+------------+"
+    "| Tea    | 1,5   | 01.02.2023 |"
+    "+--------+-------+------------+"
+    "| Coffee | 12    | 02.02.2023 |"
+    "+--------+-------+------------+"
+);
+
+test_table!(
+    test_sniff_no_header,
+    csv_to_table::from_reader_with(
+        "1\tApple\t10\n2\tBanana\t5\n13\tCherry\t120\n".as_bytes(),
+        Options::new().sniff(1024).infer_types(10)
+    )
+    .unwrap(),
+    "+----+--------+-----+"
+    "|  1 | Apple  |  10 |"
+    "+----+--------+-----+"
+    "|  2 | Banana |   5 |"
+    "+----+--------+-----+"
+    "| 13 | Cherry | 120 |"
+    "+----+--------+-----+"
+);
+
+test_table!(
+    test_sniff_flexible,
+    csv_to_table::from_reader_with(
+        "a|b|c\n1|2|3\n4|5\n6|7|8|9\n".as_bytes(),
+        Options::new().sniff(1024)
+    )
+    .unwrap(),
+    "+---+---+---+---+"
+    "| a | b | c |   |"
+    "+---+---+---+---+"
+    "| 1 | 2 | 3 |   |"
+    "+---+---+---+---+"
+    "| 4 | 5 |   |   |"
+    "+---+---+---+---+"
+    "| 6 | 7 | 8 | 9 |"
+    "+---+---+---+---+"
+);
+
+test_table!(
+    test_sniff_select_and_rename,
+    csv_to_table::from_reader_with(
+        "id\tname\tprice\n1\tApple\t10\n2\tBanana\t5\n".as_bytes(),
+        Options::new()
+            .sniff(1024)
+            .select(["name", "price"])
+            .rename("price", "cost")
+    )
+    .unwrap(),
+    "+--------+------+"
+    "| name   | cost |"
+    "+--------+------+"
+    "| Apple  | 10   |"
+    "+--------+------+"
+    "| Banana | 5    |"
+    "+--------+------+"
+);
+
+test_table!(
+    test_sniff_select_and_rename_without_header,
+    csv_to_table::from_reader_with(
+        "1\tApple\t10\n2\tBanana\t5\n".as_bytes(),
+        Options::new()
+            .sniff(1024)
+            .select(["Apple", "10"])
+            .rename("10", "cost")
+    )
+    .unwrap(),
+    "+---+--------+----+"
+    "| 1 | Apple  | 10 |"
+    "+---+--------+----+"
+    "| 2 | Banana | 5  |"
+    "+---+--------+----+"
+);
+
+#[test]
+fn test_dialect_sniff() {
+    let dialect = Dialect::sniff(b"name;price\nTea;\"1,5\"\nCoffee;12\n");
+    assert_eq!(dialect.get_delimiter(), b';');
+    assert_eq!(dialect.get_quote(), b'"');
+    assert!(dialect.has_header());
+    assert!(!dialect.is_flexible());
+
+    let dialect = Dialect::sniff(b"1\tApple\t10\n2\tBanana\t5\n");
+    assert_eq!(dialect.get_delimiter(), b'\t');
+    assert!(!dialect.has_header());
+
+    let dialect = Dialect::sniff(b"'name','note'\n'C','a, b'\n");
+    assert_eq!(dialect.get_delimiter(), b',');
+    assert_eq!(dialect.get_quote(), b'\'');
+
+    let dialect = Dialect::sniff(b"a|b|c\n1|2|3\n4|5\n");
+    assert_eq!(dialect.get_delimiter(), b'|');
+    assert!(dialect.is_flexible());
+}
+
 fn csv1() -> &'static [u8] {
     static DATA: &'_ str = r#"
 2021,Level 1,AA,"Agriculture",Dollars (millions),H10,Indirect taxes,Financial performance,489,ANZSIC06 division A
@@ -72,3 +409,28 @@ fn csv1() -> &'static [u8] {
 
     DATA.trim().as_bytes()
 }
+
+fn csv_typed() -> &'static [u8] {
+    static DATA: &'_ str = r#"
+id,name,price,in_stock,added
+1,Apple,"1,250.5",true,2021-12-31
+2,Banana,+3,false,2022-01-05
+13,Cherry,,TRUE,2022-01-05 10:00
+-4,Date,0.25,false,
+"#;
+
+    DATA.trim().as_bytes()
+}
+
+fn csv_broken() -> &'static [u8] {
+    static DATA: &'_ str = r#"
+id,name,price
+1,Apple,10
+2,Banana
+3,Cherry,5
+4,Date,7,extra
+5,Fig,120
+"#;
+
+    DATA.trim().as_bytes()
+}
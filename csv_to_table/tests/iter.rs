@@ -1,3 +1,5 @@
+use csv_to_table::{iter::CsvRecords, Dialect, ErrorMode, Options};
+use tabled::grid::records::IntoRecords;
 use testing_table::test_table;
 
 test_table!(
@@ -206,6 +208,216 @@ test_table!(
     "+--+--+--+"
 );
 
+test_table!(
+    test_iter_infer_types,
+    csv_to_table::iter::from_reader_with(csv_typed(), Options::new().infer_types(100)),
+    "+----+--------+---------+----------+------------------+"
+    "| id | name   |   price | in_stock | added            |"
+    "+----+--------+---------+----------+------------------+"
+    "|  1 | Apple  | 1,250.5 | true     | 2021-12-31       |"
+    "+----+--------+---------+----------+------------------+"
+    "|  2 | Banana |      +3 | false    | 2022-01-05       |"
+    "+----+--------+---------+----------+------------------+"
+    "| 13 | Cherry |         | TRUE     | 2022-01-05 10:00 |"
+    "+----+--------+---------+----------+------------------+"
+    "| -4 | Date   |    0.25 | false    |                  |"
+    "+----+--------+---------+----------+------------------+"
+);
+
+test_table!(
+    test_iter_infer_types_small_sample,
+    csv_to_table::iter::from_reader_with(
+        csv_typed(),
+        Options::new().infer_types(2).normalize_numbers(true)
+    ),
+    "+----+--------+--------+----------+------------------+"
+    "| id | name   |  price | in_stock | added            |"
+    "+----+--------+--------+----------+------------------+"
+    "|  1 | Apple  | 1250.5 | true     | 2021-12-31       |"
+    "+----+--------+--------+----------+------------------+"
+    "|  2 | Banana |    3.0 | false    | 2022-01-05       |"
+    "+----+--------+--------+----------+------------------+"
+    "| 13 | Cherry |        | TRUE     | 2022-01-05 10:00 |"
+    "+----+--------+--------+----------+------------------+"
+    "| -4 | Date   |   0.25 | false    |                  |"
+    "+----+--------+--------+----------+------------------+"
+);
+
+test_table!(
+    test_iter_select_and_rename,
+    csv_to_table::iter::from_reader_with(
+        csv1(),
+        Options::new()
+            .select(["Variable_code", "Value"])
+            .rename("Variable_code", "Code")
+    ),
+    "+------+---------+"
+    "| Code | Value   |"
+    "+------+---------+"
+    "| H01  | 757,504 |"
+    "+------+---------+"
+    "| H05  | 49,593  |"
+    "+------+---------+"
+    "| H07  | 33,020  |"
+    "+------+---------+"
+    "| H08  | 654,404 |"
+    "+------+---------+"
+    "| H09  | 26,138  |"
+    "+------+---------+"
+);
+
+test_table!(
+    test_iter_select_and_rename_without_header,
+    csv_to_table::iter::from_reader_with(
+        "1,Apple,10\n2,Banana,5".as_bytes(),
+        Options::new()
+            .dialect({
+                let mut dialect = Dialect::default();
+                dialect.set_header(false);
+                dialect
+            })
+            .select(["Banana"])
+            .rename("Apple", "name")
+    ),
+    "+---+--------+----+"
+    "| 1 | Apple  | 10 |"
+    "+---+--------+----+"
+    "| 2 | Banana | 5  |"
+    "+---+--------+----+"
+);
+
+#[cfg(not(feature = "ansi"))]
+test_table!(
+    test_iter_select_unknown_columns,
+    csv_to_table::iter::from_reader_with(
+        csv1(),
+        Options::new().select(["Variable_code", "Value", "Unknown"])
+    ),
+    "+--------------------------+"
+    "| unknown columns: Unknown |"
+    "+--------------------------+"
+);
+
+test_table!(
+    test_iter_exclude,
+    csv_to_table::iter::from_reader_with(
+        csv1(),
+        Options::new()
+            .exclude([
+                "Industry_aggregation_NZSIOC",
+                "Industry_name_NZSIOC",
+                "Units",
+                "Variable_category",
+            ])
+            .infer_types(10)
+    ),
+    "+------+---------------+-----------------------------------+---------+"
+    "| Year | Variable_code | Variable_name                     |   Value |"
+    "+------+---------------+-----------------------------------+---------+"
+    "| 2021 | H01           | Total income                      | 757,504 |"
+    "+------+---------------+-----------------------------------+---------+"
+    "| 2021 | H05           | Interest, dividends and donations |  49,593 |"
+    "+------+---------------+-----------------------------------+---------+"
+    "| 2021 | H07           | Non-operating income              |  33,020 |"
+    "+------+---------------+-----------------------------------+---------+"
+    "| 2021 | H08           | Total expenditure                 | 654,404 |"
+    "+------+---------------+-----------------------------------+---------+"
+    "| 2021 | H09           | Interest and donations            |  26,138 |"
+    "+------+---------------+-----------------------------------+---------+"
+);
+
+#[cfg(not(feature = "ansi"))]
+test_table!(
+    test_iter_show_errors,
+    csv_to_table::iter::from_reader_with(
+        csv1(),
+        Options::new()
+            .on_error(ErrorMode::Show)
+            .select(["Variable_code", "Value"])
+            .infer_types(3)
+    ),
+    "+------------------------------------+---------+"
+    "| Variable_code                      |   Value |"
+    "+------------------------------------+---------+"
+    "| H01                                | 757,504 |"
+    "+------------------------------------+---------+"
+    "| line 3: found 6 fields, expected 8 |         |"
+    "+------------------------------------+---------+"
+    "| H05                                |  49,593 |"
+    "+------------------------------------+---------+"
+    "| H07                                |  33,020 |"
+    "+------------------------------------+---------+"
+    "| H08                                | 654,404 |"
+    "+------------------------------------+---------+"
+    "| H09                                |  26,138 |"
+    "+------------------------------------+---------+"
+    "| skipped 1 malformed line: 3        |         |"
+    "+------------------------------------+---------+"
+);
+
+#[cfg(not(feature = "ansi"))]
+test_table!(
+    test_iter_strict_errors,
+    csv_to_table::iter::from_reader_with(csv_broken(), Options::new().on_error(ErrorMode::Strict)),
+    "+------------------------------------+-------+-------+"
+    "| id                                 | name  | price |"
+    "+------------------------------------+-------+-------+"
+    "| 1                                  | Apple | 10    |"
+    "+------------------------------------+-------+-------+"
+    "| line 3: found 2 fields, expected 3 |       |       |"
+    "+------------------------------------+-------+-------+"
+);
+
+#[test]
+fn test_iter_strict_errors_status() {
+    let reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(csv_broken());
+    let options = Options::new().on_error(ErrorMode::Strict);
+
+    let mut iter = CsvRecords::with_options(reader, options).iter_rows();
+    let rows = iter
+        .by_ref()
+        .map(|row| row.collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    assert_eq!(rows.len(), 3);
+    assert!(rows[2][0].contains("line 3: found 2 fields, expected 3"));
+
+    let err = iter.status().unwrap();
+    assert!(err.to_string().contains("found record with 2 fields"));
+}
+
+test_table!(
+    test_iter_sniff_dialect,
+    csv_to_table::iter::from_reader_with(
+        "name;price;date\nTea;\"1,5\";01.02.2023\nCoffee;12;02.02.2023\n".as_bytes(),
+        Options::new().sniff(30).infer_types(10)
+    ),
+    "+--------+-------+------------+"
+    "| name   | price | date       |"
+    "+--------+-------+------------+"
+    "| Tea    | 1,5   | 01.02.2023 |"
+    "+--------+-------+------------+"
+    "| Coffee | 12    | 02.02.2023 |"
+    "+--------+-------+------------+"
+);
+
+test_table!(
+    test_iter_sniff_quote,
+    csv_to_table::iter::from_reader_with(
+        "'name','note'\n'C','a, b'\n'Rust','c'\n".as_bytes(),
+        Options::new().sniff(1024)
+    ),
+    "+------+------+"
+    "| name | note |"
+    "+------+------+"
+    "| C    | a, b |"
+    "+------+------+"
+    "| Rust | c    |"
+    "+------+------+"
+);
+
 fn csv1() -> &'static [u8] {
     static DATA: &'_ str = r#"
 Year,Industry_aggregation_NZSIOC,Industry_name_NZSIOC,Units,Variable_code,Variable_name,Variable_category,Value
@@ -233,3 +445,28 @@ Year,Industry_aggregation_NZSIOC,Industry_name_NZSIOC,Units,Variable_code,Variab
 
     DATA.trim().as_bytes()
 }
+
+fn csv_typed() -> &'static [u8] {
+    static DATA: &'_ str = r#"
+id,name,price,in_stock,added
+1,Apple,"1,250.5",true,2021-12-31
+2,Banana,+3,false,2022-01-05
+13,Cherry,,TRUE,2022-01-05 10:00
+-4,Date,0.25,false,
+"#;
+
+    DATA.trim().as_bytes()
+}
+
+fn csv_broken() -> &'static [u8] {
+    static DATA: &'_ str = r#"
+id,name,price
+1,Apple,10
+2,Banana
+3,Cherry,5
+4,Date,7,extra
+5,Fig,120
+"#;
+
+    DATA.trim().as_bytes()
+}
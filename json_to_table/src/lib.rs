@@ -9,12 +9,12 @@
 //!
 //! let value = json!(
 //!     {
-//!         "name": "John Doe",
-//!         "age": 43,
 //!         "address": {
-//!             "street": "10 Downing Street",
-//!             "city": "London"
+//!             "city": "London",
+//!             "street": "10 Downing Street"
 //!         },
+//!         "age": 43,
+//!         "name": "John Doe",
 //!         "phones": [
 //!             "+44 1234567",
 //!             "+44 2345678"
@@ -89,11 +89,92 @@
 
 use serde_json::Value;
 
-pub use table::{JsonTable, Orientation};
+pub use table::{JsonTable, Keys, Orientation, Records};
+
+#[cfg(feature = "ansi")]
+pub use table::Palette;
+
 use tabled::{builder::Builder, Table};
 
+mod records;
 mod table;
 
+pub mod iter {
+    //! The module contains [`JsonRecords`] which is an [`Iterator`] abstraction for [`IterTable`]
+    //! over a newline delimited JSON (JSON Lines).
+    //!
+    //! It is usefull when you have a huge file and don't want to load it all along into memory.
+    //!
+    //! [`IterTable`]: tabled::tables::IterTable
+
+    use std::{
+        fs::File,
+        io::{BufReader, Read},
+        path::Path,
+    };
+
+    use tabled::tables::IterTable;
+
+    pub use super::records::*;
+
+    /// Creates [`IterTable`] from a [`Read`]er of a newline delimited JSON.
+    ///
+    /// The header consists of keys of the first object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use json_to_table::iter::from_reader;
+    ///
+    /// let json = r#"
+    /// {"designed_by": "Dennis Ritchie", "invented_year": 1972, "name": "C"}
+    /// {"designed_by": "Graydon Hoare", "invented_year": 2010, "name": "Rust"}
+    /// {"invented_year": 2009, "name": "Go"}
+    /// "#;
+    ///
+    /// let table = from_reader(json.as_bytes()).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+----------------+---------------+------+\n\
+    ///      | designed_by    | invented_year | name |\n\
+    ///      +----------------+---------------+------+\n\
+    ///      | Dennis Ritchie | 1972          | C    |\n\
+    ///      +----------------+---------------+------+\n\
+    ///      | Graydon Hoare  | 2010          | Rust |\n\
+    ///      +----------------+---------------+------+\n\
+    ///      |                | 2009          | Go   |\n\
+    ///      +----------------+---------------+------+",
+    /// );
+    /// ```
+    pub fn from_reader<R>(reader: R) -> IterTable<JsonRecords<R>>
+    where
+        R: Read,
+    {
+        IterTable::new(JsonRecords::new(reader))
+    }
+
+    /// Creates [`IterTable`] from a [`File`] which suppose to have a newline delimited JSON.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use json_to_table::iter::from_path;
+    ///
+    /// let table = from_path("path/to/a/file.jsonl").expect("success read");
+    /// let table = table.to_string();
+    /// ```
+    pub fn from_path<P>(path: P) -> std::io::Result<IterTable<JsonRecords<BufReader<File>>>>
+    where
+        P: AsRef<Path>,
+    {
+        let file = BufReader::new(File::open(path)?);
+        let table = IterTable::new(JsonRecords::new(file));
+
+        Ok(table)
+    }
+}
+
 /// The function converts a given [`Value`] to a [`JsonTable`].
 ///
 /// ```
@@ -0,0 +1,181 @@
+use std::{collections::VecDeque, io::Read};
+
+use serde_json::{de::IoRead, Deserializer, StreamDeserializer, Value};
+use tabled::grid::records::IntoRecords;
+
+/// A [`IntoRecords`] implementation for a newline delimited JSON (JSON Lines).
+///
+/// The first row is a header, which consists of keys of first objects
+/// (see [`JsonRecords::sniff`]).
+/// Keys which are not part of a header are not shown.
+///
+/// Reading stops on a first malformed value,
+/// the error can be retrieved by [`JsonRecordsIter::status`].
+pub struct JsonRecords<R> {
+    reader: R,
+    sniff: usize,
+}
+
+impl<R> JsonRecords<R> {
+    /// Creates a new [`JsonRecords`] structure.
+    pub fn new(reader: R) -> Self {
+        Self { reader, sniff: 1 }
+    }
+
+    /// Set a number of first objects which keys are used as a header.
+    ///
+    /// By default only the first object is used.
+    pub fn sniff(mut self, count: usize) -> Self {
+        self.sniff = count;
+        self
+    }
+}
+
+impl<R> std::fmt::Debug for JsonRecords<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonRecords")
+            .field("sniff", &self.sniff)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R> IntoRecords for JsonRecords<R>
+where
+    R: Read,
+{
+    type Cell = String;
+    type IterColumns = std::vec::IntoIter<String>;
+    type IterRows = JsonRecordsIter<R>;
+
+    fn iter_rows(self) -> Self::IterRows {
+        JsonRecordsIter {
+            values: Deserializer::from_reader(self.reader).into_iter(),
+            sniff: self.sniff,
+            header: None,
+            buf: VecDeque::new(),
+            err: None,
+        }
+    }
+}
+
+/// A row iterator.
+pub struct JsonRecordsIter<R>
+where
+    R: Read,
+{
+    values: StreamDeserializer<'static, IoRead<R>, Value>,
+    sniff: usize,
+    header: Option<Vec<String>>,
+    buf: VecDeque<Value>,
+    err: Option<serde_json::Error>,
+}
+
+impl<R> JsonRecordsIter<R>
+where
+    R: Read,
+{
+    /// Returns an error which stopped reading if any.
+    pub fn status(&self) -> Option<&serde_json::Error> {
+        self.err.as_ref()
+    }
+}
+
+impl<R> std::fmt::Debug for JsonRecordsIter<R>
+where
+    R: Read,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonRecordsIter")
+            .field("header", &self.header)
+            .field("err", &self.err)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R> Iterator for JsonRecordsIter<R>
+where
+    R: Read,
+{
+    type Item = std::vec::IntoIter<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.header.is_none() {
+            let header = self.read_header();
+            self.header = Some(header.clone());
+
+            // in case there's no objects values are shown as they are
+            if !header.is_empty() {
+                return Some(header.into_iter());
+            }
+        }
+
+        let value = match self.buf.pop_front() {
+            Some(value) => value,
+            None => self.next_value()?,
+        };
+
+        let header = self.header.as_deref().unwrap_or_default();
+        let row = match &value {
+            Value::Object(map) if !header.is_empty() => header
+                .iter()
+                .map(|key| map.get(key).map(value_to_string).unwrap_or_default())
+                .collect(),
+            value => vec![value_to_string(value)],
+        };
+
+        Some(row.into_iter())
+    }
+}
+
+impl<R> JsonRecordsIter<R>
+where
+    R: Read,
+{
+    fn read_header(&mut self) -> Vec<String> {
+        let mut header: Vec<String> = Vec::new();
+        while self.buf.len() < self.sniff {
+            let value = match self.next_value() {
+                Some(value) => value,
+                None => break,
+            };
+
+            if let Value::Object(map) = &value {
+                for key in map.keys() {
+                    if !header.contains(key) {
+                        header.push(key.clone());
+                    }
+                }
+            }
+
+            self.buf.push_back(value);
+        }
+
+        header
+    }
+
+    fn next_value(&mut self) -> Option<Value> {
+        if self.err.is_some() {
+            return None;
+        }
+
+        match self.values.next()? {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.err = Some(err);
+                None
+            }
+        }
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::Bool(value) => value.to_string(),
+        Value::Number(value) => value.to_string(),
+        Value::String(value) => value.clone(),
+        Value::Array(_) | Value::Object(_) => {
+            serde_json::to_string(value).unwrap_or_else(|_| format!("{:?}", value))
+        }
+    }
+}
@@ -1,30 +1,32 @@
 use core::fmt::{self, Display};
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 
 use serde_json::Value;
 use tabled::{
-    builder::Builder,
-    grid::{
-        config::{
-            AlignmentHorizontal, Borders, ColoredConfig, Entity, Indent, Sides, SpannedConfig,
-        },
-        dimension::CompleteDimension,
-        records::EmptyRecords,
-    },
-    settings::{style::Style, TableOption},
-    tables::{PoolTable, TableValue},
+    grid::{config::CompactMultilineConfig, dimension::CompleteDimension, records::EmptyRecords},
+    settings::TableOption,
+    tables::PoolTable,
     Table,
 };
+use tree_to_table::TreeTable;
 
-mod collapsed_table;
-mod plain_table;
+mod path;
+
+pub use tree_to_table::{Keys, Orientation, Records};
+
+#[cfg(feature = "ansi")]
+pub use tree_to_table::Palette;
 
 /// Converter of [`Value`] to a table,
 /// with a set of configurations.
+///
+/// It's rendered by a [`TreeTable`],
+/// so it's built the same way as tables of `toml_to_table` and `ron_to_table`.
 #[derive(Debug, Clone)]
 pub struct JsonTable<T> {
     value: T,
-    cfg: Config,
+    table: TreeTable,
+    select: Option<String>,
 }
 
 impl<T> JsonTable<T> {
@@ -68,12 +70,8 @@ impl<T> JsonTable<T> {
     pub fn new(value: T) -> Self {
         JsonTable {
             value,
-            cfg: Config {
-                plain: true,
-                cfg: ColoredConfig::new(configure_grid()),
-                array_orientation: Orientation::Column,
-                object_orientation: Orientation::Column,
-            },
+            table: TreeTable::new(),
+            select: None,
         }
     }
 
@@ -84,8 +82,8 @@ impl<T> JsonTable<T> {
     /// use json_to_table::json_to_table;
     ///
     /// let value = json!({
-    ///     "key1": 123,
     ///     "234": ["123", "234", "456"],
+    ///     "key1": 123,
     ///     "key22": {
     ///         "k1": 1,
     ///         "k2": 2,
@@ -116,7 +114,7 @@ impl<T> JsonTable<T> {
     /// );
     /// ```
     pub fn collapse(&mut self) -> &mut Self {
-        self.cfg.plain = false;
+        self.table.collapse();
         self
     }
 
@@ -127,8 +125,8 @@ impl<T> JsonTable<T> {
     /// use json_to_table::{json_to_table, Orientation};
     ///
     /// let value = json!({
-    ///     "key1": 123,
     ///     "234": ["123", "234", "456"],
+    ///     "key1": 123,
     ///     "key22": {
     ///         "k1": 1,
     ///         "k2": 2,
@@ -184,7 +182,7 @@ impl<T> JsonTable<T> {
     /// );
     /// ```
     pub fn object_orientation(&mut self, mode: Orientation) -> &mut Self {
-        self.cfg.object_orientation = mode;
+        self.table.map_orientation(mode);
         self
     }
 
@@ -195,8 +193,8 @@ impl<T> JsonTable<T> {
     /// use json_to_table::{json_to_table, Orientation};
     ///
     /// let value = json!({
-    ///     "key1": 123,
     ///     "234": ["123", "234", "456"],
+    ///     "key1": 123,
     ///     "key22": {
     ///         "k1": 1,
     ///         "k2": 2,
@@ -254,7 +252,263 @@ impl<T> JsonTable<T> {
     /// );
     /// ```
     pub fn array_orientation(&mut self, mode: Orientation) -> &mut Self {
-        self.cfg.array_orientation = mode;
+        self.table.seq_orientation(mode);
+        self
+    }
+
+    /// Render arrays of objects as flat tables with keys as a header.
+    ///
+    /// It's applied to any array which consists of objects only, at any depth.
+    ///
+    /// In a collapsed mode nested values are collapsed into cells of a row,
+    /// unless they're inlined, see [`Records`].
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::{json_to_table, Records};
+    ///
+    /// let value = json!([
+    ///     {"name": "C", "tags": ["static", "weak"]},
+    ///     {"name": "Rust", "tags": ["static", "strong"]},
+    /// ]);
+    ///
+    /// let table = json_to_table(&value)
+    ///     .records(Records::new().inline_nested(true))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+---------------------+\n",
+    ///         "| name | tags                |\n",
+    ///         "+------+---------------------+\n",
+    ///         "| C    | [\"static\",\"weak\"]   |\n",
+    ///         "+------+---------------------+\n",
+    ///         "| Rust | [\"static\",\"strong\"] |\n",
+    ///         "+------+---------------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn records(&mut self, records: Records) -> &mut Self {
+        self.table.records(records);
+        self
+    }
+
+    /// Render only a sub-tree of a value.
+    ///
+    /// A path is either a JSON Pointer (RFC 6901) like `/data/items`,
+    /// or a dotted path with an optional wildcard like `data.items[*].name`.
+    /// Values matched by a wildcard are collected into an array.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::json_to_table;
+    ///
+    /// let value = json!({
+    ///     "data": {
+    ///         "items": [
+    ///             {"name": "C", "year": 1972},
+    ///             {"name": "Rust", "year": 2010},
+    ///         ]
+    ///     }
+    /// });
+    ///
+    /// let table = json_to_table(&value)
+    ///     .select("data.items[*].name")
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+--------+\n",
+    ///         "|  C     |\n",
+    ///         "+--------+\n",
+    ///         "|  Rust  |\n",
+    ///         "+--------+",
+    ///     ),
+    /// );
+    ///
+    /// let table = json_to_table(&value)
+    ///     .select("/data/items/1")
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+--------+\n",
+    ///         "| name |  Rust  |\n",
+    ///         "+------+--------+\n",
+    ///         "| year |  2010  |\n",
+    ///         "+------+--------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn select<P>(&mut self, path: P) -> &mut Self
+    where
+        P: Into<String>,
+    {
+        self.select = Some(path.into());
+        self
+    }
+
+    /// Limit a depth of nested tables.
+    ///
+    /// Deeper objects and arrays are replaced by a placeholder like `{…3 keys}` or `[…12 items]`.
+    /// A placeholder is not a string, so it's neither quoted nor colored as a string.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::json_to_table;
+    ///
+    /// let value = json!({
+    ///     "name": "Rust",
+    ///     "team": {"lead": "-", "size": 10},
+    ///     "versions": ["1.0", "1.1", "1.2"],
+    /// });
+    ///
+    /// let table = json_to_table(&value)
+    ///     .max_depth(1)
+    ///     .collapse()
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+----------+------------+\n",
+    ///         "| name     | Rust       |\n",
+    ///         "+----------+------------+\n",
+    ///         "| team     | {…2 keys}  |\n",
+    ///         "+----------+------------+\n",
+    ///         "| versions | […3 items] |\n",
+    ///         "+----------+------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.table.max_depth(depth);
+        self
+    }
+
+    /// Set an order of object keys and filter them out.
+    ///
+    /// The configuration is applied at every depth, see [`Keys`].
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::{json_to_table, Keys};
+    ///
+    /// let value = json!([
+    ///     {"name": "C", "id": 1, "year": 1972},
+    ///     {"name": "Rust", "id": 2, "year": 2010},
+    /// ]);
+    ///
+    /// let table = json_to_table(&value)
+    ///     .keys(Keys::new().priority(["name"]).include(["id", "name"]))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-------------------+\n",
+    ///         "| +------+-----+    |\n",
+    ///         "| | name |  C  |    |\n",
+    ///         "| +------+-----+    |\n",
+    ///         "| | id   |  1  |    |\n",
+    ///         "| +------+-----+    |\n",
+    ///         "+-------------------+\n",
+    ///         "| +------+--------+ |\n",
+    ///         "| | name |  Rust  | |\n",
+    ///         "| +------+--------+ |\n",
+    ///         "| | id   |  2     | |\n",
+    ///         "| +------+--------+ |\n",
+    ///         "+-------------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn keys(&mut self, keys: Keys) -> &mut Self {
+        self.table.keys(keys);
+        self
+    }
+
+    /// Render strings quoted, so they can be told apart from numbers, booleans and `null`s.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::json_to_table;
+    ///
+    /// let value = json!({"name": "Rust", "year": "2010"});
+    ///
+    /// let table = json_to_table(&value)
+    ///     .quote_strings(true)
+    ///     .collapse()
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+--------+\n",
+    ///         "| name | \"Rust\" |\n",
+    ///         "+------+--------+\n",
+    ///         "| year | \"2010\" |\n",
+    ///         "+------+--------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn quote_strings(&mut self, on: bool) -> &mut Self {
+        self.table.quote_strings(on);
+        self
+    }
+
+    /// Align numbers to the right.
+    ///
+    /// In a collapsed mode only columns of [`Records`] are aligned,
+    /// as other cells are sized by a [`PoolTable`] itself.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::json_to_table;
+    ///
+    /// let value = json!({"downloads": 1200, "version": "1.0.0"});
+    ///
+    /// let table = json_to_table(&value)
+    ///     .right_align_numbers(true)
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-----------+---------+\n",
+    ///         "| downloads |   1200  |\n",
+    ///         "+-----------+---------+\n",
+    ///         "| version   |  1.0.0  |\n",
+    ///         "+-----------+---------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn right_align_numbers(&mut self, on: bool) -> &mut Self {
+        self.table.right_align_numbers(on);
+        self
+    }
+
+    /// Color values by their JSON type using a given [`Palette`].
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::{json_to_table, Palette};
+    /// use tabled::settings::Color;
+    ///
+    /// let value = json!({"stable": true, "year": 2010});
+    ///
+    /// let table = json_to_table(&value)
+    ///     .palette(Palette::new().boolean(Color::FG_RED))
+    ///     .to_string();
+    ///
+    /// assert!(table.contains("\u{1b}[31mtrue\u{1b}[39m"));
+    /// assert!(table.contains("\u{1b}[36m2010\u{1b}[39m"));
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn palette(&mut self, palette: Palette) -> &mut Self {
+        self.table.palette(palette);
         self
     }
 
@@ -273,8 +527,8 @@ impl<T> JsonTable<T> {
     /// };
     ///
     /// let value = json!({
-    ///     "key1": 123,
     ///     "234": ["123", "234", "456"],
+    ///     "key1": 123,
     ///     "key22": {
     ///         "k1": 1,
     ///         "k2": 2,
@@ -311,12 +565,9 @@ impl<T> JsonTable<T> {
     /// [`Table`]: tabled::Table
     pub fn with<O>(&mut self, option: O) -> &mut Self
     where
-        O: TableOption<EmptyRecords, ColoredConfig, CompleteDimension<'static>>,
+        O: TableOption<EmptyRecords, CompactMultilineConfig, CompleteDimension<'static>>,
     {
-        let mut records = EmptyRecords::default();
-        let mut dims = CompleteDimension::default();
-        option.change(&mut records, &mut self.cfg.cfg, &mut dims);
-
+        self.table.with(option);
         self
     }
 
@@ -352,7 +603,7 @@ impl<T> JsonTable<T> {
     /// assert_eq!(
     ///     table,
     ///     concat!(
-    ///         "| key1 | value1                                     |\n",
+    ///         "| key1 |  value1                                    |\n",
     ///         "|------|--------------------------------------------|\n",
     ///         "| key2 | ┌──────┬─────────────────────────────────┐ |\n",
     ///         "|      | │ key1 │  123                            │ |\n",
@@ -366,7 +617,7 @@ impl<T> JsonTable<T> {
     ///         "|      | │ │ key │  123.3  │ │     │       │        |\n",
     ///         "|      | │ └─────┴─────────┘ │     │       │        |\n",
     ///         "|      | └───────────────────┴─────┴───────┘        |\n",
-    ///         "| key4 | 1234.567                                   |",
+    ///         "| key4 |  1234.567                                  |",
     ///     ),
     /// )
     /// ```
@@ -404,7 +655,7 @@ impl<T> JsonTable<T> {
     ///     table,
     ///     concat!(
     ///         ".---------------------------------------------------.\n",
-    ///         "| key1 | value1                                     |\n",
+    ///         "| key1 |  value1                                    |\n",
     ///         "| key2 | ┌──────┬─────────────────────────────────┐ |\n",
     ///         "|      | │ key1 │  123                            │ |\n",
     ///         "|      | ├──────┼─────────────────────────────────┤ |\n",
@@ -417,7 +668,7 @@ impl<T> JsonTable<T> {
     ///         "|      | │ │ key │  123.3  │ │     │       │        |\n",
     ///         "|      | │ └─────┴─────────┘ │     │       │        |\n",
     ///         "|      | └───────────────────┴─────┴───────┘        |\n",
-    ///         "| key4 | 1234.567                                   |\n",
+    ///         "| key4 |  1234.567                                  |\n",
     ///         "'---------------------------------------------------'",
     ///     ),
     /// )
@@ -426,7 +677,7 @@ impl<T> JsonTable<T> {
     where
         T: Borrow<Value>,
     {
-        json_to_table(self.value.borrow(), &self.cfg)
+        self.table.into_table(&*self.select_value())
     }
 
     /// Convert the table into a [`PoolTable`].
@@ -456,29 +707,29 @@ impl<T> JsonTable<T> {
     /// assert_eq!(
     ///     table,
     ///     concat!(
-    ///        "+--------+-----------+\n",
-    ///        "| key1   | \"value1\"  |\n",
-    ///        "+-------++-----+-----+\n",
-    ///        "| key2  | key1 | 123 |\n",
-    ///        "|       +------++----+\n",
-    ///        "|       | key2  | 1  |\n",
-    ///        "|       |       +----+\n",
-    ///        "|       |       | 2  |\n",
-    ///        "|       |       +----+\n",
-    ///        "|       |       | 3  |\n",
-    ///        "|       |       +----+\n",
-    ///        "|       |       | 4  |\n",
-    ///        "|       |       +----+\n",
-    ///        "|       |       | 5  |\n",
-    ///        "+------++----+--+----+\n",
+    ///        "+------+-------------+\n",
+    ///        "| key1 | value1      |\n",
+    ///        "+------+------+------+\n",
+    ///        "| key2 | key1 | 123  |\n",
+    ///        "|      +------+------+\n",
+    ///        "|      | key2 | 1    |\n",
+    ///        "|      |      +------+\n",
+    ///        "|      |      | 2    |\n",
+    ///        "|      |      +------+\n",
+    ///        "|      |      | 3    |\n",
+    ///        "|      |      +------+\n",
+    ///        "|      |      | 4    |\n",
+    ///        "|      |      +------+\n",
+    ///        "|      |      | 5    |\n",
+    ///        "+------+-----++------+\n",
     ///        "| key3 | key | 123.3 |\n",
     ///        "|      +-----+-------+\n",
     ///        "|      | 2           |\n",
     ///        "|      +-------------+\n",
-    ///        "|      | \"asd\"       |\n",
-    ///        "+------+-+-----------+\n",
-    ///        "| key4   | 1234.567  |\n",
-    ///        "+--------+-----------+",
+    ///        "|      | asd         |\n",
+    ///        "+------+-------------+\n",
+    ///        "| key4 | 1234.567    |\n",
+    ///        "+------+-------------+",
     ///     ),
     /// )
     /// ```
@@ -486,7 +737,18 @@ impl<T> JsonTable<T> {
     where
         T: Borrow<Value>,
     {
-        json_to_pool_table(self.value.borrow(), &self.cfg)
+        self.table.into_pool_table(&*self.select_value())
+    }
+
+    fn select_value(&self) -> Cow<'_, Value>
+    where
+        T: Borrow<Value>,
+    {
+        let value = self.value.borrow();
+        match &self.select {
+            Some(path) => Cow::Owned(path::select(value, path)),
+            None => Cow::Borrowed(value),
+        }
     }
 }
 
@@ -495,7 +757,7 @@ where
     T: Borrow<Value>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let table = json_to_string(self.value.borrow(), &self.cfg);
+        let table = self.table.build(&*self.select_value());
         table.fmt(f)
     }
 }
@@ -517,167 +779,3 @@ where
         val.into_pool_table()
     }
 }
-
-#[derive(Debug, Clone)]
-struct Config {
-    plain: bool,
-    cfg: ColoredConfig,
-    object_orientation: Orientation,
-    array_orientation: Orientation,
-}
-
-/// The structure represents a table mode for a given entity,
-/// either it will be rendered vertically or horizontally.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
-pub enum Orientation {
-    /// Vertical mode (from top to bottom).
-    Row,
-    /// Horizontal mode (from left to right).
-    Column,
-}
-
-fn configure_grid() -> SpannedConfig {
-    let mut cfg = SpannedConfig::default();
-    cfg.set_padding(
-        Entity::Global,
-        Sides::new(
-            Indent::spaced(1),
-            Indent::spaced(1),
-            Indent::default(),
-            Indent::default(),
-        ),
-    );
-    cfg.set_alignment_horizontal(Entity::Global, AlignmentHorizontal::Left);
-    cfg.set_borders(Borders::from(Style::ascii()));
-
-    cfg
-}
-
-fn json_to_string(value: &Value, cfg: &Config) -> String {
-    match cfg.plain {
-        true => plain_table::plain_table(value, cfg),
-        false => collapsed_table::collapsed_table(value, cfg),
-    }
-}
-
-fn json_to_table(value: &Value, cfg: &Config) -> Table {
-    match value {
-        Value::Array(array) => {
-            let list = array
-                .iter()
-                .map(|value| json_to_string_inner(value, cfg))
-                .collect::<Vec<_>>();
-
-            match cfg.array_orientation {
-                Orientation::Row => Builder::from(vec![list]).build(),
-                Orientation::Column => {
-                    let list = list
-                        .into_iter()
-                        .map(|value| vec![value])
-                        .collect::<Vec<_>>();
-                    Builder::from(list).build()
-                }
-            }
-        }
-        Value::Object(map) => {
-            let list = map
-                .iter()
-                .map(|(key, value)| vec![key.clone(), json_to_string_inner(value, cfg)])
-                .collect::<Vec<_>>();
-
-            match cfg.object_orientation {
-                Orientation::Row => {
-                    let (keys, values) = list.into_iter().fold(
-                        (Vec::with_capacity(map.len()), Vec::with_capacity(map.len())),
-                        |(mut keys, mut values), mut row| {
-                            let value = row.pop().unwrap();
-                            let key = row.pop().unwrap();
-                            keys.push(key);
-                            values.push(value);
-
-                            (keys, values)
-                        },
-                    );
-                    let list = vec![keys, values];
-
-                    Builder::from(list).build()
-                }
-                Orientation::Column => Builder::from(list).build(),
-            }
-        }
-        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {
-            let value = json_value_to_string(value);
-            Builder::from(vec![vec![value]]).build()
-        }
-    }
-}
-
-fn json_to_pool_table(value: &Value, cfg: &Config) -> PoolTable {
-    let value = json_to_pool_table_value(value, cfg);
-    PoolTable::from(value)
-}
-
-fn json_to_pool_table_value(value: &Value, cfg: &Config) -> TableValue {
-    match value {
-        Value::Array(list) => {
-            let values = list
-                .iter()
-                .map(|value| json_to_pool_table_value(value, cfg))
-                .collect();
-
-            match cfg.array_orientation {
-                Orientation::Row => TableValue::Row(values),
-                Orientation::Column => TableValue::Column(values),
-            }
-        }
-        Value::Object(map) => match cfg.array_orientation {
-            Orientation::Row => {
-                let values = map
-                    .iter()
-                    .map(|(key, value)| {
-                        let key = TableValue::Cell(key.clone());
-                        let value = json_to_pool_table_value(value, cfg);
-                        TableValue::Column(vec![key, value])
-                    })
-                    .collect();
-
-                TableValue::Row(values)
-            }
-            Orientation::Column => {
-                let values = map
-                    .iter()
-                    .map(|(key, value)| {
-                        let key = TableValue::Cell(key.clone());
-                        let value = json_to_pool_table_value(value, cfg);
-                        TableValue::Row(vec![key, value])
-                    })
-                    .collect();
-
-                TableValue::Column(values)
-            }
-        },
-        value => TableValue::Cell(value.to_string()),
-    }
-}
-
-fn json_value_to_string(value: &Value) -> String {
-    match value {
-        Value::Null => String::new(),
-        Value::Bool(value) => value.to_string(),
-        Value::Number(value) => value.to_string(),
-        Value::String(value) => value.to_string(),
-        Value::Array(_) | Value::Object(_) => unreachable!(),
-    }
-}
-
-fn json_to_string_inner(value: &Value, cfg: &Config) -> String {
-    match cfg.plain {
-        true => match value {
-            Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {
-                json_value_to_string(value)
-            }
-            Value::Array(_) | Value::Object(_) => json_to_string(value, cfg),
-        },
-        false => json_to_string(value, cfg),
-    }
-}
@@ -0,0 +1,76 @@
+use serde_json::Value;
+
+/// Selects a sub-tree of a value.
+///
+/// A path is either a JSON Pointer (RFC 6901) like `/data/items/0`,
+/// or a dotted path like `data.items[*].name`.
+///
+/// In case a wildcard was used the matched values are collected into an array.
+/// If nothing was matched a [`Value::Null`] is returned.
+pub(super) fn select(value: &Value, path: &str) -> Value {
+    if path.is_empty() || path.starts_with('/') {
+        return value.pointer(path).cloned().unwrap_or(Value::Null);
+    }
+
+    let mut matches = vec![value];
+    let mut is_wildcard = false;
+    for segment in parse_path(path) {
+        let mut next = Vec::with_capacity(matches.len());
+        for value in matches {
+            match (&segment, value) {
+                (Segment::Key(key), Value::Object(map)) => next.extend(map.get(key.as_str())),
+                (Segment::Index(i), Value::Array(list)) => next.extend(list.get(*i)),
+                (Segment::Any, Value::Array(list)) => next.extend(list),
+                (Segment::Any, Value::Object(map)) => next.extend(map.values()),
+                _ => {}
+            }
+        }
+
+        is_wildcard |= segment == Segment::Any;
+        matches = next;
+    }
+
+    if is_wildcard {
+        return Value::Array(matches.into_iter().cloned().collect());
+    }
+
+    matches.first().map_or(Value::Null, |&value| value.clone())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    Any,
+}
+
+fn parse_path(path: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (key, mut rest) = match part.find('[') {
+            Some(i) => part.split_at(i),
+            None => (part, ""),
+        };
+
+        if key == "*" {
+            segments.push(Segment::Any);
+        } else if !key.is_empty() {
+            segments.push(Segment::Key(key.to_owned()));
+        }
+
+        while let Some(stripped) = rest.strip_prefix('[') {
+            let end = stripped.find(']').unwrap_or(stripped.len());
+            let index = &stripped[..end];
+            let segment = match index.parse::<usize>() {
+                Ok(i) => Segment::Index(i),
+                Err(_) if index == "*" => Segment::Any,
+                Err(_) => Segment::Key(index.trim_matches(['"', '\'']).to_owned()),
+            };
+
+            segments.push(segment);
+            rest = stripped.get(end + 1..).unwrap_or_default();
+        }
+    }
+
+    segments
+}
@@ -1,3 +1,5 @@
+#![cfg(not(feature = "preserve_order"))]
+
 use json_to_table::json_to_table;
 use serde_json::json;
 use tabled::settings::{Alignment, Padding, Style};
@@ -5,7 +7,7 @@ use tabled::settings::{Alignment, Padding, Style};
 use testing_table::test_table;
 
 #[cfg(feature = "ansi")]
-use tabled::{grid::ansi::ANSIBuf, grid::config::ColoredConfig};
+use tabled::grid::{ansi::ANSIStr, config::Borders, config::CompactMultilineConfig};
 
 test_table!(
     config_from_table_test,
@@ -105,9 +107,10 @@ test_table!(
     {
         struct ColorizeBorders;
 
-        impl<R, D> tabled::settings::TableOption<R, ColoredConfig, D> for ColorizeBorders {
-            fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
-                cfg.set_border_color_default(ANSIBuf::new("\u{1b}[34m", "\u{1b}[39m"));
+        impl<R, D> tabled::settings::TableOption<R, CompactMultilineConfig, D> for ColorizeBorders {
+            fn change(self, _: &mut R, cfg: &mut CompactMultilineConfig, _: &mut D) {
+                let color = ANSIStr::new("\u{1b}[34m", "\u{1b}[39m");
+                cfg.set_borders_color(Borders::filled(color));
             }
         }
 
@@ -0,0 +1,116 @@
+use json_to_table::{json_to_table, Orientation, Records};
+use serde_json::{json, Value};
+
+use testing_table::test_table;
+
+test_table!(
+    quote_strings_and_right_align_numbers_test,
+    json_to_table(&language()).quote_strings(true).right_align_numbers(true),
+    "+--------+-----------+"
+    "| name   |  \"Rust\"   |"
+    "+--------+-----------+"
+    "| stable |  true     |"
+    "+--------+-----------+"
+    "| tags   | +-------+ |"
+    "|        | |  \"a\"  | |"
+    "|        | +-------+ |"
+    "|        | |  1.5  | |"
+    "|        | +-------+ |"
+    "|        | |       | |"
+    "|        | +-------+ |"
+    "+--------+-----------+"
+    "| year   |     2010  |"
+    "+--------+-----------+"
+);
+
+test_table!(
+    quote_strings_and_right_align_numbers_collapsed_test,
+    json_to_table(&language()).quote_strings(true).right_align_numbers(true).collapse(),
+    "+--------+--------+"
+    "| name   | \"Rust\" |"
+    "+--------+--------+"
+    "| stable | true   |"
+    "+--------+--------+"
+    "| tags   | \"a\"    |"
+    "|        +--------+"
+    "|        | 1.5    |"
+    "|        +--------+"
+    "|        |        |"
+    "+--------+--------+"
+    "| year   | 2010   |"
+    "+--------+--------+"
+);
+
+test_table!(
+    quote_strings_and_right_align_numbers_into_table_test,
+    json_to_table(&language())
+        .quote_strings(true)
+        .right_align_numbers(true)
+        .object_orientation(Orientation::Row)
+        .into_table(),
+    "+----------+--------+-----------+--------+"
+    "| name     | stable | tags      | year   |"
+    "+----------+--------+-----------+--------+"
+    "|  \"Rust\"  |  true  | +-------+ |  2010  |"
+    "|          |        | |  \"a\"  | |        |"
+    "|          |        | +-------+ |        |"
+    "|          |        | |  1.5  | |        |"
+    "|          |        | +-------+ |        |"
+    "|          |        | |       | |        |"
+    "|          |        | +-------+ |        |"
+    "+----------+--------+-----------+--------+"
+);
+
+test_table!(
+    right_align_numbers_records_test,
+    json_to_table(&json!([{"name": "C", "year": 1972}, {"name": "Rust", "year": 10}]))
+        .records(Records::new())
+        .quote_strings(true)
+        .right_align_numbers(true),
+    "+--------+------+"
+    "| name   | year |"
+    "+--------+------+"
+    "| \"C\"    | 1972 |"
+    "+--------+------+"
+    "| \"Rust\" |   10 |"
+    "+--------+------+"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    palette_collapsed_test,
+    json_to_table(&scalars()).palette(json_to_table::Palette::new()).collapse(),
+    "+---+-------+"
+    "| \u{1b}[34ma\u{1b}[39m | \u{1b}[32mx\u{1b}[39m     |"
+    "+---+-------+"
+    "| \u{1b}[34mb\u{1b}[39m | \u{1b}[36m1\u{1b}[39m     |"
+    "+---+-------+"
+    "| \u{1b}[34mc\u{1b}[39m |       |"
+    "+---+-------+"
+    "| \u{1b}[34md\u{1b}[39m | \u{1b}[33mfalse\u{1b}[39m |"
+    "+---+-------+"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    palette_pool_table_test,
+    json_to_table(&scalars()).palette(json_to_table::Palette::new()).into_pool_table(),
+    "+---+-------+"
+    "| \u{1b}[34ma\u{1b}[39m | \u{1b}[32mx\u{1b}[39m     |"
+    "+---+-------+"
+    "| \u{1b}[34mb\u{1b}[39m | \u{1b}[36m1\u{1b}[39m     |"
+    "+---+-------+"
+    "| \u{1b}[34mc\u{1b}[39m |       |"
+    "+---+-------+"
+    "| \u{1b}[34md\u{1b}[39m | \u{1b}[33mfalse\u{1b}[39m |"
+    "+---+-------+"
+);
+
+fn language() -> Value {
+    json!({"name": "Rust", "stable": true, "tags": ["a", 1.5, null], "year": 2010})
+}
+
+#[cfg(feature = "ansi")]
+fn scalars() -> Value {
+    json!({"a": "x", "b": 1, "c": null, "d": false})
+}
@@ -0,0 +1,70 @@
+use json_to_table::iter::{from_reader, JsonRecords};
+use tabled::{grid::records::IntoRecords, tables::IterTable};
+
+use testing_table::test_table;
+
+test_table!(
+    iter_test,
+    from_reader(LINES.as_bytes()),
+    "+---+"
+    "| a |"
+    "+---+"
+    "| 1 |"
+    "+---+"
+    "| 2 |"
+    "+---+"
+    "|   |"
+    "+---+"
+);
+
+test_table!(
+    iter_sniff_test,
+    IterTable::new(JsonRecords::new(LINES.as_bytes()).sniff(3)),
+    "+---+-------+---+"
+    "| a | b     | c |"
+    "+---+-------+---+"
+    "| 1 |       |   |"
+    "+---+-------+---+"
+    "| 2 | [1,2] |   |"
+    "+---+-------+---+"
+    "|   |       |   |"
+    "+---+-------+---+"
+);
+
+test_table!(
+    iter_scalars_test,
+    from_reader("1\n\"text\"\ntrue\n".as_bytes()),
+    "+------+"
+    "| 1    |"
+    "+------+"
+    "| text |"
+    "+------+"
+    "| true |"
+    "+------+"
+);
+
+test_table!(
+    iter_malformed_test,
+    from_reader("{\"a\": 1}\n{\"a\": 2\n{\"a\": 3}".as_bytes()),
+    "+---+"
+    "| a |"
+    "+---+"
+    "| 1 |"
+    "+---+"
+);
+
+#[test]
+fn iter_status_test() {
+    let mut rows = JsonRecords::new("{\"a\": 1}\n{oops".as_bytes()).iter_rows();
+    assert!(rows.status().is_none());
+
+    while rows.next().is_some() {}
+
+    assert!(rows.status().is_some());
+}
+
+const LINES: &str = r#"
+{"a": 1}
+{"a": 2, "b": [1, 2]}
+{"c": null}
+"#;
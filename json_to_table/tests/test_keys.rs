@@ -0,0 +1,147 @@
+use json_to_table::{json_to_table, Keys, Orientation, Records};
+use serde_json::{json, Value};
+
+use testing_table::test_table;
+
+test_table!(
+    keys_priority_exclude_test,
+    json_to_table(&value()).keys(keys()),
+    "+----+--------------+"
+    "| id |  0           |"
+    "+----+--------------+"
+    "| a  | +----+-----+ |"
+    "|    | | id |  2  | |"
+    "|    | +----+-----+ |"
+    "|    | | z  |  1  | |"
+    "|    | +----+-----+ |"
+    "+----+--------------+"
+    "| b  |  1           |"
+    "+----+--------------+"
+);
+
+test_table!(
+    keys_priority_exclude_collapsed_test,
+    json_to_table(&value()).keys(keys()).collapse(),
+    "+----+--------+"
+    "| id | 0      |"
+    "+----+----+---+"
+    "| a  | id | 2 |"
+    "|    +----+---+"
+    "|    | z  | 1 |"
+    "+----+----+---+"
+    "| b  | 1      |"
+    "+----+--------+"
+);
+
+test_table!(
+    keys_priority_exclude_collapsed_row_test,
+    json_to_table(&value())
+        .keys(keys())
+        .object_orientation(Orientation::Row)
+        .collapse(),
+    "+----+--------+---+"
+    "| id | a      | b |"
+    "+----+----+---+---+"
+    "| 0  | id | z | 1 |"
+    "|    +----+---+   |"
+    "|    | 2  | 1 |   |"
+    "+----+----+---+---+"
+);
+
+test_table!(
+    keys_priority_exclude_pool_table_test,
+    json_to_table(&value()).keys(keys()).into_pool_table(),
+    "+----+--------+"
+    "| id | 0      |"
+    "+----+----+---+"
+    "| a  | id | 2 |"
+    "|    +----+---+"
+    "|    | z  | 1 |"
+    "+----+----+---+"
+    "| b  | 1      |"
+    "+----+--------+"
+);
+
+test_table!(
+    keys_include_test,
+    json_to_table(&value()).keys(Keys::new().include(["?", "id"])),
+    "+----+--------------+"
+    "| a  | +----+-----+ |"
+    "|    | | id |  2  | |"
+    "|    | +----+-----+ |"
+    "|    | | z  |  1  | |"
+    "|    | +----+-----+ |"
+    "+----+--------------+"
+    "| b  |  1           |"
+    "+----+--------------+"
+    "| id |  0           |"
+    "+----+--------------+"
+);
+
+test_table!(
+    keys_records_test,
+    json_to_table(&json!([
+        {"id": 1, "name": "C", "year": 1972},
+        {"id": 2, "name": "Rust", "year": 2010},
+    ]))
+    .records(Records::new())
+    .keys(Keys::new().priority(["name"])),
+    "+------+----+------+"
+    "| name | id | year |"
+    "+------+----+------+"
+    "| C    | 1  | 1972 |"
+    "+------+----+------+"
+    "| Rust | 2  | 2010 |"
+    "+------+----+------+"
+);
+
+#[cfg(feature = "preserve_order")]
+test_table!(
+    keys_preserve_order_test,
+    json_to_table(&json!({"b": 1, "id": 0, "a": 2})).collapse(),
+    "+----+---+"
+    "| b  | 1 |"
+    "+----+---+"
+    "| id | 0 |"
+    "+----+---+"
+    "| a  | 2 |"
+    "+----+---+"
+);
+
+#[cfg(feature = "preserve_order")]
+test_table!(
+    keys_preserve_order_sort_test,
+    json_to_table(&json!({"b": 1, "id": 0, "a": 2}))
+        .keys(Keys::new().sort(true))
+        .collapse(),
+    "+----+---+"
+    "| a  | 2 |"
+    "+----+---+"
+    "| b  | 1 |"
+    "+----+---+"
+    "| id | 0 |"
+    "+----+---+"
+);
+
+#[cfg(feature = "preserve_order")]
+test_table!(
+    keys_preserve_order_priority_test,
+    json_to_table(&json!({"b": 1, "id": 0, "a": 2}))
+        .keys(Keys::new().priority(["id"]))
+        .collapse(),
+    "+----+---+"
+    "| id | 0 |"
+    "+----+---+"
+    "| b  | 1 |"
+    "+----+---+"
+    "| a  | 2 |"
+    "+----+---+"
+);
+
+fn keys() -> Keys {
+    Keys::new().priority(["id"]).exclude(["*_tmp"])
+}
+
+fn value() -> Value {
+    json!({"a": {"id": 2, "x_tmp": 3, "z": 1}, "b": 1, "c_tmp": 4, "id": 0})
+}
@@ -5,6 +5,7 @@ use tabled::settings::Style;
 
 use testing_table::test_table;
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     orientation_test_1,
     json_to_table(&json!({
@@ -52,6 +53,7 @@ test_table!(
     "╚══════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     orientation_test_2,
     json_to_table(&json!({
@@ -253,6 +255,7 @@ test_table!(
         "└──────┴──────────────────────────────────────────────────────────┘"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_array_orientation_test_1,
     json_to_table(&json!({
@@ -277,9 +280,9 @@ test_table!(
     "║ widget ║ debug ║ on                                                    ║"
     "║        ╠═══════╬═══════════╦═══════════════════════════════════════════╣"
     "║        ║ text  ║ alignment ║ center                                    ║"
-    "║        ║       ╠═══════════╬═════════════════╦═══════════╦═════════════╣"
-    "║        ║       ║ data      ║ Click Here      ║ asda      ║ 123132      ║"
-    "║        ║       ╠═══════════╬═════════════════╩═══════════╩═════════════╣"
+    "║        ║       ╠═══════════╬════════════╦══════╦═══════════════════════╣"
+    "║        ║       ║ data      ║ Click Here ║ asda ║ 123132                ║"
+    "║        ║       ╠═══════════╬════════════╩══════╩═══════════════════════╣"
     "║        ║       ║ hOffset   ║ 250                                       ║"
     "║        ║       ╠═══════════╬═══════════════════════════════════════════╣"
     "║        ║       ║ name      ║ text1                                     ║"
@@ -294,6 +297,7 @@ test_table!(
     "╚════════╩═══════╩═══════════╩═══════════════════════════════════════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_array_orientation_test_2,
     json_to_table(&json!({
@@ -319,11 +323,11 @@ test_table!(
     "║ widget ║ debug ║ on                                                    ║"
     "║        ╠═══════╬═══════════╦═══════════════════════════════════════════╣"
     "║        ║ text  ║ alignment ║ center                                    ║"
-    "║        ║       ╠═══════════╬═════════════════╦═══════════╦═════════════╣"
-    "║        ║       ║ data      ║ Click Here      ║ asda      ║ 123132      ║"
-    "║        ║       ╠═══════════╬══════════╦═══╦══╩╦═══╦═════╦╩═══════╦═════╣"
-    "║        ║       ║ data1     ║ Click    ║ 1 ║ 3 ║ 4 ║ aa  ║ 123132 ║ 11  ║"
-    "║        ║       ╠═══════════╬══════════╩═══╩═══╩═══╩═════╩════════╩═════╣"
+    "║        ║       ╠═══════════╬════════════╦══════╦═══════════════════════╣"
+    "║        ║       ║ data      ║ Click Here ║ asda ║ 123132                ║"
+    "║        ║       ╠═══════════╬═══════╦═══╦╩══╦═══╬════╦════════╦═════════╣"
+    "║        ║       ║ data1     ║ Click ║ 1 ║ 3 ║ 4 ║ aa ║ 123132 ║ 11      ║"
+    "║        ║       ╠═══════════╬═══════╩═══╩═══╩═══╩════╩════════╩═════════╣"
     "║        ║       ║ hOffset   ║ 250                                       ║"
     "║        ║       ╠═══════════╬═══════════════════════════════════════════╣"
     "║        ║       ║ name      ║ text1                                     ║"
@@ -338,6 +342,7 @@ test_table!(
     "╚════════╩═══════╩═══════════╩═══════════════════════════════════════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_array_orientation_test_3,
     json_to_table(&json!({
@@ -363,11 +368,11 @@ test_table!(
     "║ widget ║ debug ║ on                                                    ║"
     "║        ╠═══════╬═══════════╦═══════════════════════════════════════════╣"
     "║        ║ text  ║ alignment ║ center                                    ║"
-    "║        ║       ╠═══════════╬═════════════════╦═══════════╦═════════════╣"
-    "║        ║       ║ data      ║ Click Here      ║ asda      ║ 123132      ║"
-    "║        ║       ╠═══════════╬═════════╦═══╦═══╬═══╦═════╦═╩═══════╦═════╣"
-    "║        ║       ║ data1     ║ Click   ║ 1 ║ 3 ║ 4 ║ aa  ║ 123132  ║ 11  ║"
-    "║        ║       ╠═══════════╬═════════╩═══╩═══╩═══╩═════╩═════════╩═════╣"
+    "║        ║       ╠═══════════╬════════════╦══════╦═══════════════════════╣"
+    "║        ║       ║ data      ║ Click Here ║ asda ║ 123132                ║"
+    "║        ║       ╠═══════════╬═══════╦═══╦╩══╦═══╬════╦════════╦═════════╣"
+    "║        ║       ║ data1     ║ Click ║ 1 ║ 3 ║ 4 ║ aa ║ 123132 ║ 11      ║"
+    "║        ║       ╠═══════════╬═══════╩═══╩═══╩═══╩════╩════════╩═════════╣"
     "║        ║       ║ hOffset   ║ 250                                       ║"
     "║        ║       ╠═══════════╬═══════════════════════════════════════════╣"
     "║        ║       ║ name      ║ text1                                     ║"
@@ -393,15 +398,15 @@ test_table!(
         .with(Style::extended())
         .array_orientation(Orientation::Row)
         .collapse(),
-    "╔═══════╦══════════════════════╦════════════════╦══════════════════╗"
-    "║ data  ║ Click Here           ║ asda           ║ 123132           ║"
-    "╠═══════╬══════════════════════╩════════════════╩══════════════════╣"
+    "╔═══════╦════════════╦══════╦══════════════════════════════════════╗"
+    "║ data  ║ Click Here ║ asda ║ 123132                               ║"
+    "╠═══════╬════════════╩══════╩══════════════════════════════════════╣"
     "║ data0 ║                                                          ║"
     "╠═══════╬═══════╦═══╦═══╦═══╦════╦════════╦════╦═══╦═══╦═══╦═══╦═══╣"
     "║ data1 ║ Click ║ 1 ║ 3 ║ 4 ║ aa ║ 123132 ║ 11 ║ 1 ║ 1 ║ 1 ║ 1 ║ 1 ║"
-    "╠═══════╬═══════╬═══╩╦══╩═╦═╩══╦═╩═════╦══╩════╬═══╩═══╬═══╩═╦═╩═══╣"
-    "║ data2 ║ C     ║ 1  ║ 3  ║ 4  ║ a     ║ 12    ║ 11    ║ 1   ║ 1   ║"
-    "╚═══════╩═══════╩════╩════╩════╩═══════╩═══════╩═══════╩═════╩═════╝"
+    "╠═══════╬═══╦═══╬═══╬═══╬═══╬════╬════╦═══╬════╩═══╩═══╩═══╩═══╩═══╣"
+    "║ data2 ║ C ║ 1 ║ 3 ║ 4 ║ a ║ 12 ║ 11 ║ 1 ║ 1                      ║"
+    "╚═══════╩═══╩═══╩═══╩═══╩═══╩════╩════╩═══╩════════════════════════╝"
 );
 
 test_table!(
@@ -414,13 +419,13 @@ test_table!(
         .with(Style::extended())
         .array_orientation(Orientation::Row)
         .collapse(),
-    "╔═══════╦════════════════════╦════════════╦══════════════╦═════════╗"
-    "║ data  ║ Click Here         ║ asda       ║ 123132       ║ 1       ║"
-    "╠═══════╬═══════╦═══╦═══╦═══╦╩═══╦════════╬════╦═══╦═══╦═╩═╦═══╦═══╣"
+    "╔═══════╦════════════╦══════╦════════╦═════════════════════════════╗"
+    "║ data  ║ Click Here ║ asda ║ 123132 ║ 1                           ║"
+    "╠═══════╬═══════╦═══╦╩══╦═══╬════╦═══╩════╦════╦═══╦═══╦═══╦═══╦═══╣"
     "║ data1 ║ Click ║ 1 ║ 3 ║ 4 ║ aa ║ 123132 ║ 11 ║ 1 ║ 1 ║ 1 ║ 1 ║ 1 ║"
-    "╠═══════╬═══════╬═══╩╦══╩═╦═╩══╦═╩═════╦══╩════╬═══╩═══╬═══╩═╦═╩═══╣"
-    "║ data2 ║ C     ║ 1  ║ 3  ║ 4  ║ a     ║ 12    ║ 11    ║ 1   ║ 1   ║"
-    "╚═══════╩═══════╩════╩════╩════╩═══════╩═══════╩═══════╩═════╩═════╝"
+    "╠═══════╬═══╦═══╬═══╬═══╬═══╬════╬════╦═══╬════╩═══╩═══╩═══╩═══╩═══╣"
+    "║ data2 ║ C ║ 1 ║ 3 ║ 4 ║ a ║ 12 ║ 11 ║ 1 ║ 1                      ║"
+    "╚═══════╩═══╩═══╩═══╩═══╩═══╩════╩════╩═══╩════════════════════════╝"
 );
 
 test_table!(
@@ -434,9 +439,9 @@ test_table!(
         .with(Style::extended())
         .array_orientation(Orientation::Row)
         .collapse(),
-    "╔═══════╦════════════════════╦════════════╦══════════════╦═════════╗"
-    "║ data  ║ Click Here         ║ asda       ║ 123132       ║ 1       ║"
-    "╠═══════╬═══════╦═══╦═══╦═══╦╩═══╦════════╬════╦═══╦═══╦═╩═╦═══╦═══╣"
+    "╔═══════╦════════════╦══════╦════════╦═════════════════════════════╗"
+    "║ data  ║ Click Here ║ asda ║ 123132 ║ 1                           ║"
+    "╠═══════╬═══════╦═══╦╩══╦═══╬════╦═══╩════╦════╦═══╦═══╦═══╦═══╦═══╣"
     "║ data1 ║ Click ║ 1 ║ 3 ║ 4 ║ aa ║ 123132 ║ 11 ║ 1 ║ 1 ║ 1 ║ 1 ║ 1 ║"
     "║       ║       ║   ║   ║   ╠════╬════════╣    ║   ║   ║   ║   ║   ║"
     "║       ║       ║   ║   ║   ║ bb ║ 123132 ║    ║   ║   ║   ║   ║   ║"
@@ -444,11 +449,12 @@ test_table!(
     "║ data2 ║ 11 ║ x ║ xxx                                             ║"
     "║       ╠════╬═══╩═════════════════════════════════════════════════╣"
     "║       ║ 22 ║ 1                                                   ║"
-    "╠═══════╬════╩══╦════╦════╦════╦═══════╦═══════╦═══════╦═════╦═════╣"
-    "║ data3 ║ C     ║ 1  ║ 3  ║ 4  ║ a     ║ 12    ║ 11    ║ 1   ║ 1   ║"
-    "╚═══════╩═══════╩════╩════╩════╩═══════╩═══════╩═══════╩═════╩═════╝"
+    "╠═══════╬═══╦╩══╦═══╦═══╦═══╦════╦════╦═══╦════════════════════════╣"
+    "║ data3 ║ C ║ 1 ║ 3 ║ 4 ║ a ║ 12 ║ 11 ║ 1 ║ 1                      ║"
+    "╚═══════╩═══╩═══╩═══╩═══╩═══╩════╩════╩═══╩════════════════════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_object_orientation_test_1,
     json_to_table(&json!({
@@ -484,6 +490,7 @@ test_table!(
     "╚═══════╩═══════════╩════════════╩═════════╩═══════╩═══════════════════════════════════════════╩══════╩═══════╩═════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_object_orientation_test_2,
     json_to_table(&json!({
@@ -513,21 +520,22 @@ test_table!(
     "║ on    ║ alignment ║ data       ║ data1  ║ hOffset ║ name  ║ onMouseUp                                 ║ size ║ style ║ vOffset ║"
     "║       ╠═══════════╬════════════╬════════╬═════════╬═══════╬═══════════════════════════════════════════╬══════╬═══════╬═════════╣"
     "║       ║ center    ║ Click Here ║ Click  ║ 250     ║ text1 ║ sun1.opacity = (sun1.opacity / 100) * 90; ║ 36   ║ bold  ║ 100     ║"
-    "║       ║           ║            ╠════════╣         ║       ║                                           ║      ║       ║         ║"
-    "║       ║           ║            ║ 1      ║         ║       ║                                           ║      ║       ║         ║"
-    "║       ║           ║            ╠════════╣         ║       ║                                           ║      ║       ║         ║"
-    "║       ║           ║            ║ 3      ║         ║       ║                                           ║      ║       ║         ║"
     "║       ║           ╠════════════╬════════╣         ║       ║                                           ║      ║       ║         ║"
-    "║       ║           ║ asda       ║ 4      ║         ║       ║                                           ║      ║       ║         ║"
+    "║       ║           ║ asda       ║ 1      ║         ║       ║                                           ║      ║       ║         ║"
+    "║       ║           ╠════════════╬════════╣         ║       ║                                           ║      ║       ║         ║"
+    "║       ║           ║ 123132     ║ 3      ║         ║       ║                                           ║      ║       ║         ║"
+    "║       ║           ║            ╠════════╣         ║       ║                                           ║      ║       ║         ║"
+    "║       ║           ║            ║ 4      ║         ║       ║                                           ║      ║       ║         ║"
     "║       ║           ║            ╠════════╣         ║       ║                                           ║      ║       ║         ║"
     "║       ║           ║            ║ aa     ║         ║       ║                                           ║      ║       ║         ║"
-    "║       ║           ╠════════════╬════════╣         ║       ║                                           ║      ║       ║         ║"
-    "║       ║           ║ 123132     ║ 123132 ║         ║       ║                                           ║      ║       ║         ║"
+    "║       ║           ║            ╠════════╣         ║       ║                                           ║      ║       ║         ║"
+    "║       ║           ║            ║ 123132 ║         ║       ║                                           ║      ║       ║         ║"
     "║       ║           ║            ╠════════╣         ║       ║                                           ║      ║       ║         ║"
     "║       ║           ║            ║ 11     ║         ║       ║                                           ║      ║       ║         ║"
     "╚═══════╩═══════════╩════════════╩════════╩═════════╩═══════╩═══════════════════════════════════════════╩══════╩═══════╩═════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_object_orientation_test_3,
     json_to_table(&json!({
@@ -557,16 +565,16 @@ test_table!(
     "│ on    │ alignment │ data       │ data1  │ hOffset │ name  │ onMouseUp                                 │ size │ style │ vOffset │"
     "│       ├───────────┼────────────┼────────┼─────────┼───────┼───────────────────────────────────────────┼──────┼───────┼─────────┤"
     "│       │ center    │ Click Here │ Click  │ 250     │ text1 │ sun1.opacity = (sun1.opacity / 100) * 90; │ 36   │ bold  │ 100     │"
-    "│       │           │            ├────────┤         │       │                                           │      │       │         │"
-    "│       │           │            │ 1      │         │       │                                           │      │       │         │"
-    "│       │           │            ├────────┤         │       │                                           │      │       │         │"
-    "│       │           │            │ 3      │         │       │                                           │      │       │         │"
     "│       │           ├────────────┼────────┤         │       │                                           │      │       │         │"
-    "│       │           │ asda       │ 4      │         │       │                                           │      │       │         │"
+    "│       │           │ asda       │ 1      │         │       │                                           │      │       │         │"
+    "│       │           ├────────────┼────────┤         │       │                                           │      │       │         │"
+    "│       │           │ 123132     │ 3      │         │       │                                           │      │       │         │"
+    "│       │           │            ├────────┤         │       │                                           │      │       │         │"
+    "│       │           │            │ 4      │         │       │                                           │      │       │         │"
     "│       │           │            ├────────┤         │       │                                           │      │       │         │"
     "│       │           │            │ aa     │         │       │                                           │      │       │         │"
-    "│       │           ├────────────┼────────┤         │       │                                           │      │       │         │"
-    "│       │           │ 123132     │ 123132 │         │       │                                           │      │       │         │"
+    "│       │           │            ├────────┤         │       │                                           │      │       │         │"
+    "│       │           │            │ 123132 │         │       │                                           │      │       │         │"
     "│       │           │            ├────────┤         │       │                                           │      │       │         │"
     "│       │           │            │ 11     │         │       │                                           │      │       │         │"
     "└───────┴───────────┴────────────┴────────┴─────────┴───────┴───────────────────────────────────────────┴──────┴───────┴─────────┘"
@@ -587,28 +595,28 @@ test_table!(
     "║ data       ║ data0 ║ data1  ║ data2 ║"
     "╠════════════╬═══════╬════════╬═══════╣"
     "║ Click Here ║       ║ Click  ║ C     ║"
-    "║            ║       ╠════════╣       ║"
-    "║            ║       ║ 1      ║       ║"
+    "╠════════════╣       ╠════════╬═══════╣"
+    "║ asda       ║       ║ 1      ║ 1     ║"
+    "╠════════════╣       ╠════════╬═══════╣"
+    "║ 123132     ║       ║ 3      ║ 3     ║"
     "║            ║       ╠════════╬═══════╣"
-    "║            ║       ║ 3      ║ 1     ║"
+    "║            ║       ║ 4      ║ 4     ║"
     "║            ║       ╠════════╬═══════╣"
-    "║            ║       ║ 4      ║ 3     ║"
-    "╠════════════║       ╠════════╬═══════╣"
-    "║ asda       ║       ║ aa     ║ 4     ║"
+    "║            ║       ║ aa     ║ a     ║"
     "║            ║       ╠════════╬═══════╣"
-    "║            ║       ║ 123132 ║ a     ║"
-    "║            ║       ╠════════╣       ║"
-    "║            ║       ║ 11     ╠═══════╣"
-    "║            ║       ╠════════╣ 12    ║"
-    "║            ║       ║ 1      ║       ║"
-    "╠════════════║       ╠════════╬═══════╣"
-    "║ 123132     ║       ║ 1      ║ 11    ║"
-    "║            ║       ╠════════╣       ║"
-    "║            ║       ║ 1      ║       ║"
+    "║            ║       ║ 123132 ║ 12    ║"
+    "║            ║       ╠════════╬═══════╣"
+    "║            ║       ║ 11     ║ 11    ║"
     "║            ║       ╠════════╬═══════╣"
     "║            ║       ║ 1      ║ 1     ║"
     "║            ║       ╠════════╬═══════╣"
     "║            ║       ║ 1      ║ 1     ║"
+    "║            ║       ╠════════╣       ║"
+    "║            ║       ║ 1      ║       ║"
+    "║            ║       ╠════════╣       ║"
+    "║            ║       ║ 1      ║       ║"
+    "║            ║       ╠════════╣       ║"
+    "║            ║       ║ 1      ║       ║"
     "╚════════════╩═══════╩════════╩═══════╝"
 );
 
@@ -626,28 +634,28 @@ test_table!(
     "║ data       ║ data1  ║ data2 ║"
     "╠════════════╬════════╬═══════╣"
     "║ Click Here ║ Click  ║ C     ║"
-    "║            ╠════════╣       ║"
-    "║            ║ 1      ║       ║"
-    "║            ╠════════╬═══════╣"
-    "║            ║ 3      ║ 1     ║"
     "╠════════════╬════════╬═══════╣"
-    "║ asda       ║ 4      ║ 3     ║"
+    "║ asda       ║ 1      ║ 1     ║"
+    "╠════════════╬════════╬═══════╣"
+    "║ 123132     ║ 3      ║ 3     ║"
+    "╠════════════╬════════╬═══════╣"
+    "║ 1          ║ 4      ║ 4     ║"
     "║            ╠════════╬═══════╣"
-    "║            ║ aa     ║ 4     ║"
+    "║            ║ aa     ║ a     ║"
     "║            ╠════════╬═══════╣"
-    "║            ║ 123132 ║ a     ║"
-    "╠════════════╬════════╣       ║"
-    "║ 123132     ║ 11     ╠═══════╣"
-    "║            ╠════════╣ 12    ║"
-    "║            ║ 1      ║       ║"
+    "║            ║ 123132 ║ 12    ║"
     "║            ╠════════╬═══════╣"
-    "║            ║ 1      ║ 11    ║"
-    "╠════════════╬════════╣       ║"
-    "║ 1          ║ 1      ║       ║"
+    "║            ║ 11     ║ 11    ║"
     "║            ╠════════╬═══════╣"
     "║            ║ 1      ║ 1     ║"
     "║            ╠════════╬═══════╣"
     "║            ║ 1      ║ 1     ║"
+    "║            ╠════════╣       ║"
+    "║            ║ 1      ║       ║"
+    "║            ╠════════╣       ║"
+    "║            ║ 1      ║       ║"
+    "║            ╠════════╣       ║"
+    "║            ║ 1      ║       ║"
     "╚════════════╩════════╩═══════╝"
 );
 
@@ -666,31 +674,32 @@ test_table!(
     "║ data       ║ data1           ║ data2    ║ data3 ║"
     "╠════════════╬═════════════════╬═════╦════╬═══════╣"
     "║ Click Here ║ Click           ║ 11  ║ 22 ║ C     ║"
-    "║            ╠═════════════════╬═════╬════╣       ║"
-    "║            ║ 1               ║ x   ║ 1  ║       ║"
-    "║            ╠═════════════════╬═════╣    ╠═══════╣"
-    "║            ║ 3               ║ xxx ║    ║ 1     ║"
+    "╠════════════╬═════════════════╬═════╬════╬═══════╣"
+    "║ asda       ║ 1               ║ x   ║ 1  ║ 1     ║"
+    "╠════════════╬═════════════════╬═════╣    ╠═══════╣"
+    "║ 123132     ║ 3               ║ xxx ║    ║ 3     ║"
     "╠════════════╬═════════════════╣     ║    ╠═══════╣"
-    "║ asda       ║ 4               ║     ║    ║ 3     ║"
+    "║ 1          ║ 4               ║     ║    ║ 4     ║"
     "║            ╠════════╦════════╣     ║    ╠═══════╣"
-    "║            ║ aa     ║ bb     ║     ║    ║ 4     ║"
+    "║            ║ aa     ║ bb     ║     ║    ║ a     ║"
     "║            ╠════════╬════════╣     ║    ╠═══════╣"
-    "║            ║ 123132 ║ 123132 ║     ║    ║ a     ║"
-    "╠════════════╬════════╩════════╣     ║    ║       ║"
-    "║ 123132     ║ 11              ║     ║    ╠═══════╣"
-    "║            ╠═════════════════║     ║    ║ 12    ║"
-    "║            ║ 1               ║     ║    ║       ║"
-    "║            ╠═════════════════║     ║    ╠═══════╣"
-    "║            ║ 1               ║     ║    ║ 11    ║"
-    "╠════════════╬═════════════════║     ║    ║       ║"
-    "║ 1          ║ 1               ║     ║    ║       ║"
-    "║            ╠═════════════════║     ║    ╠═══════╣"
+    "║            ║ 123132 ║ 123132 ║     ║    ║ 12    ║"
+    "║            ╠════════╩════════╣     ║    ╠═══════╣"
+    "║            ║ 11              ║     ║    ║ 11    ║"
+    "║            ╠═════════════════╣     ║    ╠═══════╣"
     "║            ║ 1               ║     ║    ║ 1     ║"
-    "║            ╠═════════════════║     ║    ╠═══════╣"
+    "║            ╠═════════════════╣     ║    ╠═══════╣"
     "║            ║ 1               ║     ║    ║ 1     ║"
+    "║            ╠═════════════════╣     ║    ║       ║"
+    "║            ║ 1               ║     ║    ║       ║"
+    "║            ╠═════════════════╣     ║    ║       ║"
+    "║            ║ 1               ║     ║    ║       ║"
+    "║            ╠═════════════════╣     ║    ║       ║"
+    "║            ║ 1               ║     ║    ║       ║"
     "╚════════════╩═════════════════╩═════╩════╩═══════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_object_array_orientation_test_1,
     json_to_table(&json!({
@@ -723,6 +732,7 @@ test_table!(
     "╚═══════╩═══════════╩════════════╩══════╩════════╩═════════╩═══════╩═══════════════════════════════════════════╩══════╩═══════╩═════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_object_array_orientation_test_2,
     json_to_table(&json!({
@@ -758,6 +768,7 @@ test_table!(
     "╚═══════╩═══════════╩════════════╩══════╩════════╩═══════╩═══╩═══╩═══╩════╩════════╩═════════╩═══════╩═══════════════════════════════════════════╩══════╩═══════╩═════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_object_array_orientation_test_3,
     json_to_table(&json!({
@@ -851,6 +862,7 @@ test_table!(
     "╚════════════╩══════╩════════╩═══╩═══════╩═══╩═══╩═══╩════════╩════════╩════╩═══╩═══╩═══╩═══╩═══╩═════╩════╩═══╩═══╩═══╩═══╩═══╩════╩════╩═══╩═══╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     collapse_object_array_orientation_test_7,
     json_to_table(&json!({
@@ -0,0 +1,128 @@
+use json_to_table::{json_to_table, Records};
+use serde_json::{json, Value};
+
+use testing_table::test_table;
+
+test_table!(
+    records_test,
+    json_to_table(&fruits()).records(Records::new()),
+    "+----+-------------------+--------+-------------+-------+"
+    "| id | meta              | name   | tags        | price |"
+    "+----+-------------------+--------+-------------+-------+"
+    "| 1  | +--------+------+ | Apple  | +---------+ |       |"
+    "|    | | origin |  PL  | |        | |  red    | |       |"
+    "|    | +--------+------+ |        | +---------+ |       |"
+    "|    |                   |        | |  sweet  | |       |"
+    "|    |                   |        | +---------+ |       |"
+    "+----+-------------------+--------+-------------+-------+"
+    "| 2  |                   | Banana |             | 1.5   |"
+    "+----+-------------------+--------+-------------+-------+"
+    "| 3  |                   | Cherry |             | 12    |"
+    "+----+-------------------+--------+-------------+-------+"
+);
+
+test_table!(
+    records_sorted_inline_missing_test,
+    json_to_table(&fruits()).records(
+        Records::new()
+            .sort_keys(true)
+            .missing("null")
+            .inline_nested(true)
+    ),
+    "+----+-----------------+--------+-------+-----------------+"
+    "| id | meta            | name   | price | tags            |"
+    "+----+-----------------+--------+-------+-----------------+"
+    "| 1  | {\"origin\":\"PL\"} | Apple  | null  | [\"red\",\"sweet\"] |"
+    "+----+-----------------+--------+-------+-----------------+"
+    "| 2  |                 | Banana | 1.5   | null            |"
+    "+----+-----------------+--------+-------+-----------------+"
+    "| 3  | null            | Cherry | 12    | null            |"
+    "+----+-----------------+--------+-------+-----------------+"
+);
+
+test_table!(
+    records_nested_test,
+    json_to_table(&json!({
+        "count": 2,
+        "items": [{"a": 2, "b": 1}, {"c": 3}],
+        "mixed": [1, {"a": 1}],
+    }))
+    .records(Records::new()),
+    "+-------+-----------------+"
+    "| count |  2              |"
+    "+-------+-----------------+"
+    "| items | +---+---+---+   |"
+    "|       | | a | b | c |   |"
+    "|       | +---+---+---+   |"
+    "|       | | 2 | 1 |   |   |"
+    "|       | +---+---+---+   |"
+    "|       | |   |   | 3 |   |"
+    "|       | +---+---+---+   |"
+    "+-------+-----------------+"
+    "| mixed | +-------------+ |"
+    "|       | |  1          | |"
+    "|       | +-------------+ |"
+    "|       | | +---+-----+ | |"
+    "|       | | | a |  1  | | |"
+    "|       | | +---+-----+ | |"
+    "|       | +-------------+ |"
+    "+-------+-----------------+"
+);
+
+test_table!(
+    records_into_table_test,
+    json_to_table(&fruits())
+        .records(Records::new().inline_nested(true))
+        .into_table(),
+    "+----+-----------------+--------+-----------------+-------+"
+    "| id | meta            | name   | tags            | price |"
+    "+----+-----------------+--------+-----------------+-------+"
+    "| 1  | {\"origin\":\"PL\"} | Apple  | [\"red\",\"sweet\"] |       |"
+    "+----+-----------------+--------+-----------------+-------+"
+    "| 2  |                 | Banana |                 | 1.5   |"
+    "+----+-----------------+--------+-----------------+-------+"
+    "| 3  |                 | Cherry |                 | 12    |"
+    "+----+-----------------+--------+-----------------+-------+"
+);
+
+test_table!(
+    records_into_pool_table_test,
+    json_to_table(&fruits())
+        .records(Records::new().inline_nested(true))
+        .into_pool_table(),
+    "+----+-----------------+--------+-----------------+-------+"
+    "| id | meta            | name   | tags            | price |"
+    "+----+-----------------+--------+-----------------+-------+"
+    "| 1  | {\"origin\":\"PL\"} | Apple  | [\"red\",\"sweet\"] |       |"
+    "+----+-----------------+--------+-----------------+-------+"
+    "| 2  |                 | Banana |                 | 1.5   |"
+    "+----+-----------------+--------+-----------------+-------+"
+    "| 3  |                 | Cherry |                 | 12    |"
+    "+----+-----------------+--------+-----------------+-------+"
+);
+
+test_table!(
+    records_collapsed_test,
+    json_to_table(&fruits())
+        .records(Records::new().missing("-"))
+        .collapse(),
+    "+----+-------------+--------+-------+-------+"
+    "| id | meta        | name   | tags  | price |"
+    "+----+--------+----+--------+-------+-------+"
+    "| 1  | origin | PL | Apple  | red   | -     |"
+    "|    |        |    |        +-------+       |"
+    "|    |        |    |        | sweet |       |"
+    "+----+--------+----+--------+-------+-------+"
+    "| 2  |             | Banana | -     | 1.5   |"
+    "+----+-------------+--------+-------+-------+"
+    "| 3  | -           | Cherry | -     | 12    |"
+    "+----+-------------+--------+-------+-------+"
+);
+
+fn fruits() -> Value {
+    json!([
+        {"id": 1, "meta": {"origin": "PL"}, "name": "Apple", "tags": ["red", "sweet"]},
+        {"id": 2, "meta": null, "name": "Banana", "price": 1.5},
+        {"id": 3, "name": "Cherry", "price": 12}
+    ])
+}
@@ -0,0 +1,150 @@
+use json_to_table::json_to_table;
+use serde_json::{json, Value};
+
+use testing_table::test_table;
+
+test_table!(
+    select_pointer_test,
+    json_to_table(&value()).select("/data/items/0/tags"),
+    "+----------+"
+    "|  static  |"
+    "+----------+"
+    "|  weak    |"
+    "+----------+"
+);
+
+test_table!(
+    select_pointer_escaped_test,
+    json_to_table(&value()).select("/data/a~1b"),
+    "+---+-----+"
+    "| x |  1  |"
+    "+---+-----+"
+);
+
+test_table!(
+    select_wildcard_test,
+    json_to_table(&value())
+        .select("data.items[*].meta.year")
+        .into_table(),
+    "+--------+"
+    "|  1972  |"
+    "+--------+"
+    "|  2010  |"
+    "+--------+"
+);
+
+test_table!(
+    select_index_collapsed_test,
+    json_to_table(&value())
+        .select("data.items[1].meta")
+        .collapse(),
+    "+---------+---------------+"
+    "| authors | Graydon Hoare |"
+    "+---------+---------------+"
+    "| year    | 2010          |"
+    "+---------+---------------+"
+);
+
+#[test]
+fn select_unknown_path_test() {
+    let table = json_to_table(&value()).select("/data/unknown").to_string();
+    assert_eq!(table, "");
+}
+
+test_table!(
+    max_depth_zero_test,
+    json_to_table(&value()).max_depth(0),
+    "+-----------+"
+    "| {…2 keys} |"
+    "+-----------+"
+);
+
+test_table!(
+    max_depth_test,
+    json_to_table(&value()).max_depth(2),
+    "+-------+--------------------------+"
+    "| data  | +-------+--------------+ |"
+    "|       | | a/b   |  {…1 key}    | |"
+    "|       | +-------+--------------+ |"
+    "|       | | items |  […2 items]  | |"
+    "|       | +-------+--------------+ |"
+    "+-------+--------------------------+"
+    "| total |  2                       |"
+    "+-------+--------------------------+"
+);
+
+test_table!(
+    select_and_max_depth_collapsed_test,
+    json_to_table(&value())
+        .select("data.items")
+        .max_depth(2)
+        .collapse(),
+    "+------+------------+"
+    "| meta | {…2 keys}  |"
+    "+------+------------+"
+    "| name | C          |"
+    "+------+------------+"
+    "| tags | […2 items] |"
+    "+------+------------+"
+    "| meta | {…2 keys}  |"
+    "+------+------------+"
+    "| name | Rust       |"
+    "+------+------------+"
+    "| tags | […3 items] |"
+    "+------+------------+"
+);
+
+test_table!(
+    max_depth_quote_strings_test,
+    json_to_table(&value())
+        .select("/data/items/0")
+        .max_depth(1)
+        .quote_strings(true)
+        .collapse(),
+    "+------+------------+"
+    "| meta | {…2 keys}  |"
+    "+------+------------+"
+    "| name | \"C\"        |"
+    "+------+------------+"
+    "| tags | […2 items] |"
+    "+------+------------+"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    max_depth_quote_strings_palette_test,
+    json_to_table(&value())
+        .select("/data/items/0")
+        .max_depth(1)
+        .quote_strings(true)
+        .palette(json_to_table::Palette::new())
+        .collapse(),
+    "+------+------------+"
+    "| \u{1b}[34mmeta\u{1b}[39m | \u{1b}[35m{…2 keys}\u{1b}[39m  |"
+    "+------+------------+"
+    "| \u{1b}[34mname\u{1b}[39m | \u{1b}[32m\"C\"\u{1b}[39m        |"
+    "+------+------------+"
+    "| \u{1b}[34mtags\u{1b}[39m | \u{1b}[35m[…2 items]\u{1b}[39m |"
+    "+------+------------+"
+);
+
+fn value() -> Value {
+    json!({
+        "data": {
+            "a/b": {"x": 1},
+            "items": [
+                {
+                    "meta": {"authors": ["Dennis Ritchie"], "year": 1972},
+                    "name": "C",
+                    "tags": ["static", "weak"]
+                },
+                {
+                    "meta": {"authors": ["Graydon Hoare"], "year": 2010},
+                    "name": "Rust",
+                    "tags": ["static", "strong", "affine"]
+                }
+            ]
+        },
+        "total": 2
+    })
+}
@@ -5,6 +5,7 @@ use tabled::settings::Style;
 
 use testing_table::test_table;
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     general_json_test_0,
     json_to_table(&json!(
@@ -85,6 +86,7 @@ test_table!(
     "╚════════╩══════════════════════════════════════════════════════════════════════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     general_json_test_1,
     json_to_table(&json!(
@@ -158,6 +160,7 @@ test_table!(
     "╚════════╩════════╩════════╩══════════════════════════════════════════════╝"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     general_json_test_3,
     json_to_table(&json!(
@@ -223,6 +226,7 @@ test_table!(
 
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     general_json_test_4,
     json_to_table(&json!(
@@ -657,6 +661,7 @@ test_table!(
     "└──────┴─────┴─────────┘"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     array_split_with_inner_array_test,
     json_to_table(&json!(
@@ -757,6 +762,7 @@ test_table!(
     "└────────┴───────┘"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     test_map_empty_entity_collapsed_2,
     json_to_table(&json!(
@@ -884,6 +890,7 @@ test_table!(
     "└────────┴───────┘"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     test_map_empty_entity_collapsed_2_horizontal,
     json_to_table(&json!(
@@ -985,6 +992,7 @@ test_table!(
     "└────────┴────────┴────────┴────────┴────────┘"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     test_map_empty_entity_collapsed_2_horizontal_map,
     json_to_table(&json!(
@@ -1157,6 +1165,7 @@ test_table!(
     "└──┘"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     test_multiline_key_height_bigger_then_value,
     json_to_table(&json!(
@@ -1177,6 +1186,7 @@ test_table!(
     "└────────┴────────┴──────────────────────────────┘"
 );
 
+#[cfg(not(feature = "preserve_order"))]
 test_table!(
     test_multiline_key_height_less_then_value,
     json_to_table(&json!(
@@ -4,6 +4,9 @@
 //!     1. Embeded (default)
 //!     2. Collapsed
 //!
+//! A [`ron::Value`] loses names of structs and enum variants,
+//! parse a document into a [`NamedValue`] to keep them.
+//!
 //! # Examples
 //!
 //! Embeded table.
@@ -132,10 +135,13 @@
 
 use ron::Value;
 
-pub use orientation::Orientation;
+pub use named::NamedValue;
 pub use table::RonTable;
+pub use tree_to_table::Orientation;
+#[cfg(feature = "ansi")]
+pub use tree_to_table::Palette;
 
-mod orientation;
+mod named;
 mod table;
 
 /// The function converts a given [`Value`] to a pretty table.
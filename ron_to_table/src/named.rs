@@ -0,0 +1,540 @@
+use std::{borrow::Cow, str::FromStr};
+
+use ron::error::{Error, Position, SpannedError};
+use tree_to_table::{Key, Node, ScalarKind, TreeValue};
+
+/// A RON value which keeps names of structs and enum variants,
+/// which are lost by [`ron::Value`].
+///
+/// A struct name is rendered as a title of its table,
+/// a unit struct or a unit variant is rendered as its name,
+/// a variant with a single scalar is rendered as a tagged cell like `Circle(5)`,
+/// and a tuple struct is rendered as a row with a column per field.
+///
+/// ```
+/// use ron_to_table::{NamedValue, RonTable};
+///
+/// let value: NamedValue = "Scene(size: Size(800, 600), mode: Windowed, shape: Circle(5))"
+///     .parse()
+///     .unwrap();
+///
+/// let table = RonTable::default().build_named(&value);
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+-Scene-+-------------------+\n",
+///         "| size  | +-Size--+-------+ |\n",
+///         "|       | |  800  |  600  | |\n",
+///         "|       | +-------+-------+ |\n",
+///         "+-------+-------------------+\n",
+///         "| mode  |  Windowed         |\n",
+///         "+-------+-------------------+\n",
+///         "| shape |  Circle(5)        |\n",
+///         "+-------+-------------------+",
+///     ),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum NamedValue {
+    /// A unit value `()`.
+    Unit,
+    /// A boolean.
+    Bool(bool),
+    /// A number, shown the same way as by [`ron::Value`],
+    /// so `0x1F` becomes `31` and `90.0` becomes `90`.
+    Number(String),
+    /// A character.
+    Char(char),
+    /// A string.
+    String(String),
+    /// An optional value.
+    Option(Option<Box<NamedValue>>),
+    /// A list `[a, b]`.
+    Seq(Vec<NamedValue>),
+    /// A map `{a: b}`.
+    Map(Vec<(NamedValue, NamedValue)>),
+    /// A tuple `(a, b)` or a tuple struct `Name(a, b)`.
+    Tuple(Option<String>, Vec<NamedValue>),
+    /// A struct `(a: b)` or a named struct `Name(a: b)`.
+    Struct(Option<String>, Vec<(String, NamedValue)>),
+    /// A unit struct or a unit variant `Name`.
+    Ident(String),
+}
+
+impl FromStr for NamedValue {
+    type Err = SpannedError;
+
+    /// Parses a RON document.
+    ///
+    /// A document is validated by [`ron`] first,
+    /// so errors are the same as the ones of [`ron::from_str`].
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        ron::from_str::<ron::Value>(text)?;
+
+        let mut parser = Parser { text, pos: 0 };
+        parser.skip_attributes()?;
+        let value = parser.value()?;
+
+        Ok(value)
+    }
+}
+
+impl TreeValue for NamedValue {
+    fn node(&self) -> Node<'_, Self> {
+        match self {
+            NamedValue::Unit | NamedValue::Option(None) => {
+                Node::Scalar(ScalarKind::Null, Cow::Borrowed(""))
+            }
+            NamedValue::Bool(value) => {
+                Node::Scalar(ScalarKind::Boolean, Cow::Owned(value.to_string()))
+            }
+            NamedValue::Number(value) => Node::Scalar(ScalarKind::Number, Cow::Borrowed(value)),
+            NamedValue::Char(value) => {
+                Node::Scalar(ScalarKind::String, Cow::Owned(value.to_string()))
+            }
+            NamedValue::String(value) => Node::Scalar(ScalarKind::String, Cow::Borrowed(value)),
+            NamedValue::Option(Some(value)) => value.node(),
+            NamedValue::Seq(list) => Node::Seq(list.iter().collect()),
+            NamedValue::Map(map) => Node::Map(
+                map.iter()
+                    .map(|(key, value)| (Key::Value(key), value))
+                    .collect(),
+            ),
+            NamedValue::Tuple(Some(name), list) if list.is_empty() => {
+                Node::Scalar(ScalarKind::Other, Cow::Borrowed(name))
+            }
+            NamedValue::Tuple(Some(name), list) if list.len() == 1 => match list[0].node() {
+                Node::Scalar(_, text) => {
+                    Node::Scalar(ScalarKind::Other, Cow::Owned(format!("{}({})", name, text)))
+                }
+                _ => Node::Tuple(list.iter().collect()),
+            },
+            NamedValue::Tuple(_, list) => Node::Tuple(list.iter().collect()),
+            NamedValue::Struct(_, fields) => Node::Map(
+                fields
+                    .iter()
+                    .map(|(key, value)| (Key::Text(Cow::Borrowed(key.as_str())), value))
+                    .collect(),
+            ),
+            NamedValue::Ident(name) => Node::Scalar(ScalarKind::Other, Cow::Borrowed(name)),
+        }
+    }
+
+    fn name(&self) -> Option<Cow<'_, str>> {
+        match self {
+            NamedValue::Option(Some(value)) => value.name(),
+            NamedValue::Tuple(Some(name), _) | NamedValue::Struct(Some(name), _) => {
+                Some(Cow::Borrowed(name))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A reader of a RON document which was already validated,
+/// so it only collects values and their names.
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn value(&mut self) -> Result<NamedValue, SpannedError> {
+        self.skip_ws()?;
+
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Err(self.error(Error::Eof)),
+        };
+
+        match c {
+            '(' => {
+                self.pos += 1;
+                self.struct_like(None)
+            }
+            '[' => {
+                self.pos += 1;
+                self.list().map(NamedValue::Seq)
+            }
+            '{' => {
+                self.pos += 1;
+                self.map()
+            }
+            '"' => self.string(),
+            '\'' => self.char(),
+            'r' if self.is_raw_string() => self.string(),
+            '0'..='9' | '+' | '-' | '.' => self.number(),
+            _ => self.ident_value(),
+        }
+    }
+
+    fn ident_value(&mut self) -> Result<NamedValue, SpannedError> {
+        let ident = self.ident()?;
+        match ident {
+            "true" => return Ok(NamedValue::Bool(true)),
+            "false" => return Ok(NamedValue::Bool(false)),
+            "None" => return Ok(NamedValue::Option(None)),
+            "inf" | "NaN" => return Ok(NamedValue::Number(format_number(ident)?)),
+            _ => {}
+        }
+
+        let name = ident.strip_prefix("r#").unwrap_or(ident).to_owned();
+
+        self.skip_ws()?;
+        if !self.consume('(') {
+            return Ok(NamedValue::Ident(name));
+        }
+
+        if name == "Some" {
+            let value = self.value()?;
+            self.skip_ws()?;
+            self.consume(',');
+            self.skip_ws()?;
+            self.expect(')', Error::ExpectedOptionEnd)?;
+
+            return Ok(NamedValue::Option(Some(Box::new(value))));
+        }
+
+        self.struct_like(Some(name))
+    }
+
+    /// Reads a tuple or a struct after an opening `(`.
+    fn struct_like(&mut self, name: Option<String>) -> Result<NamedValue, SpannedError> {
+        self.skip_ws()?;
+        if self.consume(')') {
+            return match name {
+                Some(name) => Ok(NamedValue::Tuple(Some(name), Vec::new())),
+                None => Ok(NamedValue::Unit),
+            };
+        }
+
+        if !self.is_field() {
+            let list = self.items(')')?;
+            return Ok(NamedValue::Tuple(name, list));
+        }
+
+        let mut fields = Vec::new();
+        loop {
+            self.skip_ws()?;
+            if self.consume(')') {
+                break;
+            }
+
+            let field = self.ident()?;
+            let field = field.strip_prefix("r#").unwrap_or(field).to_owned();
+            self.skip_ws()?;
+            self.expect(':', Error::ExpectedMapColon)?;
+            let value = self.value()?;
+            fields.push((field, value));
+
+            self.skip_ws()?;
+            if !self.consume(',') {
+                self.skip_ws()?;
+                self.expect(')', Error::ExpectedStructLikeEnd)?;
+                break;
+            }
+        }
+
+        Ok(NamedValue::Struct(name, fields))
+    }
+
+    fn list(&mut self) -> Result<Vec<NamedValue>, SpannedError> {
+        self.items(']')
+    }
+
+    /// Reads comma separated values up to a given closing character.
+    fn items(&mut self, end: char) -> Result<Vec<NamedValue>, SpannedError> {
+        let mut list = Vec::new();
+        loop {
+            self.skip_ws()?;
+            if self.consume(end) {
+                break;
+            }
+
+            list.push(self.value()?);
+
+            self.skip_ws()?;
+            if !self.consume(',') {
+                self.skip_ws()?;
+                self.expect(end, Error::ExpectedComma)?;
+                break;
+            }
+        }
+
+        Ok(list)
+    }
+
+    fn map(&mut self) -> Result<NamedValue, SpannedError> {
+        let mut map = Vec::new();
+        loop {
+            self.skip_ws()?;
+            if self.consume('}') {
+                break;
+            }
+
+            let key = self.value()?;
+            self.skip_ws()?;
+            self.expect(':', Error::ExpectedMapColon)?;
+            let value = self.value()?;
+            map.push((key, value));
+
+            self.skip_ws()?;
+            if !self.consume(',') {
+                self.skip_ws()?;
+                self.expect('}', Error::ExpectedMapEnd)?;
+                break;
+            }
+        }
+
+        Ok(NamedValue::Map(map))
+    }
+
+    /// Reads a string, leaving unescaping to [`ron`].
+    fn string(&mut self) -> Result<NamedValue, SpannedError> {
+        let start = self.pos;
+        let rest = &self.text[start..];
+
+        let len = if let Some(raw) = rest.strip_prefix('r') {
+            let hashes = raw.len() - raw.trim_start_matches('#').len();
+            let closing = format!("\"{}", "#".repeat(hashes));
+            let body = 1 + hashes + 1;
+            match rest[body..].find(&closing) {
+                Some(i) => body + i + closing.len(),
+                None => return Err(self.error(Error::ExpectedStringEnd)),
+            }
+        } else {
+            match quoted_len(rest, '"') {
+                Some(len) => len,
+                None => return Err(self.error(Error::ExpectedStringEnd)),
+            }
+        };
+
+        self.pos += len;
+        let text = ron::from_str::<String>(&rest[..len]).map_err(|err| self.error(err.code))?;
+
+        Ok(NamedValue::String(text))
+    }
+
+    fn char(&mut self) -> Result<NamedValue, SpannedError> {
+        let rest = &self.text[self.pos..];
+        let len = match quoted_len(rest, '\'') {
+            Some(len) => len,
+            None => return Err(self.error(Error::ExpectedChar)),
+        };
+
+        self.pos += len;
+        let c = ron::from_str::<char>(&rest[..len]).map_err(|err| self.error(err.code))?;
+
+        Ok(NamedValue::Char(c))
+    }
+
+    fn number(&mut self) -> Result<NamedValue, SpannedError> {
+        let start = self.pos;
+        let _ = self.consume('+') || self.consume('-');
+
+        let rest = &self.text[self.pos..];
+        if rest.starts_with("inf") || rest.starts_with("NaN") {
+            self.pos += 3;
+        } else if ["0x", "0o", "0b"].iter().any(|base| rest.starts_with(base)) {
+            self.pos += 2;
+            self.skip_while(|c| c.is_ascii_hexdigit() || c == '_');
+        } else {
+            self.skip_while(|c| c.is_ascii_digit() || c == '_');
+            if self.consume('.') {
+                self.skip_while(|c| c.is_ascii_digit() || c == '_');
+            }
+
+            if self.consume('e') || self.consume('E') {
+                let _ = self.consume('+') || self.consume('-');
+                self.skip_while(|c| c.is_ascii_digit() || c == '_');
+            }
+        }
+
+        let number =
+            format_number(&self.text[start..self.pos]).map_err(|err| self.error(err.code))?;
+
+        Ok(NamedValue::Number(number))
+    }
+
+    fn ident(&mut self) -> Result<&str, SpannedError> {
+        let start = self.pos;
+        let is_raw = self.text[start..].starts_with("r#");
+        if is_raw {
+            // Raw identifiers may also contain `.`, `+` and `-`, like `r#foo.bar`.
+            self.pos += 2;
+            self.skip_while(|c| c.is_alphanumeric() || "_.+-".contains(c));
+        } else {
+            self.skip_while(|c| c.is_alphanumeric() || c == '_');
+        }
+
+        if self.pos == start || is_raw && self.pos == start + 2 {
+            return Err(self.error(Error::ExpectedIdentifier));
+        }
+
+        Ok(&self.text[start..self.pos])
+    }
+
+    /// Verifies whether a struct body starts with a field name followed by `:`.
+    fn is_field(&mut self) -> bool {
+        let pos = self.pos;
+        let is_field = self.ident().is_ok() && self.skip_ws().is_ok() && self.peek() == Some(':');
+        self.pos = pos;
+
+        is_field
+    }
+
+    fn is_raw_string(&self) -> bool {
+        let rest = self.text[self.pos + 1..].trim_start_matches('#');
+        rest.starts_with('"')
+    }
+
+    /// Skips `#![enable(...)]` attributes.
+    fn skip_attributes(&mut self) -> Result<(), SpannedError> {
+        loop {
+            self.skip_ws()?;
+            if !self.consume('#') {
+                return Ok(());
+            }
+
+            self.skip_ws()?;
+            self.expect('!', Error::ExpectedAttribute)?;
+            self.skip_ws()?;
+            self.expect('[', Error::ExpectedAttribute)?;
+            self.skip_ws()?;
+            self.ident()?;
+            self.skip_ws()?;
+            self.expect('(', Error::ExpectedAttribute)?;
+
+            // A list of extensions like `(implicit_some, unwrap_newtypes)`.
+            loop {
+                self.skip_ws()?;
+                if self.consume(')') {
+                    break;
+                }
+
+                self.ident()?;
+                self.skip_ws()?;
+                if !self.consume(',') {
+                    self.expect(')', Error::ExpectedAttributeEnd)?;
+                    break;
+                }
+            }
+
+            self.skip_ws()?;
+            self.expect(']', Error::ExpectedAttributeEnd)?;
+        }
+    }
+
+    /// Skips whitespaces and comments.
+    fn skip_ws(&mut self) -> Result<(), SpannedError> {
+        loop {
+            let rest = &self.text[self.pos..];
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+
+            if trimmed.starts_with("//") {
+                let len = trimmed.find('\n').unwrap_or(trimmed.len());
+                self.pos += len;
+            } else if trimmed.starts_with("/*") {
+                self.skip_block_comment()?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn skip_block_comment(&mut self) -> Result<(), SpannedError> {
+        let mut level = 0;
+        loop {
+            let rest = &self.text[self.pos..];
+            if rest.starts_with("/*") {
+                level += 1;
+                self.pos += 2;
+            } else if rest.starts_with("*/") {
+                level -= 1;
+                self.pos += 2;
+                if level == 0 {
+                    return Ok(());
+                }
+            } else {
+                match rest.chars().next() {
+                    Some(c) => self.pos += c.len_utf8(),
+                    None => return Err(self.error(Error::UnclosedBlockComment)),
+                }
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn skip_while<F>(&mut self, f: F)
+    where
+        F: Fn(char) -> bool,
+    {
+        while let Some(c) = self.peek() {
+            if !f(c) {
+                break;
+            }
+
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn consume(&mut self, c: char) -> bool {
+        let is_next = self.peek() == Some(c);
+        if is_next {
+            self.pos += c.len_utf8();
+        }
+
+        is_next
+    }
+
+    fn expect(&mut self, c: char, err: Error) -> Result<(), SpannedError> {
+        match self.consume(c) {
+            true => Ok(()),
+            false => Err(self.error(err)),
+        }
+    }
+
+    fn error(&self, code: Error) -> SpannedError {
+        let consumed = &self.text[..self.pos];
+        let line = consumed.matches('\n').count() + 1;
+        let col = consumed.len() - consumed.rfind('\n').map_or(0, |i| i + 1) + 1;
+
+        SpannedError {
+            code,
+            position: Position { line, col },
+        }
+    }
+}
+
+/// Formats a number literal the same way as it's shown for a [`ron::Value`].
+fn format_number(literal: &str) -> Result<String, SpannedError> {
+    match ron::from_str::<ron::Value>(literal)? {
+        ron::Value::Number(ron::Number::Integer(n)) => Ok(n.to_string()),
+        ron::Value::Number(ron::Number::Float(n)) => Ok(n.get().to_string()),
+        _ => Err(SpannedError {
+            code: Error::ExpectedFloat,
+            position: Position { line: 1, col: 1 },
+        }),
+    }
+}
+
+/// Returns a length of a quoted literal including its quotes.
+fn quoted_len(text: &str, quote: char) -> Option<usize> {
+    let mut chars = text.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            c if c == quote => return Some(i + c.len_utf8()),
+            _ => {}
+        }
+    }
+
+    None
+}
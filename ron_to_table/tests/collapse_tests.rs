@@ -1,7 +1,7 @@
 use std::iter::FromIterator;
 
 use ron::{value::Float, Map, Number, Value};
-use tabled::settings::Alignment;
+use tabled::settings::{Alignment, Style};
 use testing_table::test_table;
 
 use ron_to_table::{Orientation, RonTable};
@@ -554,6 +554,30 @@ test_table!(
     "+---------------------------+"
 );
 
+test_table!(
+    test_into_pool_table,
+    RonTable::default()
+        .into_pool_table(&Value::Map(Map::from_iter([
+            (Value::String(String::from("name")), Value::String(String::from("ron"))),
+            (
+                Value::String(String::from("tags")),
+                Value::Seq(vec![
+                    Value::String(String::from("serde")),
+                    Value::String(String::from("format")),
+                ]),
+            ),
+        ])))
+        .with(Style::modern())
+        .to_string(),
+    "┌──────┬────────┐"
+    "│ name │ ron    │"
+    "├──────┼────────┤"
+    "│ tags │ serde  │"
+    "│      ├────────┤"
+    "│      │ format │"
+    "└──────┴────────┘"
+);
+
 fn build_ron_table(value: Value) -> String {
     RonTable::default().collapse().build(&value)
 }
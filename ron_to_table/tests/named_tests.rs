@@ -0,0 +1,259 @@
+use ron::error::{Error, Position};
+use tabled::settings::Style;
+use testing_table::test_table;
+
+use ron_to_table::{NamedValue, Orientation, RonTable};
+
+test_table!(
+    test_named_struct,
+    build_ron_table(r#"Point(x: 1, y: 2)"#),
+    "+-Point---+"
+    "| x |  1  |"
+    "+---+-----+"
+    "| y |  2  |"
+    "+---+-----+"
+);
+
+test_table!(
+    test_named_tuple,
+    build_ron_table(r#"Color(255, 128, 0)"#),
+    "+-Color-+-------+-----+"
+    "|  255  |  128  |  0  |"
+    "+-------+-------+-----+"
+);
+
+test_table!(
+    test_anonymous_tuple,
+    build_ron_table(r#"(1, "two", 'c')"#),
+    "+-----+-------+-----+"
+    "|  1  |  two  |  c  |"
+    "+-----+-------+-----+"
+);
+
+test_table!(
+    test_variants,
+    build_ron_table(r#"[Idle, Move(5), Jump(height: 2), Say("hi"), Unit()]"#),
+    "+------------------+"
+    "|  Idle            |"
+    "+------------------+"
+    "|  Move(5)         |"
+    "+------------------+"
+    "| +-Jump---+-----+ |"
+    "| | height |  2  | |"
+    "| +--------+-----+ |"
+    "+------------------+"
+    "|  Say(hi)         |"
+    "+------------------+"
+    "|  Unit            |"
+    "+------------------+"
+);
+
+test_table!(
+    test_option,
+    build_ron_table(r#"(some: Some(Size(1, 2)), none: None)"#),
+    "+------+---------------+"
+    "| some | +-Size+-----+ |"
+    "|      | |  1  |  2  | |"
+    "|      | +-----+-----+ |"
+    "+------+---------------+"
+    "| none |               |"
+    "+------+---------------+"
+);
+
+test_table!(
+    test_map_keys,
+    build_ron_table(r#"{Up: "w", Down: "s"}"#),
+    "+--------+-----+"
+    "|  Up    |  w  |"
+    "+--------+-----+"
+    "|  Down  |  s  |"
+    "+--------+-----+"
+);
+
+test_table!(
+    test_comments_and_extensions,
+    build_ron_table(
+        r#"
+        #![enable(implicit_some)]
+        // a player
+        Player(
+            /* a /* nested */ comment */
+            r#name: r"raw",
+            hp: 1_000,
+        )
+        "#
+    ),
+    "+-Player--------+"
+    "| name |  raw   |"
+    "+------+--------+"
+    "| hp   |  1000  |"
+    "+------+--------+"
+);
+
+test_table!(
+    test_collapsed,
+    RonTable::default()
+        .collapse()
+        .build_named(&parse(r#"Scene(size: Size(800, 600), camera: Camera(fov: 90.0))"#)),
+    "+--------------------+"
+    "| Scene              |"
+    "+--------+-----------+"
+    "| size   | Size      |"
+    "|        +-----+-----+"
+    "|        | 800 | 600 |"
+    "+--------+-----+-----+"
+    "| camera | Camera    |"
+    "|        +-----+-----+"
+    "|        | fov | 90  |"
+    "+--------+-----+-----+"
+);
+
+test_table!(
+    test_collapsed_seq_row,
+    RonTable::default()
+        .collapse()
+        .seq_orientation(Orientation::Row)
+        .build_named(&parse(r#"[Point(x: 1), Point(x: 2)]"#)),
+    "+-------+-------+"
+    "| Point | Point |"
+    "+---+---+---+---+"
+    "| x | 1 | x | 2 |"
+    "+---+---+---+---+"
+);
+
+test_table!(
+    test_into_table,
+    RonTable::default()
+        .into_table_named(&parse(r#"Color(255, 128, 0)"#))
+        .with(Style::modern())
+        .to_string(),
+    "┌───────┬───────┬─────┐"
+    "│  255  │  128  │  0  │"
+    "└───────┴───────┴─────┘"
+);
+
+test_table!(
+    test_max_depth,
+    RonTable::default()
+        .max_depth(1)
+        .build_named(&parse(r#"Scene(size: Size(800, 600), camera: Camera(fov: 90.0))"#)),
+    "+-Scene--+------------------+"
+    "| size   |  Size(…2 items)  |"
+    "+--------+------------------+"
+    "| camera |  Camera{…1 key}  |"
+    "+--------+------------------+"
+);
+
+test_table!(
+    test_edge_cases,
+    build_ron_table(
+        r#"
+        #![enable(implicit_some)] /* one ] two */
+        #![enable(unwrap_newtypes, /* a ] comment */)]
+        Literals(
+            r#type: r#a.b-c+d,
+            /* a /* nested */ block */
+            numbers: [1_000, 0x1F, 0o17, 0b101, -0x10, 10.5e1, .5, -inf],
+        )
+        "#
+    ),
+    "+-Literals+------------+"
+    "| type    |  a.b-c+d   |"
+    "+---------+------------+"
+    "| numbers | +--------+ |"
+    "|         | |  1000  | |"
+    "|         | +--------+ |"
+    "|         | |  31    | |"
+    "|         | +--------+ |"
+    "|         | |  15    | |"
+    "|         | +--------+ |"
+    "|         | |  5     | |"
+    "|         | +--------+ |"
+    "|         | |  -16   | |"
+    "|         | +--------+ |"
+    "|         | |  105   | |"
+    "|         | +--------+ |"
+    "|         | |  0.5   | |"
+    "|         | +--------+ |"
+    "|         | |  -inf  | |"
+    "|         | +--------+ |"
+    "+---------+------------+"
+);
+
+#[test]
+fn test_same_as_ron_value() {
+    let inputs = [
+        r#"[1_000, 0x1F, 0o17, 0b101, -7, +3, 1.5, 1e3, 2.5E-1, 10.01, inf, -inf, NaN]"#,
+        // keys are in an order of `ron::Value`, which sorts them, while `NamedValue` doesn't
+        r#"{'c': [true, false], 0xFF: (), "a": Some(1), "b": None}"#,
+        r###"["text", r"raw", r##"raw "quoted""##, '\n', "\u{1F600}"]"###,
+        r#"
+        #![enable(implicit_some)]
+        /* a /* nested */ comment */
+        [
+            // a line comment
+            { "nested": [[1, 2], [3]] },
+            Some(Some([])),
+        ]
+        "#,
+    ];
+
+    for input in inputs {
+        let named = input.parse::<NamedValue>().unwrap();
+        let value = ron::from_str::<ron::Value>(input).unwrap();
+
+        assert_eq!(
+            RonTable::default().build_named(&named),
+            RonTable::default().build(&value),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_attribute_error() {
+    let err = "#![enable(implicit_some] 1"
+        .parse::<NamedValue>()
+        .unwrap_err();
+
+    assert_eq!(err.code, Error::ExpectedAttributeEnd);
+}
+
+#[test]
+fn test_parse_error() {
+    let err = "Point(x: 1".parse::<NamedValue>().unwrap_err();
+
+    assert_eq!(err.code, Error::Eof);
+    assert_eq!(err.position, Position { line: 1, col: 11 });
+}
+
+#[test]
+fn test_parse() {
+    let value = parse(r#"Shape(Circle(radius: 1.5), ())"#);
+
+    assert_eq!(
+        value,
+        NamedValue::Tuple(
+            Some(String::from("Shape")),
+            vec![
+                NamedValue::Struct(
+                    Some(String::from("Circle")),
+                    vec![(
+                        String::from("radius"),
+                        NamedValue::Number(String::from("1.5"))
+                    )]
+                ),
+                NamedValue::Unit,
+            ]
+        )
+    );
+}
+
+fn build_ron_table(text: &str) -> String {
+    RonTable::default().build_named(&parse(text))
+}
+
+fn parse(text: &str) -> NamedValue {
+    text.parse().unwrap()
+}
@@ -1,7 +1,7 @@
 use std::iter::FromIterator;
 
 use ron::{value::Float, Map, Number, Value};
-use tabled::settings::Alignment;
+use tabled::settings::{Alignment, Style};
 use testing_table::test_table;
 
 use ron_to_table::{Orientation, RonTable};
@@ -586,6 +586,38 @@ test_table!(
     "+-----------------------------------+"
 );
 
+test_table!(
+    test_into_table,
+    RonTable::default()
+        .into_table(&Value::Map(Map::from_iter([
+            (Value::String(String::from("name")), Value::String(String::from("ron"))),
+            (
+                Value::String(String::from("tags")),
+                Value::Seq(vec![
+                    Value::String(String::from("serde")),
+                    Value::String(String::from("format")),
+                ]),
+            ),
+        ])))
+        .with(Style::modern())
+        .to_string(),
+    "┌────────┬──────────────┐"
+    "│  name  │  ron         │"
+    "├────────┼──────────────┤"
+    "│  tags  │ +----------+ │"
+    "│        │ |  serde   | │"
+    "│        │ +----------+ │"
+    "│        │ |  format  | │"
+    "│        │ +----------+ │"
+    "└────────┴──────────────┘"
+);
+
+test_table!(
+    test_into_table_unit,
+    RonTable::default().into_table(&Value::Unit).to_string(),
+    ""
+);
+
 fn build_ron_table(value: Value) -> String {
     RonTable::default().build(&value)
 }
@@ -0,0 +1,133 @@
+//! The library creates a pretty table out of any value which implements [`serde::Serialize`].
+//!
+//! A value is serialized directly into a table, without an intermediate document like a JSON value.
+//!
+//! - Sequences of structs become flat tables with field names as a header.
+//! - Maps and structs become key/value tables, structs are titled by their names.
+//! - Nested values become nested tables, or collapsed ones.
+//!
+//! You can configure table using [`SerdeTable`].
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Server {
+//!     host: String,
+//!     port: u16,
+//!     tags: Vec<&'static str>,
+//! }
+//!
+//! let servers = vec![
+//!     Server { host: String::from("alpha"), port: 8080, tags: vec!["main"] },
+//!     Server { host: String::from("omega"), port: 8081, tags: vec!["backup", "eu"] },
+//! ];
+//!
+//! let table = serde_to_table::to_string(&servers).unwrap();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+-------+------+--------------+\n",
+//!         "| host  | port | tags         |\n",
+//!         "+-------+------+--------------+\n",
+//!         "| alpha | 8080 | +--------+   |\n",
+//!         "|       |      | |  main  |   |\n",
+//!         "|       |      | +--------+   |\n",
+//!         "+-------+------+--------------+\n",
+//!         "| omega | 8081 | +----------+ |\n",
+//!         "|       |      | |  backup  | |\n",
+//!         "|       |      | +----------+ |\n",
+//!         "|       |      | |  eu      | |\n",
+//!         "|       |      | +----------+ |\n",
+//!         "+-------+------+--------------+",
+//!     ),
+//! );
+//! ```
+
+#![deny(unused_must_use)]
+#![warn(
+    missing_docs,
+    rust_2018_idioms,
+    missing_debug_implementations,
+    unreachable_pub
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+use serde::Serialize;
+
+pub use ser::Error;
+pub use table::{Orientation, Records, SerdeTable};
+
+#[cfg(feature = "ansi")]
+pub use table::Palette;
+
+mod ser;
+mod table;
+mod value;
+
+/// The function converts a given value to a pretty table,
+/// recursively creating new tables if necessary.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// let mut materials = BTreeMap::new();
+/// materials.insert("metal", 1.0);
+/// materials.insert("plastic", 0.5);
+///
+/// let table = serde_to_table::to_string(&materials).unwrap();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+---------+-------+\n",
+///         "| metal   |  1.0  |\n",
+///         "+---------+-------+\n",
+///         "| plastic |  0.5  |\n",
+///         "+---------+-------+",
+///     ),
+/// );
+/// ```
+pub fn to_string<T>(value: &T) -> Result<String, Error>
+where
+    T: Serialize + ?Sized,
+{
+    SerdeTable::new(value).map(|table| table.to_string())
+}
+
+/// The function converts a given value to a pretty collapsed table.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// let mut materials = BTreeMap::new();
+/// materials.insert("metal", vec![1.0, 0.9]);
+/// materials.insert("plastic", vec![0.5]);
+///
+/// let table = serde_to_table::to_string_collapsed(&materials).unwrap();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+---------+-----+\n",
+///         "| metal   | 1.0 |\n",
+///         "|         +-----+\n",
+///         "|         | 0.9 |\n",
+///         "+---------+-----+\n",
+///         "| plastic | 0.5 |\n",
+///         "+---------+-----+",
+///     ),
+/// );
+/// ```
+pub fn to_string_collapsed<T>(value: &T) -> Result<String, Error>
+where
+    T: Serialize + ?Sized,
+{
+    SerdeTable::new(value).map(|mut table| table.collapse().to_string())
+}
@@ -0,0 +1,416 @@
+use std::fmt::{self, Display};
+
+use serde::ser::{self, Serialize};
+
+use crate::value::Value;
+
+/// An error which is returned by a [`Serialize`] implementation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        Error(msg.to_string())
+    }
+}
+
+/// Serializes a value into a [`Value`].
+pub(crate) fn to_value<T>(value: &T) -> Result<Value, Error>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(Serializer)
+}
+
+struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SerializeSeq;
+    type SerializeTuple = SerializeSeq;
+    type SerializeTupleStruct = SerializeSeq;
+    type SerializeTupleVariant = SerializeSeq;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeStruct;
+    type SerializeStructVariant = SerializeStruct;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value, Error> {
+        Ok(Value::Number(v.to_string()))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        // debug formatting keeps a fraction of round numbers, like `1.0`
+        Ok(Value::Number(format!("{:?}", v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(Value::Number(format!("{:?}", v)))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::String(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        let list = v.iter().map(|b| Value::Number(b.to_string())).collect();
+        Ok(Value::Seq(list))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Value, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Ok(Value::UnitVariant(variant))
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<Value, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(self)?;
+        Ok(Value::Variant(variant, Box::new(value)))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeSeq, Error> {
+        Ok(SerializeSeq::new(len.unwrap_or(0), Kind::Seq))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeSeq, Error> {
+        // arrays like `[T; N]` are serialized as tuples,
+        // so they are treated as sequences the way `serde_json` does
+        Ok(SerializeSeq::new(len, Kind::Seq))
+    }
+
+    fn serialize_tuple_struct(self, name: &'static str, len: usize) -> Result<SerializeSeq, Error> {
+        Ok(SerializeSeq::new(len, Kind::Tuple(name)))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeSeq, Error> {
+        Ok(SerializeSeq::new(len, Kind::Variant(variant)))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, Error> {
+        Ok(SerializeMap {
+            map: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<SerializeStruct, Error> {
+        Ok(SerializeStruct {
+            name: Some(name),
+            fields: Vec::with_capacity(len),
+            variant: None,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeStruct, Error> {
+        Ok(SerializeStruct {
+            name: None,
+            fields: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+}
+
+enum Kind {
+    Seq,
+    Tuple(&'static str),
+    Variant(&'static str),
+}
+
+struct SerializeSeq {
+    list: Vec<Value>,
+    kind: Kind,
+}
+
+impl SerializeSeq {
+    fn new(len: usize, kind: Kind) -> Self {
+        Self {
+            list: Vec::with_capacity(len),
+            kind,
+        }
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.list.push(to_value(value)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Value {
+        match self.kind {
+            Kind::Seq => Value::Seq(self.list),
+            Kind::Tuple(name) => Value::Tuple(Some(name), self.list),
+            Kind::Variant(name) => Value::Variant(name, Box::new(Value::Tuple(None, self.list))),
+        }
+    }
+}
+
+impl ser::SerializeSeq for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTuple for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+struct SerializeMap {
+    map: Vec<(Value, Value)>,
+    key: Option<Value>,
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.key = Some(to_value(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = match self.key.take() {
+            Some(key) => key,
+            None => {
+                return Err(ser::Error::custom(
+                    "a map value is serialized before its key",
+                ))
+            }
+        };
+
+        self.map.push((key, to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Map(self.map))
+    }
+}
+
+struct SerializeStruct {
+    name: Option<&'static str>,
+    fields: Vec<(&'static str, Value)>,
+    variant: Option<&'static str>,
+}
+
+impl SerializeStruct {
+    fn push<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.fields.push((key, to_value(value)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Value {
+        let value = Value::Struct(self.name, self.fields);
+        match self.variant {
+            Some(name) => Value::Variant(name, Box::new(value)),
+            None => value,
+        }
+    }
+}
+
+impl ser::SerializeStruct for SerializeStruct {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(key, value)
+    }
+
+    fn skip_field(&mut self, _: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeStruct {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
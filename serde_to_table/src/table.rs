@@ -0,0 +1,153 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+use tabled::{
+    grid::{config::CompactMultilineConfig, dimension::CompleteDimension, records::EmptyRecords},
+    settings::TableOption,
+    tables::PoolTable,
+    Table,
+};
+use tree_to_table::TreeTable;
+
+use crate::{
+    ser::{to_value, Error},
+    value::Value,
+};
+
+#[cfg(feature = "ansi")]
+pub use tree_to_table::Palette;
+pub use tree_to_table::{Orientation, Records};
+
+/// Converter of a serializable value to a table,
+/// with a set of configurations.
+///
+/// The value is serialized once, when the converter is created.
+///
+/// Sequences of structs are rendered as flat tables with field names as a header,
+/// which can be changed by [`SerdeTable::records`].
+#[derive(Debug, Clone)]
+pub struct SerdeTable {
+    value: Value,
+    table: TreeTable,
+}
+
+impl SerdeTable {
+    /// Serializes a value and creates a default table configuration.
+    ///
+    /// It fails only if the [`Serialize`] implementation of a value fails.
+    pub fn new<T>(value: &T) -> Result<Self, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = to_value(value)?;
+
+        let mut table = TreeTable::new();
+        table.records(Records::new());
+
+        Ok(Self { value, table })
+    }
+
+    /// Collapse tables out instead of tables within tables.
+    pub fn collapse(&mut self) -> &mut Self {
+        self.table.collapse();
+        self
+    }
+
+    /// Set a table mode for maps and structs.
+    pub fn map_orientation(&mut self, mode: Orientation) -> &mut Self {
+        self.table.map_orientation(mode);
+        self
+    }
+
+    /// Set a table mode for sequences.
+    pub fn seq_orientation(&mut self, mode: Orientation) -> &mut Self {
+        self.table.seq_orientation(mode);
+        self
+    }
+
+    /// Limit a depth of nested tables.
+    ///
+    /// Deeper maps and sequences are replaced by a placeholder like `{…3 keys}` or `[…12 items]`.
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.table.max_depth(depth);
+        self
+    }
+
+    /// Set a configuration of flat tables which are built out of sequences of structs.
+    ///
+    /// ```
+    /// use serde::Serialize;
+    /// use serde_to_table::{Records, SerdeTable};
+    ///
+    /// #[derive(Serialize)]
+    /// struct Server {
+    ///     host: &'static str,
+    ///     #[serde(skip_serializing_if = "Option::is_none")]
+    ///     role: Option<&'static str>,
+    /// }
+    ///
+    /// let servers = [
+    ///     Server { host: "alpha", role: None },
+    ///     Server { host: "omega", role: Some("backup") },
+    /// ];
+    ///
+    /// let table = SerdeTable::new(&servers)
+    ///     .unwrap()
+    ///     .records(Records::new().sort_keys(true).missing("-"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-------+--------+\n",
+    ///         "| host  | role   |\n",
+    ///         "+-------+--------+\n",
+    ///         "| alpha | -      |\n",
+    ///         "+-------+--------+\n",
+    ///         "| omega | backup |\n",
+    ///         "+-------+--------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn records(&mut self, records: Records) -> &mut Self {
+        self.table.records(records);
+        self
+    }
+
+    /// Color values by their type using a given [`Palette`].
+    #[cfg(feature = "ansi")]
+    pub fn palette(&mut self, palette: Palette) -> &mut Self {
+        self.table.palette(palette);
+        self
+    }
+
+    /// Apply settings to the table.
+    pub fn with<O>(&mut self, option: O) -> &mut Self
+    where
+        O: TableOption<EmptyRecords, CompactMultilineConfig, CompleteDimension<'static>>,
+    {
+        self.table.with(option);
+        self
+    }
+
+    /// Build a [`Table`], so any of its settings can be applied afterwards.
+    ///
+    /// It does not recognize collapsed mode.
+    pub fn into_table(&self) -> Table {
+        self.table.into_table(&self.value)
+    }
+
+    /// Build a [`PoolTable`], so any of its settings can be applied afterwards.
+    ///
+    /// It recognizes only collapsed mode.
+    pub fn into_pool_table(&self) -> PoolTable {
+        self.table.into_pool_table(&self.value)
+    }
+}
+
+impl Display for SerdeTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let table = self.table.build(&self.value);
+        table.fmt(f)
+    }
+}
@@ -0,0 +1,61 @@
+use std::borrow::Cow;
+
+use tree_to_table::{Key, Node, ScalarKind, TreeValue};
+
+/// A serialized value.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Seq(Vec<Value>),
+    Tuple(Option<&'static str>, Vec<Value>),
+    Map(Vec<(Value, Value)>),
+    Struct(Option<&'static str>, Vec<(&'static str, Value)>),
+    UnitVariant(&'static str),
+    Variant(&'static str, Box<Value>),
+}
+
+impl TreeValue for Value {
+    fn node(&self) -> Node<'_, Self> {
+        match self {
+            Value::Null => Node::Scalar(ScalarKind::Null, Cow::Borrowed("")),
+            Value::Bool(value) => Node::Scalar(ScalarKind::Boolean, Cow::Owned(value.to_string())),
+            Value::Number(value) => Node::Scalar(ScalarKind::Number, Cow::Borrowed(value)),
+            Value::String(value) => Node::Scalar(ScalarKind::String, Cow::Borrowed(value)),
+            Value::Seq(list) => Node::Seq(list.iter().collect()),
+            Value::Tuple(_, list) => Node::Tuple(list.iter().collect()),
+            Value::Map(map) => Node::Map(
+                map.iter()
+                    .map(|(key, value)| match key {
+                        Value::String(key) => (Key::Text(Cow::Borrowed(key.as_str())), value),
+                        key => (Key::Value(key), value),
+                    })
+                    .collect(),
+            ),
+            Value::Struct(_, fields) => Node::Map(
+                fields
+                    .iter()
+                    .map(|(key, value)| (Key::Text(Cow::Borrowed(*key)), value))
+                    .collect(),
+            ),
+            Value::UnitVariant(name) => Node::Scalar(ScalarKind::Other, Cow::Borrowed(name)),
+            Value::Variant(name, value) => match value.node() {
+                Node::Scalar(_, text) => {
+                    Node::Scalar(ScalarKind::Other, Cow::Owned(format!("{}({})", name, text)))
+                }
+                node => node,
+            },
+        }
+    }
+
+    fn name(&self) -> Option<Cow<'_, str>> {
+        match self {
+            Value::Tuple(Some(name), _)
+            | Value::Struct(Some(name), _)
+            | Value::Variant(name, _) => Some(Cow::Borrowed(name)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::{collections::BTreeMap, iter::FromIterator};
+
+use serde::Serialize;
+use tabled::settings::Style;
+use testing_table::test_table;
+
+use serde_to_table::SerdeTable;
+
+#[derive(Serialize)]
+struct Package {
+    name: &'static str,
+    features: Vec<&'static str>,
+    license: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+enum Shape {
+    Empty,
+    Circle(f32),
+    Rect { width: u32, height: u32 },
+}
+
+fn packages() -> Vec<Package> {
+    vec![
+        Package {
+            name: "serde",
+            features: vec!["derive", "std"],
+            license: Some("MIT"),
+        },
+        Package {
+            name: "tabled",
+            features: vec![],
+            license: None,
+        },
+    ]
+}
+
+test_table!(
+    test_struct,
+    SerdeTable::new(&packages()[0]).unwrap().collapse(),
+    "+-------------------+"
+    "| Package           |"
+    "+----------+--------+"
+    "| name     | serde  |"
+    "+----------+--------+"
+    "| features | derive |"
+    "|          +--------+"
+    "|          | std    |"
+    "+----------+--------+"
+    "| license  | MIT    |"
+    "+----------+--------+"
+);
+
+test_table!(
+    test_records,
+    SerdeTable::new(&packages()).unwrap().collapse(),
+    "+--------+----------+---------+"
+    "| name   | features | license |"
+    "+--------+----------+---------+"
+    "| serde  | derive   | MIT     |"
+    "|        +----------+         |"
+    "|        | std      |         |"
+    "+--------+----------+---------+"
+    "| tabled |          |         |"
+    "+--------+----------+---------+"
+);
+
+test_table!(
+    test_map,
+    SerdeTable::new(&BTreeMap::from_iter([("a", vec![1, 2]), ("b", vec![3])]))
+        .unwrap()
+        .collapse()
+        .with(Style::modern()),
+    "┌───┬───┐"
+    "│ a │ 1 │"
+    "│   ├───┤"
+    "│   │ 2 │"
+    "├───┼───┤"
+    "│ b │ 3 │"
+    "└───┴───┘"
+);
+
+test_table!(
+    test_enum,
+    SerdeTable::new(&vec![
+        Shape::Empty,
+        Shape::Circle(1.0),
+        Shape::Rect { width: 2, height: 3 },
+    ])
+    .unwrap()
+    .collapse(),
+    "+-------------+"
+    "| Empty       |"
+    "+-------------+"
+    "| Circle(1.0) |"
+    "+-------------+"
+    "| Rect        |"
+    "+--------+----+"
+    "| width  | 2  |"
+    "+--------+----+"
+    "| height | 3  |"
+    "+--------+----+"
+);
+
+test_table!(
+    test_into_pool_table,
+    SerdeTable::new(&packages()[0])
+        .unwrap()
+        .collapse()
+        .into_pool_table()
+        .with(Style::modern())
+        .to_string(),
+    "┌───────────────────┐"
+    "│ Package           │"
+    "├──────────┬────────┤"
+    "│ name     │ serde  │"
+    "├──────────┼────────┤"
+    "│ features │ derive │"
+    "│          ├────────┤"
+    "│          │ std    │"
+    "├──────────┼────────┤"
+    "│ license  │ MIT    │"
+    "└──────────┴────────┘"
+);
@@ -0,0 +1,298 @@
+use std::{collections::BTreeMap, iter::FromIterator};
+
+use serde::{ser, Serialize, Serializer};
+use tabled::settings::Style;
+use testing_table::test_table;
+
+use serde_to_table::{Orientation, Records, SerdeTable};
+
+#[derive(Serialize)]
+struct Package {
+    name: &'static str,
+    version: (u8, u8, u8),
+    features: Vec<&'static str>,
+    license: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct Point(i32, i32);
+
+#[derive(Serialize)]
+struct Meters(f64);
+
+#[derive(Serialize)]
+enum Shape {
+    Empty,
+    Circle(f32),
+    Line(Point, Point),
+    Rect { width: u32, height: u32 },
+}
+
+struct Failing;
+
+impl Serialize for Failing {
+    fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Err(ser::Error::custom("failed to serialize"))
+    }
+}
+
+fn packages() -> Vec<Package> {
+    vec![
+        Package {
+            name: "serde",
+            version: (1, 0, 200),
+            features: vec!["derive", "std"],
+            license: Some("MIT"),
+        },
+        Package {
+            name: "tabled",
+            version: (0, 15, 0),
+            features: vec![],
+            license: None,
+        },
+    ]
+}
+
+test_table!(
+    test_string,
+    SerdeTable::new("123456789").unwrap(),
+    "+-----------+"
+    "| 123456789 |"
+    "+-----------+"
+);
+
+test_table!(
+    test_bool,
+    SerdeTable::new(&true).unwrap(),
+    "+------+"
+    "| true |"
+    "+------+"
+);
+
+test_table!(
+    test_int,
+    SerdeTable::new(&-123456789i64).unwrap(),
+    "+------------+"
+    "| -123456789 |"
+    "+------------+"
+);
+
+test_table!(
+    test_float,
+    SerdeTable::new(&2.0f64).unwrap(),
+    "+-----+"
+    "| 2.0 |"
+    "+-----+"
+);
+
+test_table!(test_unit, SerdeTable::new(&()).unwrap(), "");
+
+test_table!(
+    test_newtype_struct,
+    SerdeTable::new(&Meters(1.5)).unwrap(),
+    "+-----+"
+    "| 1.5 |"
+    "+-----+"
+);
+
+test_table!(
+    test_sequence,
+    SerdeTable::new(&["Hello", "World"]).unwrap(),
+    "+---------+"
+    "|  Hello  |"
+    "+---------+"
+    "|  World  |"
+    "+---------+"
+);
+
+test_table!(
+    test_tuple_struct,
+    SerdeTable::new(&Point(1, -1)).unwrap(),
+    "+-Point------+"
+    "|  1  |  -1  |"
+    "+-----+------+"
+);
+
+test_table!(
+    test_struct,
+    SerdeTable::new(&packages()[0]).unwrap(),
+    "+-Package--+--------------+"
+    "| name     |  serde       |"
+    "+----------+--------------+"
+    "| version  | +-------+    |"
+    "|          | |  1    |    |"
+    "|          | +-------+    |"
+    "|          | |  0    |    |"
+    "|          | +-------+    |"
+    "|          | |  200  |    |"
+    "|          | +-------+    |"
+    "+----------+--------------+"
+    "| features | +----------+ |"
+    "|          | |  derive  | |"
+    "|          | +----------+ |"
+    "|          | |  std     | |"
+    "|          | +----------+ |"
+    "+----------+--------------+"
+    "| license  |  MIT         |"
+    "+----------+--------------+"
+);
+
+test_table!(
+    test_records,
+    SerdeTable::new(&packages()).unwrap(),
+    "+--------+-----------+--------------+---------+"
+    "| name   | version   | features     | license |"
+    "+--------+-----------+--------------+---------+"
+    "| serde  | +-------+ | +----------+ | MIT     |"
+    "|        | |  1    | | |  derive  | |         |"
+    "|        | +-------+ | +----------+ |         |"
+    "|        | |  0    | | |  std     | |         |"
+    "|        | +-------+ | +----------+ |         |"
+    "|        | |  200  | |              |         |"
+    "|        | +-------+ |              |         |"
+    "+--------+-----------+--------------+---------+"
+    "| tabled | +------+  |              |         |"
+    "|        | |  0   |  |              |         |"
+    "|        | +------+  |              |         |"
+    "|        | |  15  |  |              |         |"
+    "|        | +------+  |              |         |"
+    "|        | |  0   |  |              |         |"
+    "|        | +------+  |              |         |"
+    "+--------+-----------+--------------+---------+"
+);
+
+test_table!(
+    test_records_missing,
+    SerdeTable::new(&vec![
+        BTreeMap::from_iter([("host", "alpha")]),
+        BTreeMap::from_iter([("host", "omega"), ("role", "backup")]),
+    ])
+    .unwrap()
+    .records(Records::new().missing("-")),
+    "+-------+--------+"
+    "| host  | role   |"
+    "+-------+--------+"
+    "| alpha | -      |"
+    "+-------+--------+"
+    "| omega | backup |"
+    "+-------+--------+"
+);
+
+test_table!(
+    test_map,
+    SerdeTable::new(&BTreeMap::from_iter([("a", vec![1, 2]), ("b", vec![])])).unwrap(),
+    "+---+---------+"
+    "| a | +-----+ |"
+    "|   | |  1  | |"
+    "|   | +-----+ |"
+    "|   | |  2  | |"
+    "|   | +-----+ |"
+    "+---+---------+"
+    "| b |         |"
+    "+---+---------+"
+);
+
+test_table!(
+    test_map_value_keys,
+    SerdeTable::new(&BTreeMap::from_iter([((1, 2), "tuple"), ((3, 4), "pair")])).unwrap(),
+    "+---------+---------+"
+    "| +-----+ |  tuple  |"
+    "| |  1  | |         |"
+    "| +-----+ |         |"
+    "| |  2  | |         |"
+    "| +-----+ |         |"
+    "+---------+---------+"
+    "| +-----+ |  pair   |"
+    "| |  3  | |         |"
+    "| +-----+ |         |"
+    "| |  4  | |         |"
+    "| +-----+ |         |"
+    "+---------+---------+"
+);
+
+test_table!(
+    test_enum,
+    SerdeTable::new(&vec![
+        Shape::Empty,
+        Shape::Circle(1.0),
+        Shape::Line(Point(0, 0), Point(1, 1)),
+        Shape::Rect { width: 2, height: 3 },
+    ])
+    .unwrap(),
+    "+-----------------------------------+"
+    "|  Empty                            |"
+    "+-----------------------------------+"
+    "|  Circle(1.0)                      |"
+    "+-----------------------------------+"
+    "| +-Line----------+---------------+ |"
+    "| | +-Point-----+ | +-Point-----+ | |"
+    "| | |  0  |  0  | | |  1  |  1  | | |"
+    "| | +-----+-----+ | +-----+-----+ | |"
+    "| +---------------+---------------+ |"
+    "+-----------------------------------+"
+    "| +-Rect---+-----+                  |"
+    "| | width  |  2  |                  |"
+    "| +--------+-----+                  |"
+    "| | height |  3  |                  |"
+    "| +--------+-----+                  |"
+    "+-----------------------------------+"
+);
+
+test_table!(
+    test_orientation,
+    SerdeTable::new(&packages()[0])
+        .unwrap()
+        .map_orientation(Orientation::Row)
+        .seq_orientation(Orientation::Row),
+    "+-Package-+-----------------------+----------------------+---------+"
+    "| name    | version               | features             | license |"
+    "+---------+-----------------------+----------------------+---------+"
+    "|  serde  | +-----+-----+-------+ | +----------+-------+ |  MIT    |"
+    "|         | |  1  |  0  |  200  | | |  derive  |  std  | |         |"
+    "|         | +-----+-----+-------+ | +----------+-------+ |         |"
+    "+---------+-----------------------+----------------------+---------+"
+);
+
+test_table!(
+    test_max_depth,
+    SerdeTable::new(&packages()).unwrap().max_depth(1),
+    "+--------------------+"
+    "|  Package{…4 keys}  |"
+    "+--------------------+"
+    "|  Package{…4 keys}  |"
+    "+--------------------+"
+);
+
+test_table!(
+    test_into_table,
+    SerdeTable::new(&packages()[1])
+        .unwrap()
+        .into_table()
+        .with(Style::rounded())
+        .to_string(),
+    "╭──────────┬──────────╮"
+    "│ name     │  tabled  │"
+    "├──────────┼──────────┤"
+    "│ version  │ +------+ │"
+    "│          │ |  0   | │"
+    "│          │ +------+ │"
+    "│          │ |  15  | │"
+    "│          │ +------+ │"
+    "│          │ |  0   | │"
+    "│          │ +------+ │"
+    "│ features │          │"
+    "│ license  │          │"
+    "╰──────────┴──────────╯"
+);
+
+#[test]
+fn test_error() {
+    let err = SerdeTable::new(&vec![Failing]).unwrap_err();
+    assert_eq!(err.to_string(), "failed to serialize");
+
+    let err = serde_to_table::to_string(&BTreeMap::from_iter([("key", Failing)])).unwrap_err();
+    assert_eq!(err.to_string(), "failed to serialize");
+}
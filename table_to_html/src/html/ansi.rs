@@ -0,0 +1,361 @@
+use std::collections::BTreeMap;
+
+use crate::html::value::escape_text;
+
+/// Converts a text with ANSI escape sequences into HTML.
+///
+/// Styled parts of the text are wrapped into `<span style="...">`.
+/// Only SGR sequences are translated (16/256/truecolor, bold, italic, underline, strikethrough),
+/// other escape sequences are dropped.
+///
+/// ```
+/// use table_to_html::html::ansi_to_html;
+///
+/// assert_eq!(
+///     ansi_to_html("\u{1b}[31mHello\u{1b}[39m World"),
+///     "<span style=\"color: #800000;\">Hello</span> World",
+/// );
+/// ```
+pub fn ansi_to_html(text: &str) -> String {
+    convert(text, |style| {
+        let css = style
+            .css()
+            .iter()
+            .map(|(key, val)| format!("{key}: {val};"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut buf = String::from("style=\"");
+        escape_text(&css, &mut buf);
+        buf.push('"');
+
+        buf
+    })
+}
+
+/// Converts a text with ANSI escape sequences into HTML.
+///
+/// Styled parts of the text are wrapped into `<span class="...">`,
+/// while definitions of the classes are put into a given CSS map.
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use table_to_html::html::ansi_to_html_classes;
+///
+/// let mut css = BTreeMap::new();
+/// let html = ansi_to_html_classes("\u{1b}[1mHello\u{1b}[22m", &mut css);
+///
+/// assert_eq!(html, "<span class=\"ansi-bold\">Hello</span>");
+/// assert_eq!(css[".ansi-bold"]["font-weight"], "bold");
+/// ```
+pub fn ansi_to_html_classes(
+    text: &str,
+    css: &mut BTreeMap<String, BTreeMap<String, String>>,
+) -> String {
+    convert(text, |style| {
+        let mut classes = vec![];
+        for (class, key, value) in style.classes() {
+            let mut m = BTreeMap::new();
+            m.insert(String::from(key), value);
+            css.insert(format!(".{class}"), m);
+
+            classes.push(class);
+        }
+
+        let mut buf = String::from("class=\"");
+        escape_text(&classes.join(" "), &mut buf);
+        buf.push('"');
+
+        buf
+    })
+}
+
+/// Translates a list of ANSI SGR sequences (like a color prefix) into CSS properties.
+///
+/// ```
+/// use table_to_html::html::ansi_to_css;
+///
+/// let css = ansi_to_css("\u{1b}[1m\u{1b}[38;2;255;0;10m");
+///
+/// assert_eq!(css["font-weight"], "bold");
+/// assert_eq!(css["color"], "#ff000a");
+/// ```
+pub fn ansi_to_css(ansi: &str) -> BTreeMap<String, String> {
+    let mut style = Style::default();
+    for sequence in ansi.split('\u{1b}').filter_map(|s| s.strip_prefix('[')) {
+        if let Some(params) = sequence.strip_suffix('m') {
+            style.apply(params);
+        }
+    }
+
+    style.css()
+}
+
+fn convert<F>(text: &str, mut attribute: F) -> String
+where
+    F: FnMut(&Style) -> String,
+{
+    let mut buf = String::with_capacity(text.len());
+    let mut style = Style::default();
+    let mut is_span_open = false;
+
+    let mut chars = text.char_indices().peekable();
+    let mut start = 0;
+    while let Some((i, c)) = chars.next() {
+        if c != '\u{1b}' && c != '\n' {
+            continue;
+        }
+
+        escape_text(&text[start..i], &mut buf);
+
+        if c == '\n' {
+            // each line must be a valid HTML by itself
+            if is_span_open {
+                buf.push_str("</span>");
+            }
+
+            buf.push('\n');
+
+            is_span_open = !style.is_empty();
+            if is_span_open {
+                buf.push_str(&format!("<span {}>", attribute(&style)));
+            }
+
+            start = i + 1;
+            continue;
+        }
+
+        let (end, sgr) = parse_escape(text, &mut chars);
+        start = end;
+
+        let params = match sgr {
+            Some(params) => params,
+            None => continue,
+        };
+
+        let prev = style.clone();
+        style.apply(params);
+        if style == prev {
+            continue;
+        }
+
+        if is_span_open {
+            buf.push_str("</span>");
+        }
+
+        is_span_open = !style.is_empty();
+        if is_span_open {
+            buf.push_str(&format!("<span {}>", attribute(&style)));
+        }
+    }
+
+    escape_text(&text[start..], &mut buf);
+
+    if is_span_open {
+        buf.push_str("</span>");
+    }
+
+    buf
+}
+
+// Skips an escape sequence returning an index after it,
+// and a list of parameters in case it's a SGR sequence.
+fn parse_escape<'a, I>(
+    text: &'a str,
+    chars: &mut std::iter::Peekable<I>,
+) -> (usize, Option<&'a str>)
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    let kind = match chars.next() {
+        Some((_, kind)) => kind,
+        None => return (text.len(), None),
+    };
+
+    match kind {
+        '[' => {
+            let params_start = chars.peek().map_or(text.len(), |(i, _)| *i);
+            for (i, c) in chars.by_ref() {
+                if ('\u{40}'..='\u{7e}').contains(&c) {
+                    let sgr = (c == 'm').then(|| &text[params_start..i]);
+                    return (i + 1, sgr);
+                }
+            }
+
+            (text.len(), None)
+        }
+        ']' => {
+            // OSC sequence (like a hyperlink) which ends either by BEL or ST
+            while let Some((i, c)) = chars.next() {
+                if c == '\u{7}' {
+                    return (i + 1, None);
+                }
+
+                if c == '\u{1b}' {
+                    if let Some((i, '\\')) = chars.peek() {
+                        let end = i + 1;
+                        chars.next();
+                        return (end, None);
+                    }
+                }
+            }
+
+            (text.len(), None)
+        }
+        _ => {
+            let end = chars.peek().map_or(text.len(), |(i, _)| *i);
+            (end, None)
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Style {
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+    fg: Option<String>,
+    bg: Option<String>,
+}
+
+impl Style {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn apply(&mut self, params: &str) {
+        let mut codes = params
+            .split(';')
+            .map(|code| code.parse::<u8>().unwrap_or(0));
+
+        while let Some(code) = codes.next() {
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                9 => self.strikethrough = true,
+                22 => self.bold = false,
+                23 => self.italic = false,
+                24 => self.underline = false,
+                29 => self.strikethrough = false,
+                30..=37 => self.fg = Some(ansi_color(code - 30)),
+                90..=97 => self.fg = Some(ansi_color(code - 90 + 8)),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some(ansi_color(code - 40)),
+                100..=107 => self.bg = Some(ansi_color(code - 100 + 8)),
+                49 => self.bg = None,
+                38 | 48 => {
+                    let color = match codes.next() {
+                        Some(5) => codes.next().map(ansi_color),
+                        Some(2) => match (codes.next(), codes.next(), codes.next()) {
+                            (Some(r), Some(g), Some(b)) => Some(rgb_color(r, g, b)),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+
+                    if code == 38 {
+                        self.fg = color;
+                    } else {
+                        self.bg = color;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn css(&self) -> BTreeMap<String, String> {
+        self.classes()
+            .into_iter()
+            .map(|(_, key, value)| (String::from(key), value))
+            .collect()
+    }
+
+    fn classes(&self) -> Vec<(String, &'static str, String)> {
+        let mut list = vec![];
+
+        if self.bold {
+            list.push((
+                String::from("ansi-bold"),
+                "font-weight",
+                String::from("bold"),
+            ));
+        }
+
+        if self.italic {
+            list.push((
+                String::from("ansi-italic"),
+                "font-style",
+                String::from("italic"),
+            ));
+        }
+
+        let decoration = match (self.underline, self.strikethrough) {
+            (true, true) => Some(("ansi-underline-strikethrough", "underline line-through")),
+            (true, false) => Some(("ansi-underline", "underline")),
+            (false, true) => Some(("ansi-strikethrough", "line-through")),
+            (false, false) => None,
+        };
+
+        if let Some((class, value)) = decoration {
+            list.push((String::from(class), "text-decoration", String::from(value)));
+        }
+
+        if let Some(color) = &self.fg {
+            let class = format!("ansi-fg-{}", color.trim_start_matches('#'));
+            list.push((class, "color", color.clone()));
+        }
+
+        if let Some(color) = &self.bg {
+            let class = format!("ansi-bg-{}", color.trim_start_matches('#'));
+            list.push((class, "background-color", color.clone()));
+        }
+
+        list
+    }
+}
+
+// Translates a 256 color palette index into a CSS color.
+fn ansi_color(index: u8) -> String {
+    const BASIC: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (128, 0, 0),
+        (0, 128, 0),
+        (128, 128, 0),
+        (0, 0, 128),
+        (128, 0, 128),
+        (0, 128, 128),
+        (192, 192, 192),
+        (128, 128, 128),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (0, 0, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    match index {
+        0..=15 => {
+            let (r, g, b) = BASIC[index as usize];
+            rgb_color(r, g, b)
+        }
+        16..=231 => {
+            let index = index - 16;
+            let level = |i: u8| if i == 0 { 0 } else { 55 + i * 40 };
+            rgb_color(level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            rgb_color(level, level, level)
+        }
+    }
+}
+
+fn rgb_color(r: u8, g: u8, b: u8) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use tabled::grid::util::string::get_lines;
 
-use crate::html::{Attribute, HtmlValue, HtmlVisitor, HtmlVisitorMut};
+use crate::html::{value::escape_text, Attribute, HtmlValue, HtmlVisitor, HtmlVisitorMut};
 
 /// A HTML element representation.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -85,7 +85,7 @@ impl HtmlElement {
                     }
                 }
             }
-            HtmlValue::Content(_) => {}
+            HtmlValue::Content(_) | HtmlValue::Raw(_) => {}
         }
 
         true
@@ -114,7 +114,7 @@ impl HtmlElement {
                     }
                 }
             }
-            HtmlValue::Content(_) => {}
+            HtmlValue::Content(_) | HtmlValue::Raw(_) => {}
         }
 
         true
@@ -149,7 +149,9 @@ fn print_attributes(mut f: impl std::fmt::Write, attrs: &[Attribute]) -> std::fm
 
         f.write_str(attr.key())?;
         f.write_char('=')?;
-        write!(f, "{:?}", attr.value())?;
+        f.write_char('"')?;
+        f.write_str(&escape(attr.value()))?;
+        f.write_char('"')?;
     }
 
     Ok(())
@@ -183,6 +185,22 @@ fn build_html(f: impl std::fmt::Write, e: &HtmlElement) -> std::fmt::Result {
                 return true;
             }
 
+            // whitespaces are significant inside <pre> so its content is printed as it is
+            if e.tag() == "pre" {
+                let content = match e.value() {
+                    Some(HtmlValue::Content(content)) => Some(escape(content)),
+                    Some(HtmlValue::Raw(content)) => Some(content.clone()),
+                    _ => None,
+                };
+
+                if let Some(content) = content {
+                    self.result = write!(self.writer, "{space}");
+                    self.result = print_tag(&mut self.writer, e.tag(), e.attrs());
+                    self.result = write!(self.writer, "{content}</pre>");
+                    return false;
+                }
+            }
+
             if let Some(val) = e.value() {
                 self.result = write!(self.writer, "{space}");
                 self.result = print_tag(&mut self.writer, e.tag(), e.attrs());
@@ -198,6 +216,13 @@ fn build_html(f: impl std::fmt::Write, e: &HtmlElement) -> std::fmt::Result {
                         self.tab -= 4;
                     }
                     HtmlValue::Content(content) => {
+                        let space = " ".repeat(self.tab + 4);
+                        for line in get_lines(content) {
+                            self.result = write!(self.writer, "{space}");
+                            self.result = writeln!(self.writer, "{}", escape(&line));
+                        }
+                    }
+                    HtmlValue::Raw(content) => {
                         let space = " ".repeat(self.tab + 4);
                         for line in get_lines(content) {
                             self.result = write!(self.writer, "{space}");
@@ -219,3 +244,9 @@ fn build_html(f: impl std::fmt::Write, e: &HtmlElement) -> std::fmt::Result {
 
     builder.result
 }
+
+fn escape(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    escape_text(text, &mut buf);
+    buf
+}
@@ -1,9 +1,11 @@
 //! The module contains a html primitives.
 
+mod ansi;
 mod attr;
 mod html_element;
-mod value;
+pub(crate) mod value;
 
+pub use ansi::{ansi_to_css, ansi_to_html, ansi_to_html_classes};
 pub use attr::Attribute;
 pub use html_element::HtmlElement;
 pub use value::HtmlValue;
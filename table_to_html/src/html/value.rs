@@ -1,10 +1,34 @@
 use crate::html::HtmlElement;
 
 /// HtmlValue represents a children elements of an HTML element.
+///
+/// New kinds of values might be added, so it's marked as `#[non_exhaustive]`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub enum HtmlValue {
     /// Children elements.
     Elements(Vec<HtmlElement>),
     /// A string content.
+    ///
+    /// It's escaped while rendering, so `<`, `>`, `&` and quotes are displayed as they are.
     Content(String),
+    /// A string content which is rendered as it is.
+    ///
+    /// So it might contain a HTML markup (e.g. links or badges).
+    /// Be aware not to put an untrusted data into it.
+    Raw(String),
+}
+
+/// Escapes a text so it would not be considered a markup.
+pub(crate) fn escape_text(text: &str, buf: &mut String) {
+    for c in text.chars() {
+        match c {
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '&' => buf.push_str("&amp;"),
+            '"' => buf.push_str("&quot;"),
+            '\'' => buf.push_str("&#39;"),
+            c => buf.push(c),
+        }
+    }
 }
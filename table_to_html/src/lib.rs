@@ -152,21 +152,21 @@
 //!         "<table>\n",
 //!         "    <thead>\n",
 //!         "        <tr>\n",
-//!         "            <th>\n",
+//!         "            <th scope=\"col\">\n",
 //!         "                <div>\n",
 //!         "                    <p>\n",
 //!         "                        name\n",
 //!         "                    </p>\n",
 //!         "                </div>\n",
 //!         "            </th>\n",
-//!         "            <th>\n",
+//!         "            <th scope=\"col\">\n",
 //!         "                <div>\n",
 //!         "                    <p>\n",
 //!         "                        based_on\n",
 //!         "                    </p>\n",
 //!         "                </div>\n",
 //!         "            </th>\n",
-//!         "            <th>\n",
+//!         "            <th scope=\"col\">\n",
 //!         "                <div>\n",
 //!         "                    <p>\n",
 //!         "                        is_active\n",
@@ -256,8 +256,13 @@
 //!
 //! In a mean time there's some regular style options.
 //!
+//! A cell content is escaped by default (`<`, `>`, `&` and quotes),
+//! use [`HtmlTable::set_raw_html`] to put a HTML markup into cells.
+//!
 //! Also notice that table elements does not have any special `id`, `class` attributes.
-//! It's supposed that you might add them if nessary your self, by using [`HtmlTable::visit_mut`]
+//! It's supposed that you might add them if nessary your self,
+//! by using [`HtmlTable::set_attribute`], [`HtmlTable::add_class`] or [`HtmlTable::visit_mut`]
+//! for a more fine-grained control.
 //!
 //! ## Adding custom ids example.
 //!
@@ -377,21 +382,35 @@
 //!
 //! [`Tabled`]: tabled::Tabled
 //! [`HtmlTable::visit_mut`]: HtmlTable::visit_mut
+//! [`HtmlTable::set_attribute`]: HtmlTable::set_attribute
+//! [`HtmlTable::add_class`]: HtmlTable::add_class
+//! [`HtmlTable::set_raw_html`]: HtmlTable::set_raw_html
 
 pub mod html;
 
+mod theme;
+
 use std::{
     collections::BTreeMap,
     fmt::{Display, Write},
 };
 
-use html::{HtmlElement, HtmlValue, HtmlVisitor, HtmlVisitorMut};
+use html::{
+    ansi_to_css, ansi_to_html, ansi_to_html_classes, HtmlElement, HtmlValue, HtmlVisitor,
+    HtmlVisitorMut,
+};
 use tabled::{
     builder::Builder,
-    grid::{config::Sides, util::string::get_lines},
+    grid::{
+        colors::Colors,
+        config::{AlignmentHorizontal, AlignmentVertical, ColoredConfig, Indent, Sides},
+        records::vec_records::Cell,
+        util::string::get_lines,
+    },
+    Table,
 };
 
-use crate::html::Attribute;
+use crate::html::{value::escape_text, Attribute};
 
 /// A Html element padding in PX.
 pub type Padding = Sides<usize>;
@@ -401,11 +420,33 @@ pub type Margin = Sides<usize>;
 pub use tabled::grid::config::{Entity, Position};
 pub use tabled::settings::Alignment;
 
+pub use theme::HtmlTheme;
+
 /// The structure represents an HTML `<table>`.
 #[derive(Debug, Clone)]
 pub struct HtmlTable {
     table: HtmlElement,
     css: BTreeMap<String, BTreeMap<String, String>>,
+    multiline: MultilineMode,
+}
+
+/// A way a multiline content of a cell is represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MultilineMode {
+    /// Each line is put into its own `<p>`.
+    Paragraphs,
+    /// Lines are separated by `<br>`.
+    LineBreaks,
+    /// Lines are put into a `<pre>` so whitespaces are preserved.
+    Preformatted,
+}
+
+// `#[default]` on a variant isn't supported by our MSRV
+#[allow(clippy::derivable_impls)]
+impl Default for MultilineMode {
+    fn default() -> Self {
+        Self::Paragraphs
+    }
 }
 
 impl HtmlTable {
@@ -493,6 +534,156 @@ impl HtmlTable {
         self.css.insert(String::from("table, th, td"), m);
     }
 
+    /// Set a caption (`<caption>`) of a table.
+    pub fn set_caption<S>(&mut self, caption: S)
+    where
+        S: Into<String>,
+    {
+        let caption = HtmlElement::new("caption", vec![], Some(HtmlValue::Content(caption.into())));
+
+        self.update_sections(|sections| sections.caption = Some(caption));
+    }
+
+    /// Set a number of first rows which are considered to be a header (`<thead>`).
+    ///
+    /// The header cells become `<th scope="col">`.
+    pub fn set_header_rows(&mut self, count: usize) {
+        self.update_sections(|sections| {
+            let has_index = sections.has_index_column();
+
+            let mut rows = sections.head.drain(..).collect::<Vec<_>>();
+            rows.append(&mut sections.body);
+
+            let body = rows.split_off(count.min(rows.len()));
+            sections.head = rows
+                .iter()
+                .map(|row| map_cells(row, |_, cell| set_cell_tag(cell, "th", Some("col"))))
+                .collect();
+            sections.body = body
+                .iter()
+                .map(|row| map_cells(row, |col, cell| body_cell(cell, has_index && col == 0)))
+                .collect();
+        });
+    }
+
+    /// Set a number of last rows which are considered to be a footer (`<tfoot>`).
+    pub fn set_footer_rows(&mut self, count: usize) {
+        self.update_sections(|sections| {
+            let mut rows = sections.body.drain(..).collect::<Vec<_>>();
+            rows.append(&mut sections.foot);
+
+            sections.foot = rows.split_off(rows.len() - count.min(rows.len()));
+            sections.body = rows;
+        });
+    }
+
+    /// Makes the first column an index column,
+    /// so its cells in `<tbody>` and `<tfoot>` become `<th scope="row">`.
+    pub fn set_index_column(&mut self) {
+        self.update_sections(|sections| {
+            for row in sections.body.iter_mut().chain(sections.foot.iter_mut()) {
+                *row = map_cells(row, |col, cell| body_cell(cell, col == 0));
+            }
+        });
+    }
+
+    /// Set widths of columns in PX, using a `<colgroup>`.
+    pub fn set_column_widths<I>(&mut self, widths: I)
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let cols = widths
+            .into_iter()
+            .map(|width| {
+                HtmlElement::void(
+                    "col",
+                    vec![Attribute::new("style", format!("width: {width}px;"))],
+                )
+            })
+            .collect();
+        let colgroup = HtmlElement::new("colgroup", vec![], Some(HtmlValue::Elements(cols)));
+
+        self.update_sections(|sections| sections.colgroup = Some(colgroup));
+    }
+
+    /// Set an attribute for a given target, replacing an attribute with the same key.
+    ///
+    /// [`Entity::Global`] refers to `<table>`, [`Entity::Row`] to `<tr>`
+    /// and [`Entity::Column`], [`Entity::Cell`] to the cells.
+    /// Cells are found by their position in a grid,
+    /// so a cell spanned over several columns or rows is matched by the first of them.
+    ///
+    /// It might be used to set `id` of an element.
+    pub fn set_attribute(&mut self, target: Entity, attr: Attribute) {
+        self.update_attributes(target, |attrs| {
+            attrs.retain(|a| a.key() != attr.key());
+            attrs.push(attr.clone());
+        });
+    }
+
+    /// Add a class to a given target.
+    ///
+    /// The target is interpreted in the same way as in [`HtmlTable::set_attribute`].
+    pub fn add_class<S>(&mut self, target: Entity, class: S)
+    where
+        S: AsRef<str>,
+    {
+        let class = class.as_ref();
+        self.update_attributes(target, |attrs| {
+            match attrs.iter_mut().find(|a| a.key() == "class") {
+                Some(attr) => {
+                    *attr = Attribute::new("class", format!("{} {}", attr.value(), class))
+                }
+                None => attrs.push(Attribute::new("class", class)),
+            }
+        });
+    }
+
+    /// Set a way a multiline content is represented.
+    ///
+    /// It's applied while a table is rendered.
+    pub fn set_multiline(&mut self, mode: MultilineMode) {
+        self.multiline = mode;
+    }
+
+    /// Makes a content of a given target to be rendered as it is, without escaping.
+    ///
+    /// It allows to put a HTML markup (e.g. links or badges) into cells.
+    /// By default `<`, `>`, `&` and quotes are escaped.
+    ///
+    /// Be aware not to use it for cells which contain an untrusted data.
+    pub fn set_raw_html(&mut self, target: Entity) {
+        self.update_cells(target, |cell| {
+            let mut cell = cell.clone();
+            cell.visit_mut(RawContent);
+            cell
+        });
+    }
+
+    /// Converts ANSI escape sequences in cells into `<span>` elements with an inline `style`.
+    pub fn convert_ansi(&mut self) {
+        convert_cells_ansi(&mut self.table, ansi_to_html);
+    }
+
+    /// Converts ANSI escape sequences in cells into `<span>` elements with a `class`,
+    /// while the classes are added to the table's style.
+    pub fn convert_ansi_to_classes(&mut self) {
+        let css = &mut self.css;
+        convert_cells_ansi(&mut self.table, |text| ansi_to_html_classes(text, css));
+    }
+
+    /// Applies a CSS theme to a table.
+    ///
+    /// The rules of the theme are merged with the already set ones.
+    pub fn set_theme(&mut self, theme: &HtmlTheme) {
+        for (target, rules) in theme.get_css() {
+            self.css
+                .entry(target.clone())
+                .or_default()
+                .extend(rules.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+    }
+
     /// Uses the visitor to traverse a table.
     pub fn visit<V: HtmlVisitor>(&self, visitor: V) {
         self.table.visit(visitor);
@@ -504,9 +695,88 @@ impl HtmlTable {
     }
 }
 
+impl HtmlTable {
+    fn update_sections<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut TableSections),
+    {
+        let mut sections = TableSections::new(&self.table);
+        f(&mut sections);
+        self.table = sections.into_element(self.table.attrs().to_vec());
+    }
+
+    fn update_attributes<F>(&mut self, target: Entity, f: F)
+    where
+        F: Fn(&mut Vec<Attribute>),
+    {
+        match target {
+            Entity::Global => self.table = update_element_attributes(&self.table, f),
+            Entity::Row(row) => self.update_rows(|i, e| match i == row {
+                true => update_element_attributes(e, &f),
+                false => e.clone(),
+            }),
+            Entity::Column(_) | Entity::Cell(_, _) => {
+                self.update_cells(target, |cell| update_element_attributes(cell, &f))
+            }
+        }
+    }
+
+    fn update_cells<F>(&mut self, target: Entity, f: F)
+    where
+        F: Fn(&HtmlElement) -> HtmlElement,
+    {
+        self.update_sections(|sections| {
+            let mut rows = sections
+                .head
+                .iter_mut()
+                .chain(sections.body.iter_mut())
+                .chain(sections.foot.iter_mut())
+                .collect::<Vec<_>>();
+
+            let columns = cell_columns(rows.iter().map(|row| &**row));
+            for (i, row) in rows.iter_mut().enumerate() {
+                **row = map_cells(row, |j, cell| {
+                    match entity_contains(target, (i, columns[i][j])) {
+                        true => f(cell),
+                        false => cell.clone(),
+                    }
+                });
+            }
+        });
+    }
+
+    fn update_rows<F>(&mut self, f: F)
+    where
+        F: Fn(usize, &HtmlElement) -> HtmlElement,
+    {
+        self.update_sections(|sections| {
+            let rows = sections
+                .head
+                .iter_mut()
+                .chain(sections.body.iter_mut())
+                .chain(sections.foot.iter_mut());
+
+            for (i, row) in rows.enumerate() {
+                *row = f(i, row);
+            }
+        });
+    }
+
+    fn build_element(&self) -> HtmlElement {
+        let mut table = self.table.clone();
+        if self.multiline != MultilineMode::Paragraphs {
+            table.visit_mut(MultilineFormatter {
+                mode: self.multiline,
+            });
+        }
+
+        table
+    }
+}
+
 impl From<HtmlTable> for HtmlElement {
     fn from(value: HtmlTable) -> Self {
-        value.table
+        value.build_element()
     }
 }
 
@@ -516,6 +786,17 @@ impl From<Builder> for HtmlTable {
     }
 }
 
+impl From<&Table> for HtmlTable {
+    /// Creates a html table out of a [`Table`] preserving its configuration.
+    ///
+    /// The first row is considered to be a header.
+    /// Alignment, padding, spans and colors (including ANSI sequences in a content) are carried over,
+    /// global settings become CSS while per cell ones become an inline `style`.
+    fn from(table: &Table) -> Self {
+        html_table_from(table)
+    }
+}
+
 impl Display for HtmlTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if !self.css.is_empty() {
@@ -524,7 +805,72 @@ impl Display for HtmlTable {
             f.write_char('\n')?;
         }
 
-        self.table.fmt(f)
+        self.build_element().fmt(f)
+    }
+}
+
+fn entity_contains(target: Entity, (row, col): Position) -> bool {
+    match target {
+        Entity::Global => true,
+        Entity::Column(c) => c == col,
+        Entity::Row(r) => r == row,
+        Entity::Cell(r, c) => r == row && c == col,
+    }
+}
+
+struct RawContent;
+
+impl HtmlVisitorMut for RawContent {
+    fn visit_element_mut(&mut self, e: &mut HtmlElement) -> bool {
+        if let Some(HtmlValue::Content(content)) = e.value() {
+            let value = Some(HtmlValue::Raw(content.clone()));
+            *e = HtmlElement::new(e.tag(), e.attrs().to_vec(), value);
+        }
+
+        true
+    }
+}
+
+struct MultilineFormatter {
+    mode: MultilineMode,
+}
+
+impl HtmlVisitorMut for MultilineFormatter {
+    fn visit_element_mut(&mut self, e: &mut HtmlElement) -> bool {
+        if e.tag() != "div" {
+            return true;
+        }
+
+        // a cell content is a list of <p> lines
+        let mut lines = vec![];
+        if let Some(HtmlValue::Elements(elements)) = e.value() {
+            for p in elements {
+                let line = match p.value() {
+                    Some(HtmlValue::Content(line)) if p.tag() == "p" => {
+                        let mut buf = String::new();
+                        escape_text(line, &mut buf);
+                        buf
+                    }
+                    Some(HtmlValue::Raw(line)) if p.tag() == "p" => line.clone(),
+                    _ => return true,
+                };
+
+                lines.push(line);
+            }
+        }
+
+        let value = match self.mode {
+            MultilineMode::Paragraphs => return true,
+            MultilineMode::LineBreaks => HtmlValue::Raw(lines.join("<br>")),
+            MultilineMode::Preformatted => {
+                let pre = HtmlElement::new("pre", vec![], Some(HtmlValue::Raw(lines.join("\n"))));
+                HtmlValue::Elements(vec![pre])
+            }
+        };
+
+        *e = HtmlElement::new("div", e.attrs().to_vec(), Some(value));
+
+        true
     }
 }
 
@@ -582,27 +928,253 @@ fn set_cell_attribute(table: &mut HtmlElement, pos: Position, attr: Attribute) {
     });
 }
 
-fn build_table(mut data: Vec<Vec<String>>, has_header: bool) -> HtmlElement {
-    let mut elements = vec![];
-    if has_header && !data.is_empty() {
-        let row = data.remove(0);
-        let th_row = build_th(row);
+fn convert_cells_ansi<F>(table: &mut HtmlElement, convert: F)
+where
+    F: FnMut(&str) -> String,
+{
+    struct Converter<F> {
+        convert: F,
+    }
+
+    impl<F> HtmlVisitorMut for Converter<F>
+    where
+        F: FnMut(&str) -> String,
+    {
+        fn visit_element_mut(&mut self, e: &mut HtmlElement) -> bool {
+            if e.tag() != "div" {
+                return true;
+            }
+
+            // a cell content is a list of <p> lines
+            let mut lines = vec![];
+            if let Some(HtmlValue::Elements(elements)) = e.value() {
+                for p in elements {
+                    // a raw content is left untouched
+                    match p.value() {
+                        Some(HtmlValue::Content(line)) if p.tag() == "p" => {
+                            lines.push(line.as_str())
+                        }
+                        _ => return true,
+                    }
+                }
+            }
+
+            if !lines.iter().any(|line| line.contains('\u{1b}')) {
+                return true;
+            }
+
+            let text = (self.convert)(&lines.join("\n"));
+            let lines = get_lines(&text)
+                .map(|line| HtmlValue::Raw(line.to_string()))
+                .map(|content| HtmlElement::new("p", vec![], Some(content)))
+                .collect();
+
+            *e = HtmlElement::new("div", e.attrs().to_vec(), Some(HtmlValue::Elements(lines)));
+
+            true
+        }
+    }
+
+    table.visit_mut(Converter { convert });
+}
+
+fn build_table(data: Vec<Vec<String>>, has_header: bool) -> HtmlElement {
+    let rows = data
+        .into_iter()
+        .enumerate()
+        .map(|(i, row)| match has_header && i == 0 {
+            true => build_th(row),
+            false => build_tr(row),
+        })
+        .collect();
+
+    build_table_sections(rows, has_header)
+}
+
+fn build_table_sections(mut rows: Vec<HtmlElement>, has_header: bool) -> HtmlElement {
+    let mut sections = TableSections::default();
+    if has_header && !rows.is_empty() {
+        let row = rows.remove(0);
+        let row = map_cells(&row, |_, cell| set_cell_tag(cell, "th", Some("col")));
+        sections.head.push(row);
+    }
+
+    sections.body = rows;
+
+    sections.into_element(vec![])
+}
+
+/// A structure of a `<table>` element.
+#[derive(Debug, Default)]
+struct TableSections {
+    caption: Option<HtmlElement>,
+    colgroup: Option<HtmlElement>,
+    head: Vec<HtmlElement>,
+    body: Vec<HtmlElement>,
+    foot: Vec<HtmlElement>,
+}
+
+impl TableSections {
+    fn new(table: &HtmlElement) -> Self {
+        let mut sections = Self::default();
+        for e in element_children(table) {
+            match e.tag() {
+                "caption" => sections.caption = Some(e.clone()),
+                "colgroup" => sections.colgroup = Some(e.clone()),
+                "thead" => sections.head = element_children(e).to_vec(),
+                "tbody" => sections.body = element_children(e).to_vec(),
+                "tfoot" => sections.foot = element_children(e).to_vec(),
+                _ => {}
+            }
+        }
+
+        sections
+    }
+
+    fn has_index_column(&self) -> bool {
+        self.body
+            .iter()
+            .chain(self.foot.iter())
+            .filter_map(|row| element_children(row).first())
+            .any(|cell| {
+                cell.tag() == "th"
+                    && cell
+                        .attrs()
+                        .iter()
+                        .any(|a| a.key() == "scope" && a.value() == "row")
+            })
+    }
+
+    fn into_element(self, attrs: Vec<Attribute>) -> HtmlElement {
+        let mut elements = vec![];
+        elements.extend(self.caption);
+        elements.extend(self.colgroup);
+
+        if !self.head.is_empty() {
+            elements.push(HtmlElement::new(
+                "thead",
+                vec![],
+                Some(HtmlValue::Elements(self.head)),
+            ));
+        }
 
         elements.push(HtmlElement::new(
-            "thead",
+            "tbody",
             vec![],
-            Some(HtmlValue::Elements(vec![th_row])),
-        ))
+            Some(HtmlValue::Elements(self.body)),
+        ));
+
+        if !self.foot.is_empty() {
+            elements.push(HtmlElement::new(
+                "tfoot",
+                vec![],
+                Some(HtmlValue::Elements(self.foot)),
+            ));
+        }
+
+        HtmlElement::new("table", attrs, Some(HtmlValue::Elements(elements)))
     }
+}
 
-    let tr_list = data.into_iter().map(build_tr).collect::<Vec<_>>();
-    elements.push(HtmlElement::new(
-        "tbody",
-        vec![],
-        Some(HtmlValue::Elements(tr_list)),
-    ));
+fn element_children(e: &HtmlElement) -> &[HtmlElement] {
+    match e.value() {
+        Some(HtmlValue::Elements(elements)) => elements,
+        _ => &[],
+    }
+}
+
+/// Returns a logical column of each cell,
+/// taking into account cells spanned from the left and from the rows above.
+fn cell_columns<'a, I>(rows: I) -> Vec<Vec<usize>>
+where
+    I: IntoIterator<Item = &'a HtmlElement>,
+{
+    // a number of rows left for each column which is occupied by a row span
+    let mut occupied: Vec<usize> = vec![];
+    let mut columns = vec![];
+    for row in rows {
+        let mut list = vec![];
+        let mut col = 0;
+        for cell in element_children(row) {
+            while occupied.get(col).copied().unwrap_or(0) > 0 {
+                col += 1;
+            }
+
+            let colspan = cell_span(cell, "colspan");
+            let rowspan = cell_span(cell, "rowspan");
+            if occupied.len() < col + colspan {
+                occupied.resize(col + colspan, 0);
+            }
+
+            for rows in &mut occupied[col..col + colspan] {
+                *rows = rowspan;
+            }
+
+            list.push(col);
+            col += colspan;
+        }
+
+        for rows in &mut occupied {
+            *rows = rows.saturating_sub(1);
+        }
+
+        columns.push(list);
+    }
+
+    columns
+}
+
+fn cell_span(cell: &HtmlElement, key: &str) -> usize {
+    cell.attrs()
+        .iter()
+        .find(|a| a.key() == key)
+        .and_then(|a| a.value().parse().ok())
+        .unwrap_or(1)
+        .max(1)
+}
+
+fn map_cells<F>(row: &HtmlElement, mut f: F) -> HtmlElement
+where
+    F: FnMut(usize, &HtmlElement) -> HtmlElement,
+{
+    let cells = element_children(row)
+        .iter()
+        .enumerate()
+        .map(|(col, cell)| f(col, cell))
+        .collect();
+
+    HtmlElement::new(
+        row.tag(),
+        row.attrs().to_vec(),
+        Some(HtmlValue::Elements(cells)),
+    )
+}
+
+fn body_cell(cell: &HtmlElement, is_index: bool) -> HtmlElement {
+    match is_index {
+        true => set_cell_tag(cell, "th", Some("row")),
+        false => set_cell_tag(cell, "td", None),
+    }
+}
+
+fn set_cell_tag(cell: &HtmlElement, tag: &str, scope: Option<&str>) -> HtmlElement {
+    let mut attrs = cell.attrs().to_vec();
+    attrs.retain(|a| a.key() != "scope");
+    if let Some(scope) = scope {
+        attrs.push(Attribute::new("scope", scope));
+    }
+
+    HtmlElement::new(tag, attrs, cell.value().cloned())
+}
 
-    HtmlElement::new("table", vec![], Some(HtmlValue::Elements(elements)))
+fn update_element_attributes<F>(e: &HtmlElement, f: F) -> HtmlElement
+where
+    F: Fn(&mut Vec<Attribute>),
+{
+    let mut attrs = e.attrs().to_vec();
+    f(&mut attrs);
+
+    HtmlElement::new(e.tag(), attrs, e.value().cloned())
 }
 
 fn build_tr(row: Vec<String>) -> HtmlElement {
@@ -616,24 +1188,120 @@ fn build_th(row: Vec<String>) -> HtmlElement {
 fn build_row(row: Vec<String>, tag: &str) -> HtmlElement {
     let th_list = row
         .into_iter()
-        .map(|content| {
-            HtmlValue::Elements(vec![HtmlElement::new(
-                "div",
-                vec![],
-                Some(HtmlValue::Elements(
-                    get_lines(&content)
-                        .map(|line| HtmlValue::Content(line.to_string()))
-                        .map(|content| HtmlElement::new("p", vec![], Some(content)))
-                        .collect(),
-                )),
-            )])
-        })
-        .map(|content| HtmlElement::new(tag, vec![], Some(content)))
+        .map(|content| build_cell(tag, &content, vec![]))
         .collect();
 
     HtmlElement::new("tr", vec![], Some(HtmlValue::Elements(th_list)))
 }
 
+fn build_cell(tag: &str, content: &str, attrs: Vec<Attribute>) -> HtmlElement {
+    let content = HtmlValue::Elements(vec![HtmlElement::new(
+        "div",
+        vec![],
+        Some(HtmlValue::Elements(
+            get_lines(content)
+                .map(|line| HtmlValue::Content(line.to_string()))
+                .map(|content| HtmlElement::new("p", vec![], Some(content)))
+                .collect(),
+        )),
+    )]);
+
+    HtmlElement::new(tag, attrs, Some(content))
+}
+
+fn html_table_from(table: &Table) -> HtmlTable {
+    let cfg = table.get_config();
+    let records = table.get_records();
+    let (count_rows, count_columns) = table.shape();
+
+    // a row span can't cross a <thead> boundary so we don't make a header in such case
+    let has_header =
+        count_rows > 0 && (0..count_columns).all(|col| cfg.get_row_span((0, col)).unwrap_or(1) < 2);
+
+    let mut rows = Vec::with_capacity(count_rows);
+    for (row, cells) in records.iter().enumerate() {
+        let tag = if has_header && row == 0 { "th" } else { "td" };
+
+        let mut list = Vec::with_capacity(count_columns);
+        for (col, cell) in cells.iter().enumerate() {
+            if !cfg.is_cell_visible((row, col)) {
+                continue;
+            }
+
+            let attrs = cell_attributes(cfg, (row, col));
+            list.push(build_cell(tag, cell.text(), attrs));
+        }
+
+        rows.push(HtmlElement::new(
+            "tr",
+            vec![],
+            Some(HtmlValue::Elements(list)),
+        ));
+    }
+
+    let mut global = BTreeMap::new();
+    global.extend(horizontal_alignment_css(
+        *cfg.get_alignment_horizontal(Entity::Global),
+    ));
+    global.extend(vertical_alignment_css(
+        *cfg.get_alignment_vertical(Entity::Global),
+    ));
+    global.extend(indent_css(cfg.get_padding(Entity::Global)));
+
+    let mut css = BTreeMap::new();
+    css.insert(entity_target(Entity::Global), global);
+
+    let mut table = HtmlTable {
+        table: build_table_sections(rows, has_header),
+        css,
+        multiline: MultilineMode::default(),
+    };
+
+    table.convert_ansi();
+
+    table
+}
+
+fn cell_attributes(cfg: &ColoredConfig, pos: Position) -> Vec<Attribute> {
+    let mut attrs = vec![];
+
+    if let Some(span) = cfg.get_column_span(pos) {
+        attrs.push(Attribute::new("colspan", span.to_string()));
+    }
+
+    if let Some(span) = cfg.get_row_span(pos) {
+        attrs.push(Attribute::new("rowspan", span.to_string()));
+    }
+
+    let cell = Entity::Cell(pos.0, pos.1);
+    let mut style = BTreeMap::new();
+
+    let alignment = *cfg.get_alignment_horizontal(cell);
+    if alignment != *cfg.get_alignment_horizontal(Entity::Global) {
+        style.extend(horizontal_alignment_css(alignment));
+    }
+
+    let alignment = *cfg.get_alignment_vertical(cell);
+    if alignment != *cfg.get_alignment_vertical(Entity::Global) {
+        style.extend(vertical_alignment_css(alignment));
+    }
+
+    let padding = cfg.get_padding(cell);
+    if padding != cfg.get_padding(Entity::Global) {
+        style.extend(indent_css(padding));
+    }
+
+    if let Some(color) = cfg.get_colors().get_color(pos) {
+        style.extend(ansi_to_css(color.get_prefix()));
+    }
+
+    if !style.is_empty() {
+        attrs.push(Attribute::new("style", build_inline_css(&style)));
+    }
+
+    attrs
+}
+
 fn entity_target(target: Entity) -> String {
     match target {
         Entity::Global => String::from("tbody > tr > td, thead > tr > th"),
@@ -697,11 +1365,63 @@ fn alignment_css(val: Alignment) -> BTreeMap<String, String> {
     m
 }
 
+fn horizontal_alignment_css(alignment: AlignmentHorizontal) -> BTreeMap<String, String> {
+    let value = match alignment {
+        AlignmentHorizontal::Left => "left",
+        AlignmentHorizontal::Right => "right",
+        AlignmentHorizontal::Center => "center",
+    };
+
+    let mut m = BTreeMap::new();
+    m.insert(String::from("text-align"), String::from(value));
+
+    m
+}
+
+fn vertical_alignment_css(alignment: AlignmentVertical) -> BTreeMap<String, String> {
+    let value = match alignment {
+        AlignmentVertical::Top => "top",
+        AlignmentVertical::Bottom => "bottom",
+        AlignmentVertical::Center => "middle",
+    };
+
+    let mut m = BTreeMap::new();
+    m.insert(String::from("vertical-align"), String::from(value));
+
+    m
+}
+
+// A terminal padding is measured in characters and lines,
+// so it's translated into `ch` and `em` correspondingly.
+fn indent_css(pad: Sides<Indent>) -> BTreeMap<String, String> {
+    let mut m = BTreeMap::new();
+    m.insert(String::from("padding-top"), format!("{}em", pad.top.size));
+    m.insert(
+        String::from("padding-bottom"),
+        format!("{}em", pad.bottom.size),
+    );
+    m.insert(String::from("padding-left"), format!("{}ch", pad.left.size));
+    m.insert(
+        String::from("padding-right"),
+        format!("{}ch", pad.right.size),
+    );
+
+    m
+}
+
+fn build_inline_css(values: &BTreeMap<String, String>) -> String {
+    values
+        .iter()
+        .map(|(key, val)| format!("{key}: {val};"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 fn build_css(css: &BTreeMap<String, BTreeMap<String, String>>) -> HtmlElement {
     HtmlElement::new(
         "style",
         vec![],
-        Some(HtmlValue::Content(
+        Some(HtmlValue::Raw(
             css.iter()
                 .map(|(target, style)| build_css_config(target, style))
                 .collect::<Vec<_>>()
@@ -727,5 +1447,6 @@ fn html_table(data: Vec<Vec<String>>, has_header: bool) -> HtmlTable {
     HtmlTable {
         table: build_table(data, has_header),
         css: BTreeMap::default(),
+        multiline: MultilineMode::default(),
     }
 }
@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+
+use tabled::settings::{style::Style, themes::Theme, Color};
+
+use crate::html::ansi_to_css;
+
+/// A CSS stylesheet which can be applied to a [`HtmlTable`].
+///
+/// There's a few ready-made themes,
+/// while a theme might also be derived from a [`Theme`] or a [`Style`].
+///
+/// ```
+/// use table_to_html::{HtmlTable, HtmlTheme};
+///
+/// let mut table = HtmlTable::new([["Hello", "World"]]);
+/// table.set_theme(&HtmlTheme::striped());
+///
+/// assert!(table.to_string().contains("tbody > tr:nth-child(even)"));
+/// ```
+///
+/// [`HtmlTable`]: crate::HtmlTable
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HtmlTheme {
+    css: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl HtmlTheme {
+    /// Creates an empty theme.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A theme with no borders except a line under a header.
+    pub fn minimal() -> Self {
+        let mut theme = Self::new();
+        theme.set_rule("table", "border-collapse", "collapse");
+        theme.set_rule("th, td", "padding", "4px 8px");
+        theme.set_rule("th, td", "text-align", "left");
+        theme.set_rule("th p, td p", "margin", "0");
+        theme.set_rule(
+            "thead > tr:last-child > th",
+            "border-bottom",
+            "1px solid #dddddd",
+        );
+
+        theme
+    }
+
+    /// A theme where each cell is surrounded by a border.
+    pub fn bordered() -> Self {
+        let mut theme = Self::new();
+        theme.set_rule("table", "border-collapse", "collapse");
+        theme.set_rule("th, td", "border", "1px solid #999999");
+        theme.set_rule("th, td", "padding", "4px 8px");
+        theme.set_rule("th p, td p", "margin", "0");
+
+        theme
+    }
+
+    /// A theme with a dark background and a light text.
+    pub fn dark() -> Self {
+        let mut theme = Self::new();
+        theme.set_rule("table", "border-collapse", "collapse");
+        theme.set_rule("table", "background-color", "#1e1e1e");
+        theme.set_rule("table", "color", "#dddddd");
+        theme.set_rule("th, td", "border", "1px solid #444444");
+        theme.set_rule("th, td", "padding", "4px 8px");
+        theme.set_rule("th p, td p", "margin", "0");
+        theme.set_rule("thead th", "background-color", "#2d2d2d");
+
+        theme
+    }
+
+    /// A [`HtmlTheme::minimal`] theme with zebra striped rows.
+    pub fn striped() -> Self {
+        let mut theme = Self::minimal();
+        theme.set_stripes("#f2f2f2");
+
+        theme
+    }
+
+    /// A theme with a small padding and font.
+    pub fn compact() -> Self {
+        let mut theme = Self::new();
+        theme.set_rule("table", "border-collapse", "collapse");
+        theme.set_rule("table", "font-size", "0.875em");
+        theme.set_rule("th, td", "padding", "1px 4px");
+        theme.set_rule("th p, td p", "margin", "0");
+
+        theme
+    }
+
+    /// Set a CSS property for a given selector.
+    pub fn set_rule<T, K, V>(&mut self, target: T, key: K, value: V)
+    where
+        T: Into<String>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.css
+            .entry(target.into())
+            .or_default()
+            .insert(key.into(), value.into());
+    }
+
+    /// Set a background color of every even row of a body (zebra striping).
+    pub fn set_stripes<C>(&mut self, color: C)
+    where
+        C: Into<String>,
+    {
+        self.set_rule("tbody > tr:nth-child(even)", "background-color", color);
+    }
+
+    /// Returns a list of CSS rules.
+    pub fn get_css(&self) -> &BTreeMap<String, BTreeMap<String, String>> {
+        &self.css
+    }
+}
+
+impl From<&Theme> for HtmlTheme {
+    /// Translates border characters into border styles and border colors into CSS colors.
+    fn from(style: &Theme) -> Self {
+        let mut theme = Self::new();
+        theme.set_rule("table", "border-collapse", "collapse");
+
+        let frame = [
+            (
+                "border-top",
+                style.get_border_top(),
+                style.get_border_color_top(),
+            ),
+            (
+                "border-bottom",
+                style.get_border_bottom(),
+                style.get_border_color_bottom(),
+            ),
+            (
+                "border-left",
+                style.get_border_left(),
+                style.get_border_color_left(),
+            ),
+            (
+                "border-right",
+                style.get_border_right(),
+                style.get_border_color_right(),
+            ),
+        ];
+
+        for (key, c, color) in frame {
+            if let Some(border) = c.and_then(|c| border_css(c, color)) {
+                theme.set_rule("table", key, border);
+            }
+        }
+
+        let vertical = style.get_border_vertical();
+        let vertical = vertical.and_then(|c| border_css(c, style.get_border_color_vertical()));
+        if let Some(border) = vertical {
+            theme.set_rule("th + th, th + td, td + th, td + td", "border-left", border);
+        }
+
+        let horizontal = style.get_border_horizontal();
+        let horizontal =
+            horizontal.and_then(|c| border_css(c, style.get_border_color_horizontal()));
+        if let Some(border) = horizontal {
+            theme.set_rule(
+                "tr + tr > th, tr + tr > td, thead + tbody > tr:first-child > *, tbody + tfoot > tr:first-child > *",
+                "border-top",
+                border,
+            );
+        }
+
+        let header = style.get_line_horizontal(1).and_then(|line| line.main);
+        if let Some(border) = header.and_then(|c| border_css(c, None)) {
+            theme.set_rule("thead > tr:last-child > th", "border-bottom", border);
+        }
+
+        theme
+    }
+}
+
+impl From<Theme> for HtmlTheme {
+    fn from(theme: Theme) -> Self {
+        Self::from(&theme)
+    }
+}
+
+impl<T, B, L, R, H, V, const HSIZE: usize, const VSIZE: usize>
+    From<Style<T, B, L, R, H, V, HSIZE, VSIZE>> for HtmlTheme
+{
+    fn from(style: Style<T, B, L, R, H, V, HSIZE, VSIZE>) -> Self {
+        Self::from(&Theme::from_style(style))
+    }
+}
+
+fn border_css(c: char, color: Option<&Color>) -> Option<String> {
+    let (width, style) = match c {
+        ' ' => return None,
+        '═' | '║' => (3, "double"),
+        '━' | '┃' => (2, "solid"),
+        '┄' | '┅' | '┆' | '┇' | '╌' | '╍' | '╎' | '╏' => (1, "dashed"),
+        '┈' | '┉' | '┊' | '┋' | '.' | ':' | '·' => (1, "dotted"),
+        _ => (1, "solid"),
+    };
+
+    let color = color
+        .and_then(|color| ansi_to_css(color.get_prefix()).remove("color"))
+        .unwrap_or_else(|| String::from("currentcolor"));
+
+    Some(format!("{width}px {style} {color}"))
+}
@@ -1,6 +1,14 @@
-use table_to_html::{Alignment, Entity, HtmlTable, Margin, Padding};
+use table_to_html::{
+    html::Attribute, Alignment, Entity, HtmlTable, HtmlTheme, Margin, MultilineMode, Padding,
+};
 
-use tabled::Table;
+use tabled::{
+    settings::{
+        object::{Cell, Columns},
+        Color, Panel, Span, Style,
+    },
+    Table,
+};
 use testing_table::test_table;
 
 test_table!(
@@ -64,21 +72,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -147,21 +155,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -266,21 +274,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -358,21 +366,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -450,21 +458,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -542,21 +550,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -631,21 +639,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -715,21 +723,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th colspan=\"3\">"
+    "            <th scope=\"col\" colspan=\"3\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -799,21 +807,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -884,21 +892,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -976,21 +984,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -1065,21 +1073,21 @@ test_table!(
     "<table>"
     "    <thead>"
     "        <tr>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        0"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        1"
     "                    </p>"
     "                </div>"
     "            </th>"
-    "            <th>"
+    "            <th scope=\"col\">"
     "                <div>"
     "                    <p>"
     "                        2"
@@ -1138,3 +1146,533 @@ test_table!(
     "    </tbody>"
     "</table>"
 );
+
+test_table!(
+    table_from_tabled_table,
+    {
+        let mut table = Table::new([["123", "324"], ["1", "2"]]);
+        table
+            .with(Panel::footer("end"))
+            .modify(Columns::single(1), Alignment::right())
+            .modify(Cell::new(1, 0), Span::column(2))
+            .modify(Cell::new(2, 0), tabled::settings::Padding::new(2, 2, 1, 0))
+            .modify(Cell::new(2, 1), Color::FG_RED | Color::BOLD);
+
+        HtmlTable::from(&table)
+    },
+    "<style>"
+    "    tbody > tr > td, thead > tr > th {"
+    "      padding-bottom: 0em;"
+    "      padding-left: 1ch;"
+    "      padding-right: 1ch;"
+    "      padding-top: 0em;"
+    "      text-align: left;"
+    "      vertical-align: top;"
+    "    }"
+    "</style>"
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th scope=\"col\">"
+    "                <div>"
+    "                    <p>"
+    "                        0"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <th style=\"text-align: right;\" scope=\"col\">"
+    "                <div>"
+    "                    <p>"
+    "                        1"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td colspan=\"2\">"
+    "                <div>"
+    "                    <p>"
+    "                        123"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td style=\"padding-bottom: 0em; padding-left: 2ch; padding-right: 2ch; padding-top: 1em;\">"
+    "                <div>"
+    "                    <p>"
+    "                        1"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td style=\"color: #800000; font-weight: bold; text-align: right;\">"
+    "                <div>"
+    "                    <p>"
+    "                        2"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td colspan=\"2\">"
+    "                <div>"
+    "                    <p>"
+    "                        end"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    table_from_tabled_table_spans_class,
+    {
+        let mut table = Table::new([["1", "2", "3"], ["4", "5", "6"], ["7", "8", "9"]]);
+        table
+            .with(Style::empty())
+            .modify(Cell::new(1, 0), Span::column(2))
+            .modify(Cell::new(2, 0), Span::row(2));
+
+        let mut html = HtmlTable::from(&table);
+        html.add_class(Entity::Column(2), "last");
+        html.set_attribute(Entity::Cell(3, 1), Attribute::new("id", "eight"));
+        html
+    },
+    "<style>"
+    "    tbody > tr > td, thead > tr > th {"
+    "      padding-bottom: 0em;"
+    "      padding-left: 1ch;"
+    "      padding-right: 1ch;"
+    "      padding-top: 0em;"
+    "      text-align: left;"
+    "      vertical-align: top;"
+    "    }"
+    "</style>"
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th scope=\"col\">"
+    "                <div>"
+    "                    <p>"
+    "                        0"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <th scope=\"col\">"
+    "                <div>"
+    "                    <p>"
+    "                        1"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <th scope=\"col\" class=\"last\">"
+    "                <div>"
+    "                    <p>"
+    "                        2"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td colspan=\"2\">"
+    "                <div>"
+    "                    <p>"
+    "                        1"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td class=\"last\">"
+    "                <div>"
+    "                    <p>"
+    "                        3"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td rowspan=\"2\">"
+    "                <div>"
+    "                    <p>"
+    "                        4"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        5"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td class=\"last\">"
+    "                <div>"
+    "                    <p>"
+    "                        6"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td id=\"eight\">"
+    "                <div>"
+    "                    <p>"
+    "                        8"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td class=\"last\">"
+    "                <div>"
+    "                    <p>"
+    "                        9"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    table_convert_ansi,
+    {
+        let mut table = HtmlTable::new([["\u{1b}[31mHello\nWorld\u{1b}[39m", "\u{1b}[1;4m<b>\u{1b}[0m"]]);
+        table.convert_ansi();
+        table
+    },
+    "<table>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        <span style=\"color: #800000;\">Hello</span>"
+    "                    </p>"
+    "                    <p>"
+    "                        <span style=\"color: #800000;\">World</span>"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        <span style=\"font-weight: bold; text-decoration: underline;\">&lt;b&gt;</span>"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    table_convert_ansi_to_classes,
+    {
+        let mut table = HtmlTable::new([["\u{1b}[38;5;196mHello\u{1b}[39m \u{1b}[44mWorld\u{1b}[49m"]]);
+        table.convert_ansi_to_classes();
+        table
+    },
+    "<style>"
+    "    .ansi-bg-000080 {"
+    "      background-color: #000080;"
+    "    }"
+    "    .ansi-fg-ff0000 {"
+    "      color: #ff0000;"
+    "    }"
+    "</style>"
+    "<table>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        <span class=\"ansi-fg-ff0000\">Hello</span> <span class=\"ansi-bg-000080\">World</span>"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    table_semantic_structure,
+    {
+        let mut table = HtmlTable::new([["#", "name"], ["1", "Debian"], ["2", "Arch"], ["", "total: 2"]]);
+        table.set_caption("Distributions");
+        table.set_header_rows(1);
+        table.set_footer_rows(1);
+        table.set_index_column();
+        table.set_column_widths([20, 100]);
+        table.set_attribute(Entity::Global, Attribute::new("id", "distros"));
+        table.add_class(Entity::Row(1), "odd");
+        table.add_class(Entity::Column(1), "name");
+        table.add_class(Entity::Cell(1, 1), "debian");
+        table
+    },
+    "<table id=\"distros\">"
+    "    <caption>"
+    "        Distributions"
+    "    </caption>"
+    "    <colgroup>"
+    "        <col style=\"width: 20px;\">"
+    "        <col style=\"width: 100px;\">"
+    "    </colgroup>"
+    "    <thead>"
+    "        <tr>"
+    "            <th scope=\"col\">"
+    "                <div>"
+    "                    <p>"
+    "                        #"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <th scope=\"col\" class=\"name\">"
+    "                <div>"
+    "                    <p>"
+    "                        name"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr class=\"odd\">"
+    "            <th scope=\"row\">"
+    "                <div>"
+    "                    <p>"
+    "                        1"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <td class=\"name debian\">"
+    "                <div>"
+    "                    <p>"
+    "                        Debian"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "        <tr>"
+    "            <th scope=\"row\">"
+    "                <div>"
+    "                    <p>"
+    "                        2"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <td class=\"name\">"
+    "                <div>"
+    "                    <p>"
+    "                        Arch"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "    <tfoot>"
+    "        <tr>"
+    "            <th scope=\"row\">"
+    "                <div>"
+    "                    <p>"
+    "                        "
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <td class=\"name\">"
+    "                <div>"
+    "                    <p>"
+    "                        total: 2"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tfoot>"
+    "</table>"
+);
+
+test_table!(
+    table_theme_striped,
+    {
+        let mut table = HtmlTable::with_header([["name"], ["Debian"]]);
+        table.set_theme(&HtmlTheme::striped());
+        table
+    },
+    "<style>"
+    "    table {"
+    "      border-collapse: collapse;"
+    "    }"
+    "    tbody > tr:nth-child(even) {"
+    "      background-color: #f2f2f2;"
+    "    }"
+    "    th p, td p {"
+    "      margin: 0;"
+    "    }"
+    "    th, td {"
+    "      padding: 4px 8px;"
+    "      text-align: left;"
+    "    }"
+    "    thead > tr:last-child > th {"
+    "      border-bottom: 1px solid #dddddd;"
+    "    }"
+    "</style>"
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th scope=\"col\">"
+    "                <div>"
+    "                    <p>"
+    "                        name"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        Debian"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    table_theme_from_style,
+    {
+        let mut table = HtmlTable::with_header([["name"], ["Debian"]]);
+        table.set_theme(&HtmlTheme::from(Style::extended()));
+        table
+    },
+    "<style>"
+    "    table {"
+    "      border-bottom: 3px double currentcolor;"
+    "      border-collapse: collapse;"
+    "      border-left: 3px double currentcolor;"
+    "      border-right: 3px double currentcolor;"
+    "      border-top: 3px double currentcolor;"
+    "    }"
+    "    th + th, th + td, td + th, td + td {"
+    "      border-left: 3px double currentcolor;"
+    "    }"
+    "    tr + tr > th, tr + tr > td, thead + tbody > tr:first-child > *, tbody + tfoot > tr:first-child > * {"
+    "      border-top: 3px double currentcolor;"
+    "    }"
+    "</style>"
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th scope=\"col\">"
+    "                <div>"
+    "                    <p>"
+    "                        name"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        Debian"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    table_escaping_and_raw_html,
+    {
+        let mut table = HtmlTable::new([["<b>\"Tom\" & 'Jerry'</b>", "<a href=\"/tom\">Tom</a>"]]);
+        table.set_raw_html(Entity::Column(1));
+        table
+    },
+    "<table>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        &lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        <a href=\"/tom\">Tom</a>"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    table_multiline_line_breaks,
+    {
+        let mut table = HtmlTable::new([["Hello\n<World>", "  fn main() {\n      todo!()\n  }"]]);
+        table.set_multiline(MultilineMode::LineBreaks);
+        table
+    },
+    "<table>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>"
+    "                <div>"
+    "                    Hello<br>&lt;World&gt;"
+    "                </div>"
+    "            </td>"
+    "            <td>"
+    "                <div>"
+    "                      fn main() {<br>      todo!()<br>  }"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    table_multiline_preformatted,
+    {
+        let mut table = HtmlTable::new([["Hello\n<World>", "  fn main() {\n      todo!()\n  }"]]);
+        table.set_multiline(MultilineMode::Preformatted);
+        table
+    },
+    "<table>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>"
+    "                <div>"
+    "                    <pre>Hello"
+    "&lt;World&gt;</pre>"
+    "                </div>"
+    "            </td>"
+    "            <td>"
+    "                <div>"
+    "                    <pre>  fn main() {"
+    "      todo!()"
+    "  }</pre>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
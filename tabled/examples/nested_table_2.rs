@@ -3,17 +3,17 @@
 //!
 //! * This second nested [`Table`] example showcases the [`derive`] approach.
 //!
-//! * Note how the [`display_with`] attribute macro applies the custom `display_distribution`
-//! filter function, which, in this case, applies styles to the final display.
+//! * Note how the [`table`] attribute macro renders a field as a nested [`Table`],
+//! which, in this case, applies styles to the final display.
 
 use tabled::{settings::Style, Table, Tabled};
 
 #[derive(Tabled)]
 struct Vendor {
     name: &'static str,
-    #[tabled(display_with = "display_distribution")]
+    #[tabled(table(style = "extended"))]
     main_os: Distribution,
-    #[tabled(display_with = "display_distribution")]
+    #[tabled(table(style = "extended"))]
     switch_os: Distribution,
 }
 
@@ -27,10 +27,6 @@ impl Vendor {
     }
 }
 
-fn display_distribution(d: &Distribution) -> String {
-    Table::new([d]).with(Style::extended()).to_string()
-}
-
 #[derive(Tabled)]
 struct Distribution {
     name: &'static str,
@@ -382,10 +382,10 @@ pub use crate::{tabled::Tabled, tables::Table};
 /// ### Format fields
 ///
 /// As was said already, using `#[derive(Tabled)]` is possible only when all fields implement a `Display` trait.
-/// However, this may be often not the case for example when a field uses the `Option` type. There's 2 common ways how to solve this:
+/// However, this may be often not the case for example when a field uses a type from another crate. There's 2 common ways how to solve this:
 ///
 /// - Implement `Tabled` trait manually for a type.
-/// - Wrap `Option` to something like `DisplayedOption<T>(Option<T>)` and implement a Display trait for it.
+/// - Wrap a type to something like `Displayed<T>(T)` and implement a Display trait for it.
 ///
 /// Alternatively, you can use the `#[tabled(display_with = "func")]` attribute for the field to specify a display function.
 ///
@@ -445,6 +445,78 @@ pub use crate::{tabled::Tabled, tables::Table};
 /// }
 /// ```
 ///
+/// ### Optional fields
+///
+/// `Option<T>` fields are displayed by a value when it's present, and by an empty string otherwise.
+/// A placeholder for `None` can be set by `#[tabled(display_option = "-")]` on a field,
+/// or on a type so it's used by all of its fields.
+/// An inlined `Option` of a `Tabled` type fills all of its columns with the placeholder.
+///
+/// ```rust
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Address {
+///     city: &'static str,
+///     street: &'static str,
+/// }
+///
+/// #[derive(Tabled)]
+/// #[tabled(display_option = "-")]
+/// struct User {
+///     name: &'static str,
+///     age: Option<u8>,
+///     #[tabled(inline)]
+///     address: Option<Address>,
+/// }
+///
+/// let users = [
+///     User { name: "Maxim", age: None, address: Some(Address { city: "Minsk", street: "Nezavisimosti" }) },
+///     User { name: "Anna", age: Some(25), address: None },
+/// ];
+///
+/// let table = Table::new(users).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-------+-----+-------+---------------+\n\
+///      | name  | age | city  | street        |\n\
+///      +-------+-----+-------+---------------+\n\
+///      | Maxim | -   | Minsk | Nezavisimosti |\n\
+///      +-------+-----+-------+---------------+\n\
+///      | Anna  | 25  | -     | -             |\n\
+///      +-------+-----+-------+---------------+"
+/// );
+/// ```
+///
+/// ### Column settings
+///
+/// A column presentation can be set right on a field,
+/// so it's applied every time a table is built by `Table::new`.
+///
+/// - `#[tabled(align = "right")]` sets an alignment, one of `left`, `center` and `right`.
+/// - `#[tabled(color = "red")]` sets a color, like `red` or `bright_red`.
+/// - `#[tabled(max_width = 20)]` truncates longer cells.
+/// - `#[tabled(max_width = 20, truncate = "...")]` truncates longer cells with a suffix.
+/// - `#[tabled(max_width = 20, wrap)]` wraps longer cells instead.
+///
+/// ```
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Package {
+///     name: &'static str,
+///     #[tabled(align = "right")]
+///     downloads: usize,
+///     #[tabled(max_width = 10, truncate = "...")]
+///     description: &'static str,
+/// }
+/// ```
+///
+/// The settings are applied to data rows only, so headers are left as they are.
+/// They follow `order`, `skip` and `inline` attributes,
+/// and the ones of an inlined type are kept as well.
+///
 /// ### Format headers
 ///
 /// Beside `#[tabled(rename = "")]` you can change a format of a column name using
@@ -512,6 +584,165 @@ pub use crate::{tabled::Tabled, tables::Table};
 ///     price: f32,
 /// }
 /// ```
+///
+/// ### Group headers
+///
+/// Columns can be put under a shared header using `#[tabled(group = "Name")]`.
+/// Adjacent columns with the same group are spanned by a single cell above their names.
+/// A bare `#[tabled(group)]` on an inlined field uses a name of its type,
+/// so groups of the type are nested under it.
+///
+/// ```rust
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct User {
+///     name: &'static str,
+///     #[tabled(group = "Address")]
+///     city: &'static str,
+///     #[tabled(group = "Address")]
+///     street: &'static str,
+/// }
+///
+/// let users = [User { name: "Maxim", city: "Minsk", street: "Nezavisimosti" }];
+///
+/// let table = Table::new(users).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-------+-------+---------------+\n\
+///      | name  | Address               |\n\
+///      +       +-------+---------------+\n\
+///      |       | city  | street        |\n\
+///      +-------+-------+---------------+\n\
+///      | Maxim | Minsk | Nezavisimosti |\n\
+///      +-------+-------+---------------+"
+/// );
+/// ```
+///
+/// ### Explode
+///
+/// A list field can be rendered as several rows using `#[tabled(explode)]`,
+/// a row per element, while the rest of the fields are spanned over them.
+/// Combined with `inline` the elements are expected to implement `Tabled` themselves.
+/// Otherwise each element is a single cell, and `format` or `display_with` are applied to each of them.
+///
+/// ```rust
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Item {
+///     product: &'static str,
+///     quantity: u8,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Order {
+///     id: u8,
+///     #[tabled(explode, inline)]
+///     items: Vec<Item>,
+/// }
+///
+/// let orders = [Order {
+///     id: 1,
+///     items: vec![
+///         Item { product: "apple", quantity: 3 },
+///         Item { product: "pear", quantity: 1 },
+///     ],
+/// }];
+///
+/// let table = Table::new(orders).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+----+---------+----------+\n\
+///      | id | product | quantity |\n\
+///      +----+---------+----------+\n\
+///      | 1  | apple   | 3        |\n\
+///      +    +---------+----------+\n\
+///      |    | pear    | 1        |\n\
+///      +----+---------+----------+"
+/// );
+/// ```
+///
+/// ### Nested tables
+///
+/// A field which implements `Tabled` can be rendered as a nested table using `#[tabled(table)]`.
+/// A collection of such values, like `Vec<T>`, is rendered as a table of its elements.
+/// A style of a nested table can be set by `#[tabled(table(style = "modern"))]`.
+///
+/// ```rust
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Author {
+///     name: &'static str,
+///     country: &'static str,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Book {
+///     title: &'static str,
+///     #[tabled(table(style = "psql"))]
+///     author: Author,
+/// }
+///
+/// let books = [Book { title: "Dune", author: Author { name: "Frank Herbert", country: "US" } }];
+///
+/// let table = Table::new(books).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-------+---------------------------+\n\
+///      | title | author                    |\n\
+///      +-------+---------------------------+\n\
+///      | Dune  |  name          | country  |\n\
+///      |       | ---------------+--------- |\n\
+///      |       |  Frank Herbert | US       |\n\
+///      +-------+---------------------------+"
+/// );
+/// ```
+///
+/// ### Enum modes
+///
+/// By default an enum has a column per variant, which is marked by `+`.
+/// It can be changed by `#[tabled(enum = "tag")]`, so a single column with a variant name is used,
+/// or by `#[tabled(enum = "union")]`, so a variant name is followed by a union of fields of all variants.
+/// Fields with the same name share a column, and fields of other variants are left blank.
+/// Unnamed fields are prefixed by a variant name, like `Error.0`, unless they're renamed.
+///
+/// ```rust
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// #[tabled(enum = "union")]
+/// enum Event {
+///     Login { user: &'static str },
+///     Upload { user: &'static str, size: usize },
+///     Shutdown,
+/// }
+///
+/// let events = [
+///     Event::Login { user: "maxim" },
+///     Event::Upload { user: "anna", size: 1024 },
+///     Event::Shutdown,
+/// ];
+///
+/// let table = Table::new(events).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+----------+-------+------+\n\
+///      | Event    | user  | size |\n\
+///      +----------+-------+------+\n\
+///      | Login    | maxim |      |\n\
+///      +----------+-------+------+\n\
+///      | Upload   | anna  | 1024 |\n\
+///      +----------+-------+------+\n\
+///      | Shutdown |       |      |\n\
+///      +----------+-------+------+"
+/// );
+/// ```
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use tabled_derive::Tabled;
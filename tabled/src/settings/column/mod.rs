@@ -0,0 +1,165 @@
+//! This module contains a [`ColumnSettings`] structure,
+//! which keeps a presentation of a column defined by [`Tabled`].
+//!
+//! [`Tabled`]: crate::Tabled
+
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, ColoredConfig},
+        records::{ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
+    },
+    settings::{
+        object::{Columns, Object, Rows},
+        Alignment, Color, Modify, TableOption, Width,
+    },
+};
+
+/// A presentation of a single column,
+/// which is usually defined by `#[tabled(...)]` attributes of a field.
+///
+/// A list of them is returned by [`Tabled::columns`] and applied by [`Table::new`],
+/// but it can be applied to any table as a regular setting.
+///
+/// Only data rows are changed, a header is left as it is.
+///
+/// ```
+/// use tabled::{grid::config::AlignmentHorizontal, settings::ColumnSettings, Table};
+///
+/// let mut table = Table::new([("Hello", 1), ("World", 123)]);
+/// table.with(ColumnSettings::new().alignment(AlignmentHorizontal::Right).shift(1));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------+-----+\n\
+///      | &str  | i32 |\n\
+///      +-------+-----+\n\
+///      | Hello |   1 |\n\
+///      +-------+-----+\n\
+///      | World | 123 |\n\
+///      +-------+-----+"
+/// );
+/// ```
+///
+/// [`Tabled::columns`]: crate::Tabled::columns
+/// [`Table::new`]: crate::Table::new
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSettings {
+    column: usize,
+    header: usize,
+    alignment: Option<AlignmentHorizontal>,
+    color: Option<Color>,
+    max_width: Option<usize>,
+    wrap: bool,
+    suffix: Option<String>,
+}
+
+impl ColumnSettings {
+    /// Creates an empty settings of a first column.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a number of first rows which make a header,
+    /// they are left as they are.
+    ///
+    /// By default it's a single row.
+    pub fn header(mut self, rows: usize) -> Self {
+        self.header = rows;
+        self
+    }
+
+    /// Moves the settings right by a given number of columns.
+    pub fn shift(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+
+    /// Sets a horizontal alignment of the column.
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets a color of the column.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets a maximum width of the column.
+    ///
+    /// By default longer cells are truncated.
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Wrap cells which are longer than a maximum width instead of truncating them.
+    pub fn wrap(mut self, on: bool) -> Self {
+        self.wrap = on;
+        self
+    }
+
+    /// Sets a suffix which is appended to truncated cells.
+    pub fn suffix<S>(mut self, suffix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Returns an index of the column.
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Default for ColumnSettings {
+    fn default() -> Self {
+        Self {
+            column: 0,
+            header: 1,
+            alignment: None,
+            color: None,
+            max_width: None,
+            wrap: false,
+            suffix: None,
+        }
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for ColumnSettings
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, dims: &mut D) {
+        let (column, header) = (self.column, self.header);
+        let column = move || Columns::single(column).not(Rows::new(..header));
+
+        if let Some(alignment) = self.alignment {
+            Modify::new(column())
+                .with(Alignment::from(alignment))
+                .change(records, cfg, dims);
+        }
+
+        if let Some(color) = self.color {
+            Modify::new(column()).with(color).change(records, cfg, dims);
+        }
+
+        if let Some(width) = self.max_width {
+            if self.wrap {
+                Modify::new(column())
+                    .with(Width::wrap(width))
+                    .change(records, cfg, dims);
+            } else {
+                let suffix = self.suffix.unwrap_or_default();
+                Modify::new(column())
+                    .with(Width::truncate(width).suffix(suffix))
+                    .change(records, cfg, dims);
+            }
+        }
+    }
+}
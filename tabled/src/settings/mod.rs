@@ -58,6 +58,8 @@ mod modify;
 #[cfg(feature = "std")]
 mod color;
 #[cfg(feature = "std")]
+mod column;
+#[cfg(feature = "std")]
 mod concat;
 #[cfg(feature = "std")]
 mod duplicate;
@@ -127,6 +129,7 @@ pub use self::{
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
     color::Color,
+    column::ColumnSettings,
     concat::Concat,
     disable::Disable,
     duplicate::Dup,
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::settings::ColumnSettings;
+
 /// Tabled a trait responsible for providing a header fields and a row fields.
 ///
 /// It's urgent that `header` len is equal to `fields` len.
@@ -18,6 +20,45 @@ pub trait Tabled {
     fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<Cow<'static, str>>;
+    /// Columns method may return a presentation of columns,
+    /// which is applied by [`Table::new`].
+    ///
+    /// By default there's none.
+    ///
+    /// [`Table::new`]: crate::Table::new
+    fn columns() -> Vec<ColumnSettings> {
+        Vec::new()
+    }
+    /// Groups method may return a list of groups of each column,
+    /// from the outermost one to the innermost one.
+    ///
+    /// [`Table::new`] renders them as header rows above the headers,
+    /// where a group is spanned over adjacent columns it contains.
+    ///
+    /// By default there's none.
+    ///
+    /// [`Table::new`]: crate::Table::new
+    fn groups() -> Vec<Vec<Cow<'static, str>>> {
+        Vec::new()
+    }
+    /// Rows method returns a list of rows a value is rendered as.
+    ///
+    /// By default it's a single row of [`Tabled::fields`].
+    fn rows(&self) -> Vec<Vec<Cow<'_, str>>> {
+        vec![self.fields()]
+    }
+    /// Exploded columns method returns a list of columns
+    /// which cells differ from row to row returned by [`Tabled::rows`].
+    ///
+    /// The rest of the cells are shared by the rows,
+    /// so [`Table::new`] spans them over the rows.
+    ///
+    /// By default there's none.
+    ///
+    /// [`Table::new`]: crate::Table::new
+    fn exploded_columns() -> Vec<usize> {
+        Vec::new()
+    }
 }
 
 impl<T> Tabled for &T
@@ -32,6 +73,18 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn columns() -> Vec<ColumnSettings> {
+        T::columns()
+    }
+    fn groups() -> Vec<Vec<Cow<'static, str>>> {
+        T::groups()
+    }
+    fn rows(&self) -> Vec<Vec<Cow<'_, str>>> {
+        T::rows(self)
+    }
+    fn exploded_columns() -> Vec<usize> {
+        T::exploded_columns()
+    }
 }
 
 impl<T> Tabled for Box<T>
@@ -46,6 +99,18 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn columns() -> Vec<ColumnSettings> {
+        T::columns()
+    }
+    fn groups() -> Vec<Vec<Cow<'static, str>>> {
+        T::groups()
+    }
+    fn rows(&self) -> Vec<Vec<Cow<'_, str>>> {
+        T::rows(self)
+    }
+    fn exploded_columns() -> Vec<usize> {
+        T::exploded_columns()
+    }
 }
 
 macro_rules! tuple_table {
@@ -66,10 +131,80 @@ macro_rules! tuple_table {
                 $(fields.append(&mut $name::headers());)+
                 fields
             }
+
+            fn columns() -> Vec<ColumnSettings> {
+                let mut columns = Vec::new();
+                let mut offset = 0;
+                $(
+                    columns.extend($name::columns().into_iter().map(|c| c.shift(offset)));
+                    offset += $name::LENGTH;
+                )+
+                let _ = offset;
+                columns
+            }
+
+            fn groups() -> Vec<Vec<Cow<'static, str>>> {
+                let mut groups = Vec::with_capacity(Self::LENGTH);
+                $(
+                    let mut list = $name::groups();
+                    list.resize($name::LENGTH, Vec::new());
+                    groups.append(&mut list);
+                )+
+                groups
+            }
+
+            fn rows(&self) -> Vec<Vec<Cow<'_, str>>> {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = self;
+                let parts = vec![
+                    $(($name.rows(), $name::LENGTH, !$name::exploded_columns().is_empty()),)+
+                ];
+                join_rows(parts, Self::LENGTH)
+            }
+
+            fn exploded_columns() -> Vec<usize> {
+                let mut columns = Vec::new();
+                let mut offset = 0;
+                $(
+                    columns.extend($name::exploded_columns().into_iter().map(|c| c + offset));
+                    offset += $name::LENGTH;
+                )+
+                let _ = offset;
+                columns
+            }
         }
     };
 }
 
+type Rows<'a> = Vec<Vec<Cow<'a, str>>>;
+
+// Joins rows of a few values side by side.
+//
+// A single row of a value which is not exploded is repeated,
+// and the missing rows are filled with empty cells.
+fn join_rows(parts: Vec<(Rows<'_>, usize, bool)>, length: usize) -> Rows<'_> {
+    let count_rows = parts
+        .iter()
+        .map(|(rows, _, _)| rows.len())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let mut out = vec![Vec::with_capacity(length); count_rows];
+    for (rows, length, exploded) in parts {
+        let is_shared = !exploded && rows.len() == 1;
+        for (i, row) in out.iter_mut().enumerate() {
+            match rows.get(i) {
+                Some(cells) => row.extend(cells.iter().cloned()),
+                None if is_shared => row.extend(rows[0].iter().cloned()),
+                None => row.extend(std::iter::repeat(Cow::Borrowed("")).take(length)),
+            }
+        }
+    }
+
+    out
+}
+
 tuple_table! { A }
 tuple_table! { A B }
 tuple_table! { A B C }
@@ -65,6 +65,9 @@ pub struct Table {
 impl Table {
     /// New creates a Table instance.
     ///
+    /// A presentation of columns returned by [`Tabled::columns`] is applied right away.
+    /// A value which is rendered as several [`Tabled::rows`] gets its shared cells spanned over them.
+    ///
     /// If you use a reference iterator you'd better use [`FromIterator`] instead.
     /// As it has a different lifetime constraints and make less copies therefore.
     pub fn new<I, T>(iter: I) -> Self
@@ -79,30 +82,61 @@ impl Table {
             header.push(cell);
         }
 
-        let mut records = vec![header];
-        for row in iter.into_iter() {
-            let mut list = Vec::with_capacity(T::LENGTH);
-            for text in row.fields().into_iter() {
-                let text = text.into_owned();
-                let cell = CellInfo::new(text);
-
-                list.push(cell);
+        let groups = T::groups();
+        let mut records = group_header(header, &groups);
+        let mut exploded_rows = Vec::new();
+        for value in iter.into_iter() {
+            let rows = value.rows();
+            if rows.len() > 1 {
+                exploded_rows.push((records.len(), rows.len()));
             }
 
-            records.push(list);
+            for row in rows {
+                let mut list = Vec::with_capacity(T::LENGTH);
+                for text in row.into_iter() {
+                    let text = text.into_owned();
+                    let cell = CellInfo::new(text);
+
+                    list.push(cell);
+                }
+
+                records.push(list);
+            }
         }
 
         let records = VecRecords::new(records);
 
-        Self {
+        let mut config = configure_grid();
+        set_group_spans(&mut config, &groups, T::LENGTH);
+        set_shared_spans(
+            &mut config,
+            &exploded_rows,
+            &T::exploded_columns(),
+            T::LENGTH,
+        );
+
+        let mut table = Self {
             records,
-            config: ColoredConfig::new(configure_grid()),
+            config: ColoredConfig::new(config),
             dimension: CompleteDimensionVecRecords::default(),
+        };
+
+        // a header consists of group rows and a row of column names
+        let header_rows = groups.iter().map(Vec::len).max().unwrap_or(0) + 1;
+        for column in T::columns() {
+            let _ = table.with(column.header(header_rows));
         }
+
+        table
     }
 
     /// Creates a builder from a data set given.
     ///
+    /// The builder keeps only a header and [`Tabled::rows`] of each value.
+    /// Unlike [`Table::new`] it doesn't apply [`Tabled::columns`] and [`Tabled::groups`],
+    /// and it doesn't span cells shared by several rows.
+    /// [`Tabled::columns`] can still be applied to a built table by [`Table::with`].
+    ///
     /// # Example
     ///
     ///
@@ -159,8 +193,10 @@ impl Table {
         let mut builder = Builder::with_capacity(0, T::LENGTH);
         builder.push_record(T::headers());
 
-        for row in iter {
-            builder.push_record(row.fields().into_iter());
+        for value in iter {
+            for row in value.rows() {
+                builder.push_record(row.into_iter());
+            }
         }
 
         builder
@@ -409,6 +445,83 @@ fn configure_grid() -> SpannedConfig {
     cfg
 }
 
+fn group_header(
+    header: Vec<CellInfo<String>>,
+    groups: &[Vec<Cow<'static, str>>],
+) -> Vec<Vec<CellInfo<String>>> {
+    let depth = groups.iter().map(Vec::len).max().unwrap_or(0);
+    if depth == 0 {
+        return vec![header];
+    }
+
+    let mut rows = vec![vec![CellInfo::new(String::new()); header.len()]; depth + 1];
+    for (col, text) in header.into_iter().enumerate() {
+        let path = groups.get(col).map_or(&[][..], Vec::as_slice);
+        for (level, name) in path.iter().enumerate() {
+            rows[level][col] = CellInfo::new(name.to_string());
+        }
+
+        // a header is put right under its last group and spanned down
+        rows[path.len()][col] = text;
+    }
+
+    rows
+}
+
+fn set_group_spans(
+    cfg: &mut SpannedConfig,
+    groups: &[Vec<Cow<'static, str>>],
+    count_columns: usize,
+) {
+    let depth = groups.iter().map(Vec::len).max().unwrap_or(0);
+    if depth == 0 {
+        return;
+    }
+
+    let is_same_group = |a: usize, b: usize, level: usize| match (groups.get(a), groups.get(b)) {
+        (Some(a), Some(b)) => a.len() > level && b.len() > level && a[..=level] == b[..=level],
+        _ => false,
+    };
+
+    for col in 0..count_columns {
+        let path_len = groups.get(col).map_or(0, Vec::len);
+        if path_len < depth {
+            cfg.set_row_span((path_len, col), depth - path_len + 1);
+        }
+
+        for level in 0..path_len {
+            if col > 0 && is_same_group(col - 1, col, level) {
+                continue;
+            }
+
+            let mut span = 1;
+            while col + span < count_columns && is_same_group(col, col + span, level) {
+                span += 1;
+            }
+
+            if span > 1 {
+                cfg.set_column_span((level, col), span);
+            }
+        }
+    }
+}
+
+// Cells which are shared by rows of a single value are spanned over them.
+fn set_shared_spans(
+    cfg: &mut SpannedConfig,
+    rows: &[(usize, usize)],
+    exploded_columns: &[usize],
+    count_columns: usize,
+) {
+    for &(row, count_rows) in rows {
+        for col in 0..count_columns {
+            if !exploded_columns.contains(&col) {
+                cfg.set_row_span((row, col), count_rows);
+            }
+        }
+    }
+}
+
 fn use_format_configuration<'a>(
     f: &mut fmt::Formatter<'_>,
     table: &'a Table,
@@ -747,6 +747,615 @@ mod derived {
         "| Keep it simple                     | Unknown         | 🍳                            | 100    |"
         "+------------------------------------+-----------------+-------------------------------+--------+"
     );
+    test_table!(
+        table_column_settings,
+        Table::new({
+            #[derive(Tabled)]
+            struct Package {
+                name: &'static str,
+                #[tabled(align = "right")]
+                downloads: usize,
+                #[tabled(max_width = 10, truncate = "...")]
+                description: &'static str,
+                #[tabled(max_width = 6, wrap)]
+                license: &'static str,
+            }
+
+            vec![
+                Package {
+                    name: "tabled",
+                    downloads: 9001,
+                    description: "An easy to use library for pretty print tables",
+                    license: "MIT",
+                },
+                Package {
+                    name: "serde",
+                    downloads: 320000000,
+                    description: "A serialization framework",
+                    license: "MIT OR Apache-2.0",
+                },
+            ]
+        }),
+        "+--------+-----------+-------------+---------+"
+        "| name   | downloads | description | license |"
+        "+--------+-----------+-------------+---------+"
+        "| tabled |      9001 | An easy...  | MIT     |"
+        "+--------+-----------+-------------+---------+"
+        "| serde  | 320000000 | A seria...  | MIT OR  |"
+        "|        |           |             |  Apach  |"
+        "|        |           |             | e-2.0   |"
+        "+--------+-----------+-------------+---------+"
+    );
+
+    test_table!(
+        table_column_settings_order_skip_inline,
+        Table::new({
+            #[derive(Tabled)]
+            struct Price {
+                #[tabled(align = "right")]
+                amount: usize,
+                currency: &'static str,
+            }
+
+            #[derive(Tabled)]
+            struct Item {
+                #[tabled(skip)]
+                #[allow(dead_code)]
+                id: usize,
+                #[tabled(inline)]
+                price: Price,
+                #[tabled(order = 0, align = "center")]
+                name: &'static str,
+            }
+
+            vec![
+                Item { id: 0, price: Price { amount: 1, currency: "EUR" }, name: "pen" },
+                Item { id: 1, price: Price { amount: 1500, currency: "USD" }, name: "laptop" },
+            ]
+        }),
+        "+--------+--------+----------+"
+        "| name   | amount | currency |"
+        "+--------+--------+----------+"
+        "|  pen   |      1 | EUR      |"
+        "+--------+--------+----------+"
+        "| laptop |   1500 | USD      |"
+        "+--------+--------+----------+"
+    );
+
+    test_table!(
+        table_column_settings_color,
+        Table::new({
+            #[derive(Tabled)]
+            struct Status {
+                name: &'static str,
+                #[tabled(color = "red")]
+                state: &'static str,
+            }
+
+            vec![Status { name: "db", state: "down" }]
+        }),
+        "+------+-------+"
+        "| name | state |"
+        "+------+-------+"
+        "| db   | \u{1b}[31mdown\u{1b}[39m  |"
+        "+------+-------+"
+    );
+
+    test_table!(
+        table_column_settings_tuple,
+        Table::new({
+            #[derive(Tabled)]
+            struct Id {
+                #[tabled(align = "right")]
+                id: usize,
+            }
+
+            vec![("first", Id { id: 1 }), ("second", Id { id: 100 })]
+        }),
+        "+--------+-----+"
+        "| &str   | id  |"
+        "+--------+-----+"
+        "| first  |   1 |"
+        "+--------+-----+"
+        "| second | 100 |"
+        "+--------+-----+"
+    );
+
+    test_table!(
+        table_groups,
+        Table::new({
+            #[derive(Tabled)]
+            struct User {
+                name: &'static str,
+                #[tabled(group = "Address")]
+                city: &'static str,
+                #[tabled(group = "Address")]
+                street: &'static str,
+                age: u8,
+            }
+
+            vec![
+                User { name: "Maxim", city: "Minsk", street: "Nezavisimosti", age: 30 },
+                User { name: "Anna", city: "Oslo", street: "Karl Johans gate", age: 25 },
+            ]
+        }),
+        "+-------+-------+------------------+-----+"
+        "| name  | Address                  | age |"
+        "+       +-------+------------------+     +"
+        "|       | city  | street           |     |"
+        "+-------+-------+------------------+-----+"
+        "| Maxim | Minsk | Nezavisimosti    | 30  |"
+        "+-------+-------+------------------+-----+"
+        "| Anna  | Oslo  | Karl Johans gate | 25  |"
+        "+-------+-------+------------------+-----+"
+    );
+
+    test_table!(
+        table_groups_nested,
+        Table::new({
+            #[derive(Tabled)]
+            struct Address {
+                city: &'static str,
+                #[tabled(group = "Location")]
+                lat: f32,
+                #[tabled(group = "Location")]
+                lon: f32,
+            }
+
+            #[derive(Tabled)]
+            struct User {
+                #[tabled(order = 1)]
+                name: &'static str,
+                #[tabled(inline, group)]
+                address: Address,
+                #[tabled(group = "Contact")]
+                email: &'static str,
+            }
+
+            vec![User {
+                name: "Maxim",
+                address: Address { city: "Minsk", lat: 53.9, lon: 27.56 },
+                email: "maxim@example.com",
+            }]
+        }),
+        "+-------+------+-------+-------+-------------------+"
+        "| Address              | name  | Contact           |"
+        "+-------+------+-------+       +-------------------+"
+        "| city  | Location     |       | email             |"
+        "+       +------+-------+       +                   +"
+        "|       | lat  | lon   |       |                   |"
+        "+-------+------+-------+-------+-------------------+"
+        "| Minsk | 53.9 | 27.56 | Maxim | maxim@example.com |"
+        "+-------+------+-------+-------+-------------------+"
+    );
+
+    test_table!(
+        table_groups_tuple,
+        Table::new({
+            #[derive(Tabled)]
+            struct Score {
+                #[tabled(group = "Score")]
+                home: u8,
+                #[tabled(group = "Score")]
+                away: u8,
+            }
+
+            vec![("final", Score { home: 2, away: 1 })]
+        }),
+        "+-------+------+------+"
+        "| &str  | Score       |"
+        "+       +------+------+"
+        "|       | home | away |"
+        "+-------+------+------+"
+        "| final | 2    | 1    |"
+        "+-------+------+------+"
+    );
+
+    test_table!(
+        table_column_settings_groups,
+        Table::new({
+            #[derive(Tabled)]
+            struct Package {
+                name: &'static str,
+                #[tabled(group = "Stats", align = "right")]
+                downloads: usize,
+                #[tabled(group = "Stats", max_width = 4)]
+                stars: &'static str,
+            }
+
+            vec![Package { name: "tabled", downloads: 9001, stars: "2000+" }]
+        }),
+        "+--------+-----------+-------+"
+        "| name   | Stats             |"
+        "+        +-----------+-------+"
+        "|        | downloads | stars |"
+        "+--------+-----------+-------+"
+        "| tabled |      9001 | 2000  |"
+        "+--------+-----------+-------+"
+    );
+
+    test_table!(
+        table_builder_drops_attributes,
+        Table::builder({
+            #[derive(Tabled)]
+            struct Order {
+                #[tabled(align = "right")]
+                id: u8,
+                #[tabled(explode, group = "Items")]
+                items: Vec<&'static str>,
+            }
+
+            vec![Order { id: 1, items: vec!["apple", "pear"] }]
+        })
+        .build(),
+        "+----+-------+"
+        "| id | items |"
+        "+----+-------+"
+        "| 1  | apple |"
+        "+----+-------+"
+        "| 1  | pear  |"
+        "+----+-------+"
+    );
+
+    test_table!(
+        table_builder_with_columns,
+        {
+            #[derive(Tabled)]
+            struct Package {
+                name: &'static str,
+                #[tabled(align = "right")]
+                downloads: usize,
+            }
+
+            let data = vec![Package { name: "tabled", downloads: 9001 }];
+            let mut table = Table::builder(data).build();
+            for column in Package::columns() {
+                table.with(column);
+            }
+
+            table
+        },
+        "+--------+-----------+"
+        "| name   | downloads |"
+        "+--------+-----------+"
+        "| tabled |      9001 |"
+        "+--------+-----------+"
+    );
+
+    test_table!(
+        table_explode,
+        Table::new({
+            #[derive(Tabled)]
+            struct Item {
+                product: &'static str,
+                quantity: u8,
+            }
+
+            #[derive(Tabled)]
+            struct Order {
+                id: u8,
+                #[tabled(explode, inline)]
+                items: Vec<Item>,
+                total: f32,
+            }
+
+            vec![
+                Order {
+                    id: 1,
+                    items: vec![
+                        Item { product: "apple", quantity: 3 },
+                        Item { product: "pear", quantity: 1 },
+                    ],
+                    total: 4.5,
+                },
+                Order {
+                    id: 2,
+                    items: vec![Item { product: "plum", quantity: 12 }],
+                    total: 6.0,
+                },
+                Order { id: 3, items: vec![], total: 0.0 },
+            ]
+        }),
+        "+----+---------+----------+-------+"
+        "| id | product | quantity | total |"
+        "+----+---------+----------+-------+"
+        "| 1  | apple   | 3        | 4.5   |"
+        "+    +---------+----------+       +"
+        "|    | pear    | 1        |       |"
+        "+----+---------+----------+-------+"
+        "| 2  | plum    | 12       | 6     |"
+        "+----+---------+----------+-------+"
+        "| 3  |         |          | 0     |"
+        "+----+---------+----------+-------+"
+    );
+
+    test_table!(
+        table_explode_display,
+        Table::new({
+            #[derive(Tabled)]
+            struct Post {
+                title: &'static str,
+                #[tabled(explode, format = "#{}")]
+                tags: &'static [&'static str],
+                #[tabled(explode)]
+                authors: [&'static str; 2],
+            }
+
+            vec![Post {
+                title: "Release",
+                tags: &["rust", "cli", "tables"],
+                authors: ["Maxim", "Anna"],
+            }]
+        }),
+        "+---------+---------+---------+"
+        "| title   | tags    | authors |"
+        "+---------+---------+---------+"
+        "| Release | #rust   | Maxim   |"
+        "+         +---------+---------+"
+        "|         | #cli    | Anna    |"
+        "+         +---------+---------+"
+        "|         | #tables |         |"
+        "+---------+---------+---------+"
+    );
+
+    test_table!(
+        table_explode_nested,
+        Table::new({
+            #[derive(Tabled)]
+            struct Team {
+                name: &'static str,
+                #[tabled(explode)]
+                members: Vec<&'static str>,
+            }
+
+            vec![(
+                1,
+                Team {
+                    name: "core",
+                    members: vec!["Maxim", "Anna"],
+                },
+            )]
+        }),
+        "+-----+------+---------+"
+        "| i32 | name | members |"
+        "+-----+------+---------+"
+        "| 1   | core | Maxim   |"
+        "+     +      +---------+"
+        "|     |      | Anna    |"
+        "+-----+------+---------+"
+    );
+
+    test_table!(
+        table_nested,
+        Table::new({
+            #[derive(Tabled)]
+            struct Author {
+                name: &'static str,
+                country: &'static str,
+            }
+
+            #[derive(Tabled)]
+            struct Book {
+                title: &'static str,
+                #[tabled(table)]
+                author: Author,
+            }
+
+            vec![Book {
+                title: "Dune",
+                author: Author { name: "Frank Herbert", country: "US" },
+            }]
+        }),
+        "+-------+-----------------------------+"
+        "| title | author                      |"
+        "+-------+-----------------------------+"
+        "| Dune  | +---------------+---------+ |"
+        "|       | | name          | country | |"
+        "|       | +---------------+---------+ |"
+        "|       | | Frank Herbert | US      | |"
+        "|       | +---------------+---------+ |"
+        "+-------+-----------------------------+"
+    );
+
+    test_table!(
+        table_nested_collection,
+        Table::new({
+            #[derive(Tabled)]
+            struct Release {
+                version: &'static str,
+                downloads: usize,
+            }
+
+            #[derive(Tabled)]
+            struct Crate {
+                name: &'static str,
+                #[tabled(table(style = "modern"))]
+                releases: Vec<Release>,
+            }
+
+            vec![Crate {
+                name: "tabled",
+                releases: vec![
+                    Release { version: "0.15.0", downloads: 120 },
+                    Release { version: "0.14.0", downloads: 3400 },
+                ],
+            }]
+        }),
+        "+--------+-------------------------+"
+        "| name   | releases                |"
+        "+--------+-------------------------+"
+        "| tabled | ┌─────────┬───────────┐ |"
+        "|        | │ version │ downloads │ |"
+        "|        | ├─────────┼───────────┤ |"
+        "|        | │ 0.15.0  │ 120       │ |"
+        "|        | ├─────────┼───────────┤ |"
+        "|        | │ 0.14.0  │ 3400      │ |"
+        "|        | └─────────┴───────────┘ |"
+        "+--------+-------------------------+"
+    );
+
+    test_table!(
+        table_nested_explode,
+        Table::new({
+            #[derive(Tabled)]
+            struct Point {
+                x: i32,
+                y: i32,
+            }
+
+            #[derive(Tabled)]
+            struct Path {
+                name: &'static str,
+                #[tabled(explode, table(style = "psql"))]
+                points: [Point; 2],
+            }
+
+            vec![Path {
+                name: "line",
+                points: [Point { x: 0, y: 0 }, Point { x: 3, y: -4 }],
+            }]
+        }),
+        "+------+----------+"
+        "| name | points   |"
+        "+------+----------+"
+        "| line |  x | y   |"
+        "|      | ---+---  |"
+        "|      |  0 | 0   |"
+        "+      +----------+"
+        "|      |  x | y   |"
+        "|      | ---+---- |"
+        "|      |  3 | -4  |"
+        "+------+----------+"
+    );
+
+    test_table!(
+        table_enum_tag,
+        Table::new({
+            #[derive(Tabled)]
+            #[tabled(enum = "tag", rename_all = "snake_case")]
+            enum Status {
+                InProgress,
+                #[tabled(rename = "done!")]
+                Done,
+                #[allow(dead_code)]
+                Failed(&'static str),
+            }
+
+            vec![(1, Status::InProgress), (2, Status::Done), (3, Status::Failed("timeout"))]
+        }),
+        "+-----+-------------+"
+        "| i32 | Status      |"
+        "+-----+-------------+"
+        "| 1   | in_progress |"
+        "+-----+-------------+"
+        "| 2   | done!       |"
+        "+-----+-------------+"
+        "| 3   | failed      |"
+        "+-----+-------------+"
+    );
+
+    test_table!(
+        table_enum_union,
+        Table::new({
+            #[derive(Tabled)]
+            #[tabled(enum = "union")]
+            enum Event {
+                Login {
+                    user: &'static str,
+                },
+                Upload {
+                    user: &'static str,
+                    #[tabled(rename = "size (KB)", align = "right")]
+                    size: usize,
+                },
+                #[tabled(rename = "Shutdown!")]
+                Shutdown,
+                #[tabled(skip)]
+                #[allow(dead_code)]
+                Tick(u64),
+            }
+
+            vec![
+                Event::Login { user: "maxim" },
+                Event::Upload { user: "anna", size: 1024 },
+                Event::Shutdown,
+                Event::Tick(1),
+            ]
+        }),
+        "+-----------+-------+-----------+"
+        "| Event     | user  | size (KB) |"
+        "+-----------+-------+-----------+"
+        "| Login     | maxim |           |"
+        "+-----------+-------+-----------+"
+        "| Upload    | anna  |      1024 |"
+        "+-----------+-------+-----------+"
+        "| Shutdown! |       |           |"
+        "+-----------+-------+-----------+"
+        "|           |       |           |"
+        "+-----------+-------+-----------+"
+    );
+
+    test_table!(
+        table_option_fields,
+        Table::new({
+            #[derive(Tabled)]
+            struct User {
+                name: &'static str,
+                email: Option<&'static str>,
+                #[tabled(display_option = "unknown")]
+                age: Option<u8>,
+            }
+
+            vec![
+                User { name: "Maxim", email: Some("maxim@example.com"), age: None },
+                User { name: "Anna", email: None, age: Some(25) },
+            ]
+        }),
+        "+-------+-------------------+---------+"
+        "| name  | email             | age     |"
+        "+-------+-------------------+---------+"
+        "| Maxim | maxim@example.com | unknown |"
+        "+-------+-------------------+---------+"
+        "| Anna  |                   | 25      |"
+        "+-------+-------------------+---------+"
+    );
+
+    test_table!(
+        table_option_fields_placeholder,
+        Table::new({
+            #[derive(Tabled)]
+            struct Address {
+                city: &'static str,
+                zip: Option<u32>,
+            }
+
+            #[derive(Tabled)]
+            #[tabled(display_option = "—")]
+            struct User {
+                name: &'static str,
+                #[tabled(inline)]
+                address: Option<Address>,
+                #[tabled(display_option = "n/a")]
+                phone: Option<&'static str>,
+            }
+
+            vec![
+                User {
+                    name: "Maxim",
+                    address: Some(Address { city: "Minsk", zip: None }),
+                    phone: None,
+                },
+                User { name: "Anna", address: None, phone: Some("+47 555") },
+            ]
+        }),
+        "+-------+-------+-----+---------+"
+        "| name  | city  | zip | phone   |"
+        "+-------+-------+-----+---------+"
+        "| Maxim | Minsk |     | n/a     |"
+        "+-------+-------+-----+---------+"
+        "| Anna  | —     | —   | +47 555 |"
+        "+-------+-------+-----+---------+"
+    );
 }
 
 #[cfg(feature = "ansi")]
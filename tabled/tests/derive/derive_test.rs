@@ -711,6 +711,47 @@ mod enum_ {
             C(String::new()) => ["", "",  "", "4 ss", ""],
             K => ["", "", "",  "", "k."],
     );
+
+    test_enum!(
+        enum_tag,
+        t: #[tabled(enum = "tag", rename_all = "UPPERCASE")] { A { a: u8 } #[tabled(rename = "b")] B(sstr) #[tabled(order = 0)] C },
+        headers: ["TestType"],
+        tests:
+            A { a: 1 } => ["A"],
+            B("") => ["b"],
+            C => ["C"],
+    );
+
+    test_enum!(
+        enum_union,
+        t: #[tabled(enum = "union")] {
+            A { a: u8, #[tabled(rename = "c")] b: i32 }
+            B { c: sstr, #[tabled(skip)] d: u8 }
+            C(u8, #[tabled(format = "{}!")] sstr)
+            #[tabled(skip)]
+            D
+        },
+        headers: ["TestType", "a", "c", "C.0", "C.1"],
+        tests:
+            A { a: 1, b: 2 } => ["A", "1", "2", "", ""],
+            B { c: "b", d: 3 } => ["B", "", "b", "", ""],
+            C(4, "c") => ["C", "", "", "4", "c!"],
+            D => ["", "", "", "", ""],
+    );
+
+    test_enum!(
+        enum_union_unnamed,
+        t: #[tabled(enum = "union")] {
+            A(u8)
+            B(sstr, #[tabled(rename = "size")] u8)
+            C { size: u8 }
+        },
+        headers: ["TestType", "A.0", "B.0", "size"],
+        tests:
+            A(1) => ["A", "1", "", ""],
+            B("b", 2) => ["B", "", "b", "2"],
+            C { size: 3 } => ["C", "", "", "3"],
+    );
 }
 
 mod unit {
@@ -1168,6 +1209,204 @@ fn test_skip_enum_0() {
     assert_eq!(Letters::Digit.fields(), vec!["", ""]);
 }
 
+#[test]
+fn test_column_settings() {
+    use tabled::{grid::config::AlignmentHorizontal, settings::ColumnSettings};
+
+    #[derive(Tabled)]
+    struct Plain {
+        id: u8,
+        name: &'static str,
+    }
+
+    #[derive(Tabled)]
+    struct Person {
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        id: u8,
+        #[tabled(max_width = 5, wrap)]
+        name: &'static str,
+        #[tabled(order = 0, align = "left")]
+        age: u8,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Tabled)]
+    enum Shape {
+        #[tabled(align = "center")]
+        Empty,
+        #[tabled(inline)]
+        Circle(#[tabled(color = "bright_blue")] f32),
+    }
+
+    assert!(Plain::columns().is_empty());
+    assert_eq!(
+        Person::columns(),
+        vec![
+            ColumnSettings::new().alignment(AlignmentHorizontal::Left),
+            ColumnSettings::new().max_width(5).wrap(true).shift(1),
+        ]
+    );
+    assert_eq!(
+        Shape::columns(),
+        vec![
+            ColumnSettings::new().alignment(AlignmentHorizontal::Center),
+            ColumnSettings::new()
+                .color(tabled::settings::Color::FG_BRIGHT_BLUE)
+                .shift(1),
+        ]
+    );
+}
+
+#[test]
+fn test_groups() {
+    #[derive(Tabled)]
+    struct Plain {
+        id: u8,
+        name: &'static str,
+    }
+
+    #[derive(Tabled)]
+    struct Point {
+        #[tabled(group = "Coordinates")]
+        x: u8,
+        #[tabled(group = "Coordinates")]
+        y: u8,
+    }
+
+    #[derive(Tabled)]
+    struct Marker {
+        name: &'static str,
+        #[tabled(inline, group)]
+        point: Point,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Tabled)]
+    enum Shape {
+        #[tabled(group = "Empty")]
+        Empty,
+        #[tabled(inline, group)]
+        Dot(#[tabled(inline)] Point),
+    }
+
+    assert!(Plain::groups().is_empty());
+    assert_eq!(
+        Point::groups(),
+        vec![vec!["Coordinates"], vec!["Coordinates"]]
+    );
+    assert_eq!(
+        Marker::groups(),
+        vec![
+            vec![],
+            vec!["Point", "Coordinates"],
+            vec!["Point", "Coordinates"],
+        ]
+    );
+    assert_eq!(
+        Shape::groups(),
+        vec![
+            vec!["Empty"],
+            vec!["Dot", "Coordinates"],
+            vec!["Dot", "Coordinates"],
+        ]
+    );
+}
+
+#[test]
+fn test_explode() {
+    #[derive(Tabled)]
+    struct Item {
+        name: &'static str,
+        #[tabled(explode)]
+        sizes: Vec<u8>,
+    }
+
+    #[derive(Tabled)]
+    struct Order {
+        #[tabled(explode, inline)]
+        items: Vec<Item>,
+        #[tabled(order = 0)]
+        id: u8,
+    }
+
+    let item = Item {
+        name: "shirt",
+        sizes: vec![40, 42],
+    };
+
+    assert_eq!(Item::LENGTH, 2);
+    assert_eq!(Item::exploded_columns(), vec![1]);
+    assert_eq!(item.fields(), vec!["shirt", "40"]);
+    assert_eq!(item.rows(), vec![vec!["shirt", "40"], vec!["shirt", "42"]]);
+
+    let order = Order {
+        id: 7,
+        items: vec![
+            item,
+            Item {
+                name: "hat",
+                sizes: vec![],
+            },
+        ],
+    };
+
+    assert_eq!(Order::headers(), vec!["id", "name", "sizes"]);
+    assert_eq!(Order::exploded_columns(), vec![1, 2]);
+    assert_eq!(
+        order.rows(),
+        vec![
+            vec!["7", "shirt", "40"],
+            vec!["7", "shirt", "42"],
+            vec!["7", "hat", ""],
+        ]
+    );
+}
+
+#[test]
+fn test_display_option() {
+    #[derive(Tabled)]
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    #[derive(Tabled)]
+    #[tabled(display_option = "-")]
+    struct Marker {
+        label: Option<&'static str>,
+        #[tabled(display_option = "?")]
+        size: Option<Option<u8>>,
+        #[tabled(format = "{:?}")]
+        color: Option<&'static str>,
+        #[tabled(inline("point."))]
+        point: Option<Point>,
+    }
+
+    let marker = Marker {
+        label: None,
+        size: Some(None),
+        color: None,
+        point: None,
+    };
+
+    assert_eq!(Marker::LENGTH, 5);
+    assert_eq!(
+        Marker::headers(),
+        vec!["label", "size", "color", "point.x", "point.y"]
+    );
+    assert_eq!(marker.fields(), vec!["-", "?", "None", "-", "-"]);
+
+    let marker = Marker {
+        label: Some("A"),
+        size: Some(Some(3)),
+        color: Some("red"),
+        point: Some(Point { x: 1, y: 2 }),
+    };
+
+    assert_eq!(marker.fields(), vec!["A", "3", "Some(\"red\")", "1", "2"]);
+}
+
 #[test]
 fn test_reimport_trait_by_crate_attribute() {
     pub mod new_module {
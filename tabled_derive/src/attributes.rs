@@ -1,4 +1,4 @@
-use syn::{Attribute, Lit, LitInt};
+use syn::{Attribute, Lit, LitInt, LitStr};
 
 use crate::{
     casing_style::CasingStyle,
@@ -21,6 +21,18 @@ pub struct FieldAttributes {
     pub order: Option<usize>,
     pub format: Option<String>,
     pub format_with_args: Option<Vec<FuncArg>>,
+    pub alignment: Option<String>,
+    pub color: Option<String>,
+    pub max_width: Option<usize>,
+    pub wrap: bool,
+    pub truncate: bool,
+    pub truncate_suffix: Option<String>,
+    pub group: bool,
+    pub group_name: Option<String>,
+    pub explode: bool,
+    pub table: bool,
+    pub table_style: Option<String>,
+    pub display_option: Option<String>,
 }
 
 impl FieldAttributes {
@@ -39,9 +51,37 @@ impl FieldAttributes {
             }
         }
 
+        if self.wrap && self.truncate {
+            return Err(Error::message(
+                "`wrap` and `truncate` attributes can't be used together",
+            ));
+        }
+
+        if (self.wrap || self.truncate) && self.max_width.is_none() {
+            return Err(Error::message(
+                "`wrap` and `truncate` attributes require a `max_width` attribute",
+            ));
+        }
+
+        if self.table && self.inline {
+            return Err(Error::message(
+                "`table` and `inline` attributes can't be used together",
+            ));
+        }
+
+        if self.table && (self.display_with.is_some() || self.format.is_some()) {
+            return Err(Error::message(
+                "a `table` attribute can't be used with `display_with` or `format` attributes",
+            ));
+        }
+
         Ok(())
     }
 
+    pub fn has_column_settings(&self) -> bool {
+        self.alignment.is_some() || self.color.is_some() || self.max_width.is_some()
+    }
+
     fn insert_attribute(&mut self, attr: FieldAttr) -> Result<(), Error> {
         match attr.kind {
             FieldAttrKind::Skip(b) => {
@@ -83,6 +123,24 @@ impl FieldAttributes {
                 }
             }
             FieldAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            FieldAttrKind::Align(value) => self.alignment = Some(parse_alignment(&value)?),
+            FieldAttrKind::Color(value) => self.color = Some(parse_color(&value)?),
+            FieldAttrKind::MaxWidth(value) => self.max_width = Some(lit_int_to_usize(&value)?),
+            FieldAttrKind::Wrap(b) => self.wrap = b.value,
+            FieldAttrKind::Truncate(suffix) => {
+                self.truncate = true;
+                self.truncate_suffix = suffix.map(|suffix| suffix.value());
+            }
+            FieldAttrKind::Group(name) => {
+                self.group = true;
+                self.group_name = name.map(|name| name.value());
+            }
+            FieldAttrKind::Explode(b) => self.explode = b.value,
+            FieldAttrKind::DisplayOption(value) => self.display_option = Some(value.value()),
+            FieldAttrKind::Table(style) => {
+                self.table = true;
+                self.table_style = style.map(|style| parse_style(&style)).transpose()?;
+            }
         }
 
         Ok(())
@@ -95,6 +153,14 @@ pub struct TypeAttributes {
     pub inline: bool,
     pub inline_value: Option<String>,
     pub crate_name: Option<String>,
+    pub enum_mode: Option<EnumMode>,
+    pub display_option: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumMode {
+    Tag,
+    Union,
 }
 
 impl TypeAttributes {
@@ -113,6 +179,12 @@ impl TypeAttributes {
             }
         }
 
+        if self.inline && self.enum_mode.is_some() {
+            return Err(Error::message(
+                "`inline` and `enum` attributes can't be used together",
+            ));
+        }
+
         Ok(())
     }
 
@@ -136,6 +208,8 @@ impl TypeAttributes {
             TypeAttrKind::RenameAll(lit) => {
                 self.rename_all = Some(CasingStyle::from_lit(&lit)?);
             }
+            TypeAttrKind::Enum(lit) => self.enum_mode = Some(parse_enum_mode(&lit)?),
+            TypeAttrKind::DisplayOption(lit) => self.display_option = Some(lit.value()),
         }
 
         Ok(())
@@ -152,6 +226,85 @@ fn lit_int_to_usize(value: &LitInt) -> Result<usize, Error> {
     })
 }
 
+fn parse_alignment(value: &LitStr) -> Result<String, Error> {
+    let alignment = match value.value().as_str() {
+        "left" => "Left",
+        "center" => "Center",
+        "right" => "Right",
+        _ => {
+            return Err(Error::new(
+                format!("unexpected alignment {:?}", value.value()),
+                value.span(),
+                Some(String::from("expected one of `left`, `center`, `right`")),
+            ))
+        }
+    };
+
+    Ok(alignment.to_owned())
+}
+
+fn parse_color(value: &LitStr) -> Result<String, Error> {
+    const COLORS: [&str; 8] = [
+        "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    ];
+
+    let text = value.value();
+    let name = text.strip_prefix("bright_").unwrap_or(&text);
+    if !COLORS.contains(&name) {
+        return Err(Error::new(
+            format!("unexpected color {:?}", text),
+            value.span(),
+            Some(format!(
+                "expected one of {}, optionally prefixed by `bright_`",
+                COLORS.join(", ")
+            )),
+        ));
+    }
+
+    Ok(format!("FG_{}", text.to_uppercase()))
+}
+
+fn parse_enum_mode(value: &LitStr) -> Result<EnumMode, Error> {
+    match value.value().as_str() {
+        "tag" => Ok(EnumMode::Tag),
+        "union" => Ok(EnumMode::Union),
+        mode => Err(Error::new(
+            format!("unexpected enum mode {:?}", mode),
+            value.span(),
+            Some(String::from("expected one of `tag`, `union`")),
+        )),
+    }
+}
+
+fn parse_style(value: &LitStr) -> Result<String, Error> {
+    const STYLES: [&str; 13] = [
+        "ascii",
+        "ascii_rounded",
+        "blank",
+        "dots",
+        "empty",
+        "extended",
+        "markdown",
+        "modern",
+        "modern_rounded",
+        "psql",
+        "re_structured_text",
+        "rounded",
+        "sharp",
+    ];
+
+    let style = value.value();
+    if !STYLES.contains(&style.as_str()) {
+        return Err(Error::new(
+            format!("unexpected style {:?}", style),
+            value.span(),
+            Some(format!("expected one of {}", STYLES.join(", "))),
+        ));
+    }
+
+    Ok(style)
+}
+
 #[derive(Debug)]
 pub enum FuncArg {
     SelfRef,
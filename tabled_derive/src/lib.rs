@@ -19,7 +19,7 @@ use syn::{
     Ident, Index, PathSegment, Type, Variant,
 };
 
-use attributes::{FieldAttributes, FuncArg, TypeAttributes};
+use attributes::{EnumMode, FieldAttributes, FuncArg, TypeAttributes};
 use error::Error;
 
 #[proc_macro_derive(Tabled, attributes(tabled))]
@@ -45,6 +45,35 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
         .unwrap();
     let fields = info.values;
     let headers = info.headers;
+    let columns = info.columns.map(|columns| {
+        let settings_path = crate_item_path(&tabled_trait_path, &["settings", "ColumnSettings"]);
+        quote! {
+            fn columns() -> Vec<#settings_path> {
+                #columns
+            }
+        }
+    });
+    let groups = info.groups.map(|groups| {
+        quote! {
+            fn groups() -> Vec<Vec<::std::borrow::Cow<'static, str>>> {
+                #groups
+            }
+        }
+    });
+    let rows = info.rows.map(|rows| {
+        quote! {
+            fn rows(&self) -> Vec<Vec<::std::borrow::Cow<'_, str>>> {
+                #rows
+            }
+        }
+    });
+    let exploded_columns = info.exploded_columns.map(|columns| {
+        quote! {
+            fn exploded_columns() -> Vec<usize> {
+                #columns
+            }
+        }
+    });
 
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -60,6 +89,14 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
             fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
                 #headers
             }
+
+            #columns
+
+            #groups
+
+            #rows
+
+            #exploded_columns
         }
     };
 
@@ -72,14 +109,19 @@ fn get_tabled_length(
     trait_path: &ExprPath,
 ) -> Result<TokenStream, Error> {
     match &ast.data {
+        Data::Struct(_) if attrs.enum_mode.is_some() => Err(Error::message(
+            "an `enum` attribute can be used only on enums",
+        )),
         Data::Struct(data) => get_fields_length(&data.fields, trait_path),
-        Data::Enum(data) => {
-            if attrs.inline {
-                Ok(quote! { 1 })
-            } else {
-                get_enum_length(data, trait_path)
+        Data::Enum(data) => match attrs.enum_mode {
+            Some(EnumMode::Tag) => Ok(quote! { 1 }),
+            Some(EnumMode::Union) => {
+                let length = union_headers(data, attrs)?.len() + 1;
+                Ok(quote! { #length })
             }
-        }
+            None if attrs.inline => Ok(quote! { 1 }),
+            None => get_enum_length(data, trait_path),
+        },
         Data::Union(_) => Err(Error::message("Union type isn't supported")),
     }
 }
@@ -96,12 +138,13 @@ fn get_fields_length(fields: &Fields, tabled_trait: &ExprPath) -> Result<TokenSt
         .filter(|(_, attr)| !attr.is_ignored)
         .map(|(field, attr)| {
             if attr.inline {
-                let field_type = &field.ty;
-                quote!({<#field_type as #tabled_trait>::LENGTH})
+                let field_type = tabled_type(field, &attr)?;
+                Ok(quote!({<#field_type as #tabled_trait>::LENGTH}))
             } else {
-                quote!({ 1 })
+                Ok(quote!({ 1 }))
             }
-        });
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
 
     let size_components = std::iter::once(quote!(0)).chain(size_components);
 
@@ -195,6 +238,14 @@ fn info_from_fields(
 
     let mut headers = Vec::new();
     let mut values = Vec::new();
+    let mut columns = Vec::new();
+    let mut has_columns = false;
+    let mut groups = Vec::new();
+    let mut has_groups = false;
+    let mut rows = Vec::new();
+    let mut exploded_columns = Vec::new();
+    let mut has_explode = false;
+    let mut has_inline = false;
     let mut reorder = HashMap::new();
 
     let mut skipped = 0;
@@ -215,17 +266,56 @@ fn info_from_fields(
             reorder.insert(order, i - skipped);
         }
 
-        let header = field_headers(field, i, &attributes, header_prefix, trait_path);
+        let field_type = tabled_type(field, &attributes)?;
+
+        let header = field_headers(field, field_type, i, &attributes, header_prefix, trait_path);
         headers.push(header);
 
         let field_name_result = field_name(i, field);
-        let value = get_field_fields(&field_name_result, &attributes, fields, &field_name);
-        values.push(value);
+        let value = match attributes.explode {
+            // an exploded field is a part of rows, and the first one is used as fields
+            true => TokenStream::new(),
+            false => get_field_fields(
+                &field_name_result,
+                &field.ty,
+                &attributes,
+                fields,
+                &field_name,
+                trait_path,
+            ),
+        };
+        values.push(value.clone());
+
+        has_columns |= attributes.inline || attributes.has_column_settings();
+        let column = field_columns(field_type, &attributes, trait_path);
+        columns.push(column);
+
+        has_groups |= attributes.inline || attributes.group;
+        let group = field_groups(field_type, &attributes, trait_path)?;
+        groups.push(group);
+
+        has_explode |= attributes.explode;
+        has_inline |= attributes.inline;
+        let (row, exploded) = field_rows(
+            &field_name_result,
+            field,
+            value,
+            &attributes,
+            fields,
+            &field_name,
+            trait_path,
+        )?;
+        rows.push(row);
+        exploded_columns.push(exploded);
     }
 
     if !reorder.is_empty() {
         values = reorder_fields(&reorder, &values);
         headers = reorder_fields(&reorder, &headers);
+        columns = reorder_fields(&reorder, &columns);
+        groups = reorder_fields(&reorder, &groups);
+        rows = reorder_fields(&reorder, &rows);
+        exploded_columns = reorder_fields(&reorder, &exploded_columns);
     }
 
     let headers = quote!({
@@ -234,13 +324,86 @@ fn info_from_fields(
         out
     });
 
-    let values = quote!({
-        let mut out = Vec::new();
-        #(out.extend(#values);)*
-        out
+    let values = match has_explode {
+        true => quote!({
+            <Self as #trait_path>::rows(self)
+                .into_iter()
+                .next()
+                .unwrap_or_default()
+        }),
+        false => quote!({
+            let mut out = Vec::new();
+            #(out.extend(#values);)*
+            out
+        }),
+    };
+
+    let columns = has_columns.then(|| {
+        quote!({
+            let mut out = Vec::new();
+            let mut offset = 0;
+            #(#columns)*
+            let _ = offset;
+            out
+        })
     });
 
-    Ok(Impl { headers, values })
+    let groups = has_groups.then(|| {
+        quote!({
+            let mut out: Vec<Vec<::std::borrow::Cow<'static, str>>> = Vec::new();
+            #(#groups)*
+            out
+        })
+    });
+
+    let has_rows = has_explode || has_inline;
+    let rows = has_rows.then(|| {
+        quote!({
+            let parts: Vec<(Vec<Vec<::std::borrow::Cow<'_, str>>>, usize, bool)> = vec![#(#rows,)*];
+            let count_rows = parts
+                .iter()
+                .map(|(rows, _, _)| rows.len())
+                .max()
+                .unwrap_or(0)
+                .max(1);
+
+            // a single row of a field which is not exploded is repeated
+            let mut out = vec![Vec::new(); count_rows];
+            for (rows, length, exploded) in parts {
+                let is_shared = !exploded && rows.len() == 1;
+                for (i, row) in out.iter_mut().enumerate() {
+                    match rows.get(i) {
+                        Some(cells) => row.extend(cells.iter().cloned()),
+                        None if is_shared => row.extend(rows[0].iter().cloned()),
+                        None => row.extend(
+                            ::std::iter::repeat(::std::borrow::Cow::Borrowed("")).take(length),
+                        ),
+                    }
+                }
+            }
+
+            out
+        })
+    });
+
+    let exploded_columns = has_rows.then(|| {
+        quote!({
+            let mut out = Vec::new();
+            let mut offset = 0;
+            #(#exploded_columns)*
+            let _ = offset;
+            out
+        })
+    });
+
+    Ok(Impl {
+        headers,
+        values,
+        columns,
+        groups,
+        rows,
+        exploded_columns,
+    })
 }
 
 fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Vec<T> {
@@ -273,6 +436,7 @@ fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Ve
 
 fn field_headers(
     field: &Field,
+    field_type: &Type,
     index: usize,
     attributes: &FieldAttributes,
     prefix: &str,
@@ -283,7 +447,7 @@ fn field_headers(
             .inline_prefix
             .as_ref()
             .map_or_else(|| "", |s| s.as_str());
-        return get_type_headers(&field.ty, prefix, "", trait_path);
+        return get_type_headers(field_type, prefix, "", trait_path);
     }
 
     let header_name = field_header_name(field, attributes, index);
@@ -295,14 +459,295 @@ fn field_headers(
     }
 }
 
+fn field_columns(
+    field_type: &Type,
+    attributes: &FieldAttributes,
+    trait_path: &ExprPath,
+) -> TokenStream {
+    let settings = column_settings(attributes, trait_path);
+
+    if attributes.inline {
+        let settings = settings.map(|settings| {
+            quote! {
+                for i in 0..length {
+                    out.push(#settings.shift(offset + i));
+                }
+            }
+        });
+
+        return quote! {
+            let length = <#field_type as #trait_path>::LENGTH;
+            out.extend(<#field_type as #trait_path>::columns().into_iter().map(|c| c.shift(offset)));
+            #settings
+            offset += length;
+        };
+    }
+
+    let settings = settings.map(|settings| quote!(out.push(#settings.shift(offset));));
+
+    quote! {
+        #settings
+        offset += 1;
+    }
+}
+
+fn column_settings(attributes: &FieldAttributes, trait_path: &ExprPath) -> Option<TokenStream> {
+    if !attributes.has_column_settings() {
+        return None;
+    }
+
+    let settings_path = crate_item_path(trait_path, &["settings", "ColumnSettings"]);
+    let mut settings = quote!(#settings_path::new());
+
+    if let Some(alignment) = &attributes.alignment {
+        let path = crate_item_path(
+            trait_path,
+            &["grid", "config", "AlignmentHorizontal", alignment],
+        );
+        settings = quote!(#settings.alignment(#path));
+    }
+
+    if let Some(color) = &attributes.color {
+        let path = crate_item_path(trait_path, &["settings", "Color", color]);
+        settings = quote!(#settings.color(#path));
+    }
+
+    if let Some(width) = attributes.max_width {
+        settings = quote!(#settings.max_width(#width));
+    }
+
+    if attributes.wrap {
+        settings = quote!(#settings.wrap(true));
+    }
+
+    if let Some(suffix) = &attributes.truncate_suffix {
+        settings = quote!(#settings.suffix(#suffix));
+    }
+
+    Some(settings)
+}
+
+fn field_groups(
+    field_type: &Type,
+    attributes: &FieldAttributes,
+    trait_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    let group = match (&attributes.group_name, attributes.group) {
+        (Some(name), _) => Some(name.clone()),
+        (None, true) => Some(type_name(field_type, attributes)?),
+        (None, false) => None,
+    };
+
+    let group = match group {
+        Some(name) => quote!(vec![::std::borrow::Cow::Borrowed(#name)]),
+        None => quote!(Vec::new()),
+    };
+
+    if attributes.inline {
+        return Ok(quote! {
+            let length = <#field_type as #trait_path>::LENGTH;
+            let mut list = <#field_type as #trait_path>::groups();
+            list.resize(length, Vec::new());
+            for path in list {
+                let mut group: Vec<::std::borrow::Cow<'static, str>> = #group;
+                group.extend(path);
+                out.push(group);
+            }
+        });
+    }
+
+    Ok(quote!(out.push(#group);))
+}
+
+// A name of an inlined type is used as a default group name.
+fn type_name(field_type: &Type, attributes: &FieldAttributes) -> Result<String, Error> {
+    use syn::spanned::Spanned;
+
+    let path = match field_type {
+        Type::Path(path) if attributes.inline => path,
+        ty => {
+            return Err(Error::new(
+                "a `group` attribute requires a name unless a field is inlined",
+                ty.span(),
+                Some(String::from("use `#[tabled(group = \"name\")]`")),
+            ))
+        }
+    };
+
+    let name = path
+        .path
+        .segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+        .unwrap_or_default();
+
+    Ok(name)
+}
+
+fn field_rows(
+    field: &TokenStream,
+    declaration: &Field,
+    value: TokenStream,
+    attributes: &FieldAttributes,
+    fields: &Fields,
+    field_name: impl Fn(usize, &Field) -> TokenStream,
+    trait_path: &ExprPath,
+) -> Result<(TokenStream, TokenStream), Error> {
+    let field_type = tabled_type(declaration, attributes)?;
+
+    if attributes.explode {
+        let length = match attributes.inline {
+            true => quote!(<#field_type as #trait_path>::LENGTH),
+            false => quote!(1),
+        };
+
+        let rows = match attributes.inline {
+            true => quote! {
+                #field
+                    .iter()
+                    .flat_map(|item| <#field_type as #trait_path>::rows(item))
+                    .collect::<Vec<_>>()
+            },
+            false => {
+                let value = get_field_fields(
+                    &quote!((*item)),
+                    field_type,
+                    attributes,
+                    fields,
+                    field_name,
+                    trait_path,
+                );
+                quote!(#field.iter().map(|item| #value).collect::<Vec<_>>())
+            }
+        };
+
+        let row = quote!((#rows, #length, true));
+        let exploded = quote! {
+            out.extend(offset..offset + #length);
+            offset += #length;
+        };
+
+        return Ok((row, exploded));
+    }
+
+    if attributes.inline {
+        let rows = match option_type(&declaration.ty) {
+            Some(_) => {
+                let placeholder = option_placeholder(attributes);
+                quote! {
+                    match &#field {
+                        Some(value) => <#field_type as #trait_path>::rows(value),
+                        None => vec![vec![
+                            ::std::borrow::Cow::Borrowed(#placeholder);
+                            <#field_type as #trait_path>::LENGTH
+                        ]],
+                    }
+                }
+            }
+            None => quote!(<#field_type as #trait_path>::rows(&#field)),
+        };
+
+        let row = quote! {
+            (
+                #rows,
+                <#field_type as #trait_path>::LENGTH,
+                !<#field_type as #trait_path>::exploded_columns().is_empty(),
+            )
+        };
+        let exploded = quote! {
+            let length = <#field_type as #trait_path>::LENGTH;
+            out.extend(<#field_type as #trait_path>::exploded_columns().into_iter().map(|c| c + offset));
+            offset += length;
+        };
+
+        return Ok((row, exploded));
+    }
+
+    let row = quote!((vec![#value], 1, false));
+    let exploded = quote!(offset += 1;);
+
+    Ok((row, exploded))
+}
+
+// An exploded field is rendered by its elements,
+// so a type of the elements is used instead,
+// as well as an inlined optional field is rendered by its value.
+fn tabled_type<'a>(field: &'a Field, attributes: &FieldAttributes) -> Result<&'a Type, Error> {
+    if !attributes.explode {
+        let ty = match attributes.inline {
+            true => option_type(&field.ty).unwrap_or(&field.ty),
+            false => &field.ty,
+        };
+
+        return Ok(ty);
+    }
+
+    element_type(&field.ty).ok_or_else(|| {
+        use syn::spanned::Spanned;
+
+        Error::new(
+            "an `explode` attribute expects a list of values",
+            field.ty.span(),
+            Some(String::from("use a type like `Vec<T>`, `[T; N]` or `&[T]`")),
+        )
+    })
+}
+
+fn option_type(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(path) => path,
+        _ => return None,
+    };
+
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn option_placeholder(attributes: &FieldAttributes) -> &str {
+    attributes.display_option.as_deref().unwrap_or("")
+}
+
+fn element_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Reference(reference) => element_type(&reference.elem),
+        Type::Slice(slice) => Some(&slice.elem),
+        Type::Array(array) => Some(&array.elem),
+        Type::Path(path) => {
+            let segment = path.path.segments.last()?;
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => {
+                    args.args.iter().find_map(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 fn collect_info_enum(
     ast: &DataEnum,
     attrs: &TypeAttributes,
     name: &Ident,
     trait_path: &ExprPath,
 ) -> Result<Impl, Error> {
-    match &attrs.inline {
-        true => {
+    match attrs.enum_mode {
+        Some(EnumMode::Tag) => collect_info_enum_inlined(ast, attrs, name.to_string()),
+        Some(EnumMode::Union) => collect_info_enum_union(ast, attrs, name, trait_path),
+        None if attrs.inline => {
             let enum_name = attrs
                 .inline_value
                 .clone()
@@ -310,7 +755,7 @@ fn collect_info_enum(
 
             collect_info_enum_inlined(ast, attrs, enum_name)
         }
-        false => _collect_info_enum(ast, attrs, trait_path),
+        None => _collect_info_enum(ast, attrs, trait_path),
     }
 }
 
@@ -324,6 +769,10 @@ fn _collect_info_enum(
 
     let mut headers_list = Vec::new();
     let mut variants = Vec::new();
+    let mut columns_list = Vec::new();
+    let mut has_columns = false;
+    let mut groups_list = Vec::new();
+    let mut has_groups = false;
     for v in orderedvariants {
         let mut attributes = FieldAttributes::parse(&v.attrs)?;
         merge_attributes(&mut attributes, attrs);
@@ -331,9 +780,54 @@ fn _collect_info_enum(
             continue;
         }
 
+        for field in &v.fields {
+            if FieldAttributes::parse(&field.attrs)?.explode {
+                return Err(Error::message(
+                    "an `explode` attribute isn't supported in enum variants",
+                ));
+            }
+        }
+
         let info = info_from_variant(v, &attributes, attrs, trait_path)?;
         variants.push((v, info.values));
         headers_list.push(info.headers);
+
+        let length = match attributes.inline {
+            true => get_fields_length(&v.fields, trait_path)?,
+            false => quote!(1),
+        };
+
+        has_columns |= info.columns.is_some();
+        let columns = info
+            .columns
+            .map(|columns| quote!(out.extend(#columns.into_iter().map(|c| c.shift(offset)));));
+        columns_list.push(quote! {
+            #columns
+            offset += #length;
+        });
+
+        let mut variant_groups = info.groups;
+        if attributes.inline && attributes.group {
+            let name = attributes
+                .group_name
+                .clone()
+                .unwrap_or_else(|| variant_name(v, &attributes));
+            let groups = variant_groups.unwrap_or_else(|| quote!(vec![Vec::new(); #length]));
+
+            variant_groups = Some(quote! {
+                #groups.into_iter().map(|mut path: Vec<::std::borrow::Cow<'static, str>>| {
+                    path.insert(0, ::std::borrow::Cow::Borrowed(#name));
+                    path
+                })
+            });
+        }
+
+        has_groups |= variant_groups.is_some();
+        let groups = match variant_groups {
+            Some(groups) => quote!(out.extend(#groups);),
+            None => quote!(out.resize(out.len() + #length, Vec::new());),
+        };
+        groups_list.push(groups);
     }
 
     let variant_sizes = get_enum_variant_length(ast, trait_path)
@@ -348,7 +842,32 @@ fn _collect_info_enum(
         .concat()
     };
 
-    Ok(Impl { headers, values })
+    let columns = has_columns.then(|| {
+        quote!({
+            let mut out = Vec::new();
+            let mut offset = 0;
+            #(#columns_list)*
+            let _ = offset;
+            out
+        })
+    });
+
+    let groups = has_groups.then(|| {
+        quote!({
+            let mut out: Vec<Vec<::std::borrow::Cow<'static, str>>> = Vec::new();
+            #(#groups_list)*
+            out
+        })
+    });
+
+    Ok(Impl {
+        headers,
+        values,
+        columns,
+        groups,
+        rows: None,
+        exploded_columns: None,
+    })
 }
 
 fn collect_info_enum_inlined(
@@ -380,7 +899,154 @@ fn collect_info_enum_inlined(
         }
     };
 
-    Ok(Impl { headers, values })
+    Ok(Impl {
+        headers,
+        values,
+        columns: None,
+        groups: None,
+        rows: None,
+        exploded_columns: None,
+    })
+}
+
+fn collect_info_enum_union(
+    ast: &DataEnum,
+    attrs: &TypeAttributes,
+    name: &Ident,
+    trait_path: &ExprPath,
+) -> Result<Impl, Error> {
+    let union = union_headers(ast, attrs)?;
+    let orderedvariants = reodered_variants(ast)?;
+
+    let mut branches = Vec::new();
+    let mut columns = Vec::new();
+    for variant in orderedvariants {
+        let mut attributes = FieldAttributes::parse(&variant.attrs)?;
+        merge_attributes(&mut attributes, attrs);
+
+        let pattern = match_variant(variant);
+        if attributes.is_ignored {
+            branches.push(quote!(Self::#pattern => {}));
+            continue;
+        }
+
+        let tag = variant_name(variant, &attributes);
+
+        let mut cells = Vec::new();
+        for (i, field) in variant.fields.iter().enumerate() {
+            let mut attributes = FieldAttributes::parse(&field.attrs)?;
+            merge_attributes(&mut attributes, attrs);
+            if attributes.is_ignored {
+                continue;
+            }
+
+            // the first column is taken by a variant name
+            let header = union_header_name(&tag, field, &attributes, i);
+            let index = union.iter().position(|h| *h == header).unwrap_or_default() + 1;
+
+            let value = get_field_fields(
+                &variant_var_name(i, field),
+                &field.ty,
+                &attributes,
+                &variant.fields,
+                variant_var_name,
+                trait_path,
+            );
+            cells.push(quote!(out[#index] = #value.into_iter().next().unwrap_or_default();));
+
+            if let Some(settings) = column_settings(&attributes, trait_path) {
+                columns.push(quote!(out.push(#settings.shift(#index));));
+            }
+        }
+
+        branches.push(quote! {
+            Self::#pattern => {
+                out[0] = ::std::borrow::Cow::Borrowed(#tag);
+                #(#cells)*
+            }
+        });
+    }
+
+    let enum_name = name.to_string();
+    let headers = quote! {
+        vec![
+            ::std::borrow::Cow::Borrowed(#enum_name),
+            #(::std::borrow::Cow::Borrowed(#union),)*
+        ]
+    };
+
+    let values = quote! {
+        let size = <Self as #trait_path>::LENGTH;
+        let mut out = vec![::std::borrow::Cow::Borrowed(""); size];
+
+        #[allow(unused_variables)]
+        match &self {
+            #(#branches)*
+        }
+
+        out
+    };
+
+    let columns = (!columns.is_empty()).then(|| {
+        quote!({
+            let mut out = Vec::new();
+            #(#columns)*
+            out
+        })
+    });
+
+    Ok(Impl {
+        headers,
+        values,
+        columns,
+        groups: None,
+        rows: None,
+        exploded_columns: None,
+    })
+}
+
+// A union of fields of all variants,
+// where fields with the same name share a column.
+fn union_headers(ast: &DataEnum, attrs: &TypeAttributes) -> Result<Vec<String>, Error> {
+    let mut headers = Vec::new();
+    for variant in reodered_variants(ast)? {
+        let mut attributes = FieldAttributes::parse(&variant.attrs)?;
+        merge_attributes(&mut attributes, attrs);
+        if attributes.is_ignored {
+            continue;
+        }
+
+        let tag = variant_name(variant, &attributes);
+        for (i, field) in variant.fields.iter().enumerate() {
+            let mut attributes = FieldAttributes::parse(&field.attrs)?;
+            merge_attributes(&mut attributes, attrs);
+            if attributes.is_ignored {
+                continue;
+            }
+
+            if attributes.inline || attributes.explode {
+                return Err(Error::message(
+                    "`inline` and `explode` attributes aren't supported in a union enum",
+                ));
+            }
+
+            let header = union_header_name(&tag, field, &attributes, i);
+            if !headers.contains(&header) {
+                headers.push(header);
+            }
+        }
+    }
+
+    Ok(headers)
+}
+
+// Positional fields of different variants are unrelated,
+// so they are prefixed by a variant name like `A.0` not to share a column.
+fn union_header_name(tag: &str, f: &Field, attr: &FieldAttributes, index: usize) -> String {
+    match (&f.ident, &attr.rename) {
+        (None, None) => format!("{}.{}", tag, index),
+        _ => field_header_name(f, attr, index),
+    }
 }
 
 fn info_from_variant(
@@ -450,12 +1116,35 @@ fn info_from_variant(
     // we need exactly string because of it must be inlined as string
     let values = quote! { vec![#value] };
 
-    Ok(Impl { headers, values })
+    let columns = column_settings(attr, trait_path).map(|settings| quote!(vec![#settings]));
+
+    let groups = match (&attr.group_name, attr.group) {
+        (Some(name), _) => Some(quote!(vec![vec![::std::borrow::Cow::Borrowed(#name)]])),
+        (None, true) => {
+            return Err(Error::message(
+                "a `group` attribute requires a name unless a variant is inlined",
+            ))
+        }
+        (None, false) => None,
+    };
+
+    Ok(Impl {
+        headers,
+        values,
+        columns,
+        groups,
+        rows: None,
+        exploded_columns: None,
+    })
 }
 
 struct Impl {
     headers: TokenStream,
     values: TokenStream,
+    columns: Option<TokenStream>,
+    groups: Option<TokenStream>,
+    rows: Option<TokenStream>,
+    exploded_columns: Option<TokenStream>,
 }
 
 fn get_type_headers(
@@ -480,14 +1169,55 @@ fn get_type_headers(
 
 fn get_field_fields(
     field: &TokenStream,
+    field_type: &Type,
     attr: &FieldAttributes,
     fields: &Fields,
     field_name: impl Fn(usize, &Field) -> TokenStream,
+    trait_path: &ExprPath,
 ) -> TokenStream {
     if attr.inline {
+        if let Some(inner) = option_type(field_type) {
+            let placeholder = option_placeholder(attr);
+            return quote! {
+                match &#field {
+                    Some(value) => value.fields(),
+                    None => vec![
+                        ::std::borrow::Cow::Borrowed(#placeholder);
+                        <#inner as #trait_path>::LENGTH
+                    ],
+                }
+            };
+        }
+
         return quote! { #field.fields() };
     }
 
+    if attr.display_with.is_none() && attr.format.is_none() {
+        if let Some(inner) = option_type(field_type) {
+            let value = get_field_fields(
+                &quote!((*value)),
+                inner,
+                attr,
+                fields,
+                field_name,
+                trait_path,
+            );
+            let placeholder = option_placeholder(attr);
+
+            return quote! {
+                match &#field {
+                    Some(value) => #value,
+                    None => vec![::std::borrow::Cow::Borrowed(#placeholder)],
+                }
+            };
+        }
+    }
+
+    if attr.table {
+        let table = nested_table(field, field_type, attr, trait_path);
+        return quote!(vec![::std::borrow::Cow::Owned(#table)]);
+    }
+
     if let Some(func) = &attr.display_with {
         let args = match &attr.display_with_args {
             None => Some(quote!(&#field)),
@@ -535,6 +1265,50 @@ fn get_field_fields(
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
 }
 
+fn nested_table(
+    field: &TokenStream,
+    field_type: &Type,
+    attr: &FieldAttributes,
+    trait_path: &ExprPath,
+) -> TokenStream {
+    let table_path = crate_item_path(trait_path, &["Table"]);
+    let data = match is_collection(field_type) {
+        true => quote!(#field.iter()),
+        false => quote!([&#field]),
+    };
+
+    match &attr.table_style {
+        Some(style) => {
+            let style = crate_item_path(trait_path, &["settings", "Style", style]);
+            quote!(#table_path::new(#data).with(#style()).to_string())
+        }
+        None => quote!(#table_path::new(#data).to_string()),
+    }
+}
+
+// Collections are rendered as a table of their elements,
+// while any other value is rendered as a table of a single row.
+fn is_collection(ty: &Type) -> bool {
+    const COLLECTIONS: [&str; 6] = [
+        "Vec",
+        "VecDeque",
+        "LinkedList",
+        "HashSet",
+        "BTreeSet",
+        "BinaryHeap",
+    ];
+
+    match ty {
+        Type::Reference(reference) => is_collection(&reference.elem),
+        Type::Slice(_) | Type::Array(_) => true,
+        Type::Path(path) => match path.path.segments.last() {
+            Some(segment) => COLLECTIONS.contains(&segment.ident.to_string().as_str()),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
 fn use_function(args: &TokenStream, function: &str) -> TokenStream {
     let path: syn::Result<syn::ExprPath> = syn::parse_str(function);
     match path {
@@ -709,6 +1483,10 @@ fn merge_attributes(attr: &mut FieldAttributes, global_attr: &TypeAttributes) {
     if attr.rename_all.is_none() {
         attr.rename_all = global_attr.rename_all;
     }
+
+    if attr.display_option.is_none() {
+        attr.display_option = global_attr.display_option.clone();
+    }
 }
 
 fn fnarg_tokens(
@@ -793,6 +1571,19 @@ fn parse_crate_name(name: &str) -> Result<ExprPath, Error> {
     syn::parse_str(name).map_err(|_| Error::message("unexpected crate attribute type"))
 }
 
+fn crate_item_path(trait_path: &ExprPath, segments: &[&str]) -> ExprPath {
+    let mut path = trait_path.clone();
+    path.path.segments.pop();
+    for segment in segments {
+        path.path.segments.push(PathSegment {
+            ident: Ident::new(segment, proc_macro2::Span::call_site()),
+            arguments: syn::PathArguments::None,
+        });
+    }
+
+    path
+}
+
 fn create_tabled_trait_path(mut p: ExprPath) -> ExprPath {
     p.path.segments.push(PathSegment {
         ident: Ident::new("Tabled", proc_macro2::Span::call_site()),
@@ -34,6 +34,15 @@ pub enum FieldAttrKind {
     DisplayWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
     Order(LitInt),
     FormatWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
+    Align(LitStr),
+    Color(LitStr),
+    MaxWidth(LitInt),
+    Wrap(LitBool),
+    Truncate(Option<LitStr>),
+    Group(Option<LitStr>),
+    Explode(LitBool),
+    Table(Option<LitStr>),
+    DisplayOption(LitStr),
 }
 
 impl Parse for FieldAttr {
@@ -58,6 +67,11 @@ impl Parse for FieldAttr {
                     "format" => {
                         return Ok(Self::new(name, FormatWith(lit, None, Punctuated::new())))
                     }
+                    "align" => return Ok(Self::new(name, Align(lit))),
+                    "color" => return Ok(Self::new(name, Color(lit))),
+                    "truncate" => return Ok(Self::new(name, Truncate(Some(lit)))),
+                    "group" => return Ok(Self::new(name, Group(Some(lit)))),
+                    "display_option" => return Ok(Self::new(name, DisplayOption(lit))),
                     _ => {}
                 }
             }
@@ -68,6 +82,8 @@ impl Parse for FieldAttr {
                 match name_str.as_str() {
                     "skip" => return Ok(Self::new(name, Skip(lit))),
                     "inline" => return Ok(Self::new(name, Inline(lit, None))),
+                    "wrap" => return Ok(Self::new(name, Wrap(lit))),
+                    "explode" => return Ok(Self::new(name, Explode(lit))),
                     _ => {}
                 }
             }
@@ -75,8 +91,10 @@ impl Parse for FieldAttr {
             if input.peek(LitInt) {
                 let lit = input.parse::<LitInt>()?;
 
-                if let "order" = name_str.as_str() {
-                    return Ok(Self::new(name, Order(lit)));
+                match name_str.as_str() {
+                    "order" => return Ok(Self::new(name, Order(lit))),
+                    "max_width" => return Ok(Self::new(name, MaxWidth(lit))),
+                    _ => {}
                 }
             }
 
@@ -90,6 +108,21 @@ impl Parse for FieldAttr {
             let nested;
             let _paren = parenthesized!(nested in input);
 
+            if name_str == "table" {
+                let key: Ident = nested.parse()?;
+                if key != "style" {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unexpected table attribute: {key}"),
+                    ));
+                }
+
+                nested.parse::<Token![=]>()?;
+                let lit = nested.parse::<LitStr>()?;
+
+                return Ok(Self::new(name, Table(Some(lit))));
+            }
+
             if nested.peek(LitStr) {
                 let lit = nested.parse::<LitStr>()?;
 
@@ -140,6 +173,16 @@ impl Parse for FieldAttr {
                     Inline(LitBool::new(true, Span::call_site()), None),
                 ))
             }
+            "wrap" => return Ok(Self::new(name, Wrap(LitBool::new(true, Span::call_site())))),
+            "truncate" => return Ok(Self::new(name, Truncate(None))),
+            "group" => return Ok(Self::new(name, Group(None))),
+            "table" => return Ok(Self::new(name, Table(None))),
+            "explode" => {
+                return Ok(Self::new(
+                    name,
+                    Explode(LitBool::new(true, Span::call_site())),
+                ))
+            }
             _ => {}
         }
 
@@ -29,6 +29,8 @@ pub enum TypeAttrKind {
     Inline(LitBool, Option<LitStr>),
     RenameAll(LitStr),
     Crate(LitStr),
+    Enum(LitStr),
+    DisplayOption(LitStr),
 }
 
 impl Parse for TypeAttr {
@@ -46,6 +48,14 @@ impl Parse for TypeAttr {
             ));
         }
 
+        if input.peek(Token![enum]) {
+            let enum_token: Token![enum] = input.parse()?;
+            let _ = input.parse::<Token![=]>()?;
+            let value = input.parse::<LitStr>()?;
+
+            return Ok(Self::new(Ident::new("enum", enum_token.span), Enum(value)));
+        }
+
         let name: Ident = input.parse()?;
         let name_str = name.to_string();
 
@@ -55,8 +65,10 @@ impl Parse for TypeAttr {
             if input.peek(LitStr) {
                 let lit = input.parse::<LitStr>()?;
 
-                if let "rename_all" = name_str.as_str() {
-                    return Ok(Self::new(name, RenameAll(lit)));
+                match name_str.as_str() {
+                    "rename_all" => return Ok(Self::new(name, RenameAll(lit))),
+                    "display_option" => return Ok(Self::new(name, DisplayOption(lit))),
+                    _ => {}
                 }
             }
 
@@ -261,7 +261,10 @@
 
 use toml::Value;
 
-pub use table::{Orientation, TomlTable};
+pub use table::{DatetimeFormat, Orientation, Records, TomlTable};
+
+#[cfg(feature = "ansi")]
+pub use table::Palette;
 
 mod table;
 
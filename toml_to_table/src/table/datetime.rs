@@ -0,0 +1,141 @@
+use toml::value::{Datetime, Offset};
+
+/// A format which is used to render TOML datetimes.
+///
+/// ```
+/// use toml_to_table::{DatetimeFormat, TomlTable};
+///
+/// let value = toml::from_str::<toml::Value>("released = 1979-05-27T07:32:00Z").unwrap();
+///
+/// let table = TomlTable::new(&value)
+///     .datetime_format(DatetimeFormat::Readable)
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+----------+---------------------------+\n",
+///         "| released |  1979-05-27 07:32:00 UTC  |\n",
+///         "+----------+---------------------------+",
+///     ),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatetimeFormat {
+    /// Renders a datetime as it's written in TOML, like `1979-05-27T07:32:00Z`.
+    Toml,
+    /// Renders a datetime with a space between a date and a time,
+    /// and with `UTC` instead of `Z`, like `1979-05-27 07:32:00 UTC`.
+    Readable,
+    /// Renders a datetime by a pattern, like `%d.%m.%Y %H:%M`.
+    ///
+    /// Supported specifiers are
+    /// `%Y` (year), `%m` (month), `%d` (day),
+    /// `%H` (hour), `%M` (minute), `%S` (second), `%f` (nanoseconds),
+    /// `%z` (offset like `+07:00`) and `%%`.
+    ///
+    /// Specifiers of a part which is not present in a datetime,
+    /// like a time of a local date, are rendered as empty strings.
+    Pattern(String),
+}
+
+// `#[default]` on a variant isn't supported by our MSRV
+#[allow(clippy::derivable_impls)]
+impl Default for DatetimeFormat {
+    fn default() -> Self {
+        Self::Toml
+    }
+}
+
+impl DatetimeFormat {
+    pub(super) fn format(&self, datetime: &Datetime) -> String {
+        match self {
+            DatetimeFormat::Toml => datetime.to_string(),
+            DatetimeFormat::Readable => readable(datetime),
+            DatetimeFormat::Pattern(pattern) => format_pattern(datetime, pattern),
+        }
+    }
+}
+
+fn readable(datetime: &Datetime) -> String {
+    let mut parts = Vec::with_capacity(3);
+
+    if let Some(date) = &datetime.date {
+        parts.push(format!(
+            "{:04}-{:02}-{:02}",
+            date.year, date.month, date.day
+        ));
+    }
+
+    if let Some(time) = &datetime.time {
+        let mut text = format!("{:02}:{:02}:{:02}", time.hour, time.minute, time.second);
+        if time.nanosecond != 0 {
+            let fraction = format!("{:09}", time.nanosecond);
+            text.push('.');
+            text.push_str(fraction.trim_end_matches('0'));
+        }
+
+        parts.push(text);
+    }
+
+    match datetime.offset {
+        Some(Offset::Z) => parts.push(String::from("UTC")),
+        Some(offset) => parts.push(offset_string(offset)),
+        None => {}
+    }
+
+    parts.join(" ")
+}
+
+fn format_pattern(datetime: &Datetime, pattern: &str) -> String {
+    let date = datetime.date.as_ref();
+    let time = datetime.time.as_ref();
+
+    let mut buf = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            buf.push(c);
+            continue;
+        }
+
+        let spec = match chars.next() {
+            Some(spec) => spec,
+            None => {
+                buf.push('%');
+                break;
+            }
+        };
+
+        let text = match spec {
+            'Y' => date.map(|d| format!("{:04}", d.year)),
+            'm' => date.map(|d| format!("{:02}", d.month)),
+            'd' => date.map(|d| format!("{:02}", d.day)),
+            'H' => time.map(|t| format!("{:02}", t.hour)),
+            'M' => time.map(|t| format!("{:02}", t.minute)),
+            'S' => time.map(|t| format!("{:02}", t.second)),
+            'f' => time.map(|t| format!("{:09}", t.nanosecond)),
+            'z' => datetime.offset.map(offset_string),
+            '%' => Some(String::from("%")),
+            spec => Some(format!("%{}", spec)),
+        };
+
+        if let Some(text) = text {
+            buf.push_str(&text);
+        }
+    }
+
+    buf
+}
+
+fn offset_string(offset: Offset) -> String {
+    let minutes = match offset {
+        Offset::Z => 0,
+        Offset::Custom { minutes } => minutes,
+    };
+
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.unsigned_abs();
+
+    format!("{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+}
@@ -1,8 +1,7 @@
-mod orientation;
-mod table_collapsed;
-mod table_plain;
+mod datetime;
+mod view;
 
-pub use orientation::Orientation;
+pub use datetime::DatetimeFormat;
 
 use std::{
     borrow::Borrow,
@@ -10,30 +9,27 @@ use std::{
 };
 
 use tabled::{
-    grid::{
-        config::{AlignmentHorizontal, Borders, CompactMultilineConfig, Indent, Sides},
-        dimension::CompleteDimension,
-        records::EmptyRecords,
-    },
-    settings::{style::Style, TableOption},
+    grid::{config::CompactMultilineConfig, dimension::CompleteDimension, records::EmptyRecords},
+    settings::TableOption,
+    tables::PoolTable,
+    Table,
 };
-
 use toml::Value;
+use tree_to_table::TreeTable;
+
+use view::View;
+
+#[cfg(feature = "ansi")]
+pub use tree_to_table::Palette;
+pub use tree_to_table::{Orientation, Records};
 
 /// Converter of [`Value`] to a table,
 /// with a set of configurations.
 #[derive(Debug, Clone)]
 pub struct TomlTable<V> {
     value: V,
-    settings: Settings,
-}
-
-#[derive(Debug, Clone)]
-struct Settings {
-    cfg: CompactMultilineConfig,
-    plain: bool,
-    object_orientation: Orientation,
-    array_orientation: Orientation,
+    table: TreeTable,
+    datetime: DatetimeFormat,
 }
 
 impl<V> TomlTable<V>
@@ -44,30 +40,93 @@ where
     pub fn new(value: V) -> Self {
         Self {
             value,
-            settings: Settings {
-                plain: true,
-                cfg: configure_grid(),
-                array_orientation: Orientation::Column,
-                object_orientation: Orientation::Column,
-            },
+            table: TreeTable::new(),
+            datetime: DatetimeFormat::default(),
         }
     }
 
     /// Collapse tables out instead of tables within tables.
     pub fn collapse(&mut self) -> &mut Self {
-        self.settings.plain = false;
+        self.table.collapse();
         self
     }
 
     /// Set a table mode for a [`Value::Table`].
     pub fn map_orientation(&mut self, mode: Orientation) -> &mut Self {
-        self.settings.object_orientation = mode;
+        self.table.map_orientation(mode);
         self
     }
 
     /// Set a table mode for a [`Value::Array`].
     pub fn seq_orientation(&mut self, mode: Orientation) -> &mut Self {
-        self.settings.array_orientation = mode;
+        self.table.seq_orientation(mode);
+        self
+    }
+
+    /// Limit a depth of nested tables.
+    ///
+    /// Deeper tables and arrays are replaced by a placeholder like `{…3 keys}` or `[…12 items]`.
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.table.max_depth(depth);
+        self
+    }
+
+    /// Render arrays of tables as flat tables with keys as a header.
+    ///
+    /// It's applied to any array which consists of tables only, at any depth,
+    /// like the ones defined by `[[servers]]`.
+    ///
+    /// ```
+    /// use toml_to_table::{Records, TomlTable};
+    ///
+    /// let value = toml::from_str::<toml::Value>(
+    ///     r#"
+    ///     [[servers]]
+    ///     host = "alpha"
+    ///     port = 8080
+    ///
+    ///     [[servers]]
+    ///     host = "omega"
+    ///     port = 8081
+    ///     role = "backup"
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let table = TomlTable::new(&value)
+    ///     .records(Records::new().missing("-"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+---------+---------------------------+\n",
+    ///         "| servers | +-------+------+--------+ |\n",
+    ///         "|         | | host  | port | role   | |\n",
+    ///         "|         | +-------+------+--------+ |\n",
+    ///         "|         | | alpha | 8080 | -      | |\n",
+    ///         "|         | +-------+------+--------+ |\n",
+    ///         "|         | | omega | 8081 | backup | |\n",
+    ///         "|         | +-------+------+--------+ |\n",
+    ///         "+---------+---------------------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn records(&mut self, records: Records) -> &mut Self {
+        self.table.records(records);
+        self
+    }
+
+    /// Set a format of datetimes.
+    pub fn datetime_format(&mut self, format: DatetimeFormat) -> &mut Self {
+        self.datetime = format;
+        self
+    }
+
+    /// Color values by their type using a given [`Palette`].
+    #[cfg(feature = "ansi")]
+    pub fn palette(&mut self, palette: Palette) -> &mut Self {
+        self.table.palette(palette);
         self
     }
 
@@ -76,12 +135,50 @@ where
     where
         O: TableOption<EmptyRecords, CompactMultilineConfig, CompleteDimension<'static>>,
     {
-        let mut records = EmptyRecords::default();
-        let mut dims = CompleteDimension::default();
-        option.change(&mut records, &mut self.settings.cfg, &mut dims);
-
+        self.table.with(option);
         self
     }
+
+    /// Build a [`Table`], so any of its settings can be applied afterwards.
+    ///
+    /// It does not recognize collapsed mode.
+    ///
+    /// ```
+    /// use tabled::settings::{Panel, Style};
+    /// use toml_to_table::TomlTable;
+    ///
+    /// let value = toml::from_str::<toml::Value>("name = \"tabled\"\nversion = \"0.15.0\"").unwrap();
+    ///
+    /// let table = TomlTable::new(&value)
+    ///     .into_table()
+    ///     .with(Panel::header("package"))
+    ///     .with(Style::markdown())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "| package            |\n",
+    ///         "|---------|----------|\n",
+    ///         "| name    |  tabled  |\n",
+    ///         "| version |  0.15.0  |",
+    ///     ),
+    /// );
+    /// ```
+    pub fn into_table(&self) -> Table {
+        self.table.into_table(&self.view())
+    }
+
+    /// Build a [`PoolTable`], so any of its settings can be applied afterwards.
+    ///
+    /// It recognizes only collapsed mode.
+    pub fn into_pool_table(&self) -> PoolTable {
+        self.table.into_pool_table(&self.view())
+    }
+
+    fn view(&self) -> View<'_> {
+        View::new(self.value.borrow(), &self.datetime)
+    }
 }
 
 impl<V> Display for TomlTable<V>
@@ -89,28 +186,7 @@ where
     V: Borrow<Value>,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let value = self.value.borrow();
-        let table = match self.settings.plain {
-            true => table_plain::table(value, &self.settings),
-            false => table_collapsed::table(value, &self.settings),
-        };
-
+        let table = self.table.build(&self.view());
         table.fmt(f)
     }
 }
-
-fn configure_grid() -> CompactMultilineConfig {
-    let pad = Sides::new(
-        Indent::spaced(1),
-        Indent::spaced(1),
-        Indent::default(),
-        Indent::default(),
-    );
-
-    let mut cfg = CompactMultilineConfig::new();
-    cfg.set_padding(pad);
-    cfg.set_alignment_horizontal(AlignmentHorizontal::Left);
-    cfg.set_borders(Borders::from(Style::ascii()));
-
-    cfg
-}
@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+
+use toml::Value;
+use tree_to_table::{Key, Node, ScalarKind, TreeValue};
+
+use super::DatetimeFormat;
+
+/// A view of a [`Value`] with datetimes formatted in advance.
+pub(super) enum View<'a> {
+    Table(Vec<(&'a str, View<'a>)>),
+    Array(Vec<View<'a>>),
+    Scalar(ScalarKind, Cow<'a, str>),
+}
+
+impl<'a> View<'a> {
+    pub(super) fn new(value: &'a Value, datetime: &DatetimeFormat) -> Self {
+        match value {
+            Value::Table(map) => View::Table(
+                map.iter()
+                    .map(|(key, value)| (key.as_str(), View::new(value, datetime)))
+                    .collect(),
+            ),
+            Value::Array(list) => View::Array(
+                list.iter()
+                    .map(|value| View::new(value, datetime))
+                    .collect(),
+            ),
+            Value::Boolean(value) => {
+                View::Scalar(ScalarKind::Boolean, Cow::Owned(value.to_string()))
+            }
+            Value::Integer(value) => {
+                View::Scalar(ScalarKind::Number, Cow::Owned(value.to_string()))
+            }
+            Value::Float(value) => View::Scalar(ScalarKind::Number, Cow::Owned(value.to_string())),
+            Value::String(value) => View::Scalar(ScalarKind::String, Cow::Borrowed(value)),
+            Value::Datetime(value) => {
+                View::Scalar(ScalarKind::Other, Cow::Owned(datetime.format(value)))
+            }
+        }
+    }
+}
+
+impl TreeValue for View<'_> {
+    fn node(&self) -> Node<'_, Self> {
+        match self {
+            View::Table(map) => Node::Map(
+                map.iter()
+                    .map(|(key, value)| (Key::Text(Cow::Borrowed(*key)), value))
+                    .collect(),
+            ),
+            View::Array(list) => Node::Seq(list.iter().collect()),
+            View::Scalar(kind, text) => Node::Scalar(*kind, Cow::Borrowed(text)),
+        }
+    }
+}
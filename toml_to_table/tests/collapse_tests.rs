@@ -1,10 +1,10 @@
 use std::{iter::FromIterator, str::FromStr};
 
-use tabled::settings::Alignment;
+use tabled::settings::{Alignment, Style};
 use testing_table::test_table;
 use toml::{Table as TomlMap, Value};
 
-use toml_to_table::{Orientation, TomlTable};
+use toml_to_table::{Orientation, Records, TomlTable};
 
 test_table!(
     test_string,
@@ -473,6 +473,57 @@ test_table!(
     "+---------------------------+"
 );
 
+test_table!(
+    test_into_pool_table,
+    TomlTable::new(&Value::Table(TomlMap::from_iter([
+            (String::from("name"), Value::String(String::from("toml"))),
+            (
+                String::from("keywords"),
+                Value::Array(vec![
+                    Value::String(String::from("encoding")),
+                    Value::String(String::from("parser")),
+                ]),
+            ),
+        ])))
+    .into_pool_table()
+    .with(Style::modern())
+    .to_string(),
+    "┌──────────┬──────────┐"
+    "│ keywords │ encoding │"
+    "│          ├──────────┤"
+    "│          │ parser   │"
+    "├──────────┼──────────┤"
+    "│ name     │ toml     │"
+    "└──────────┴──────────┘"
+);
+
+test_table!(
+    test_records,
+    TomlTable::new(Value::from_str(
+        r#"
+        [[servers]]
+        host = "alpha"
+        port = 8080
+
+        [[servers]]
+        host = "omega-1"
+        tags = ["backup", "eu"]
+        "#
+    ).unwrap())
+    .records(Records::new().missing("-"))
+    .collapse()
+    .to_string(),
+    "+---------+---------+------+--------+"
+    "| servers | host    | port | tags   |"
+    "|         +---------+------+--------+"
+    "|         | alpha   | 8080 | -      |"
+    "|         +---------+------+--------+"
+    "|         | omega-1 | -    | backup |"
+    "|         |         |      +--------+"
+    "|         |         |      | eu     |"
+    "+---------+---------+------+--------+"
+);
+
 fn toml_table(value: Value) -> String {
     TomlTable::new(value).collapse().to_string()
 }
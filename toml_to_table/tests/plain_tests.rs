@@ -1,10 +1,10 @@
 use std::iter::FromIterator;
 
-use tabled::settings::Alignment;
+use tabled::settings::{Alignment, Style};
 use testing_table::test_table;
 use toml::{Table as TomlMap, Value};
 
-use toml_to_table::{Orientation, TomlTable};
+use toml_to_table::{DatetimeFormat, Orientation, Records, TomlTable};
 
 test_table!(
     test_string,
@@ -509,6 +509,112 @@ test_table!(
     "+---------------------------------+"
 );
 
+test_table!(
+    test_into_table,
+    TomlTable::new(&Value::Table(TomlMap::from_iter([
+            (String::from("name"), Value::String(String::from("toml"))),
+            (
+                String::from("keywords"),
+                Value::Array(vec![
+                    Value::String(String::from("encoding")),
+                    Value::String(String::from("parser")),
+                ]),
+            ),
+        ])))
+    .into_table()
+    .with(Style::rounded())
+    .to_string(),
+    "╭──────────┬────────────────╮"
+    "│ keywords │ +------------+ │"
+    "│          │ |  encoding  | │"
+    "│          │ +------------+ │"
+    "│          │ |  parser    | │"
+    "│          │ +------------+ │"
+    "├──────────┼────────────────┤"
+    "│ name     │  toml          │"
+    "╰──────────┴────────────────╯"
+);
+
+test_table!(
+    test_records,
+    TomlTable::new(toml_value(
+        r#"
+        [[servers]]
+        host = "alpha"
+        started = 2024-01-05T10:00:00Z
+
+        [[servers]]
+        host = "omega"
+        started = 2024-01-05T10:00:00+02:00
+        tags = ["backup", "eu"]
+        "#
+    ))
+    .records(Records::new())
+    .datetime_format(DatetimeFormat::Readable)
+    .to_string(),
+    "+---------+-------------------------------------------------------+"
+    "| servers | +-------+----------------------------+--------------+ |"
+    "|         | | host  | started                    | tags         | |"
+    "|         | +-------+----------------------------+--------------+ |"
+    "|         | | alpha | 2024-01-05 10:00:00 UTC    |              | |"
+    "|         | +-------+----------------------------+--------------+ |"
+    "|         | | omega | 2024-01-05 10:00:00 +02:00 | +----------+ | |"
+    "|         | |       |                            | |  backup  | | |"
+    "|         | |       |                            | +----------+ | |"
+    "|         | |       |                            | |  eu      | | |"
+    "|         | |       |                            | +----------+ | |"
+    "|         | +-------+----------------------------+--------------+ |"
+    "+---------+-------------------------------------------------------+"
+);
+
+test_table!(
+    test_records_not_tables,
+    TomlTable::new(toml_value("servers = [{ host = \"alpha\" }, \"omega\"]"))
+        .records(Records::new())
+        .to_string(),
+    "+---------+------------------------+"
+    "| servers | +--------------------+ |"
+    "|         | | +------+---------+ | |"
+    "|         | | | host |  alpha  | | |"
+    "|         | | +------+---------+ | |"
+    "|         | +--------------------+ |"
+    "|         | |  omega             | |"
+    "|         | +--------------------+ |"
+    "+---------+------------------------+"
+);
+
+test_table!(
+    test_datetime_readable,
+    TomlTable::new(toml_value(
+        "date = 1979-05-27\ntime = 07:32:00.999\nlocal = 1979-05-27T07:32:00\noffset = 1979-05-27T00:32:00-07:00"
+    ))
+    .datetime_format(DatetimeFormat::Readable)
+    .to_string(),
+    "+--------+------------------------------+"
+    "| date   |  1979-05-27                  |"
+    "+--------+------------------------------+"
+    "| local  |  1979-05-27 07:32:00         |"
+    "+--------+------------------------------+"
+    "| offset |  1979-05-27 00:32:00 -07:00  |"
+    "+--------+------------------------------+"
+    "| time   |  07:32:00.999                |"
+    "+--------+------------------------------+"
+);
+
+test_table!(
+    test_datetime_pattern,
+    TomlTable::new(toml_value(
+        "date = 1979-05-27\noffset = 1979-05-27T00:32:00.5-07:30"
+    ))
+    .datetime_format(DatetimeFormat::Pattern(String::from("%d.%m.%Y %H:%M:%S.%f %z %% %q")))
+    .to_string(),
+    "+--------+---------------------------------------------+"
+    "| date   |  27.05.1979 ::.  % %q                       |"
+    "+--------+---------------------------------------------+"
+    "| offset |  27.05.1979 00:32:00.500000000 -07:30 % %q  |"
+    "+--------+---------------------------------------------+"
+);
+
 fn toml_table(value: Value) -> String {
     TomlTable::new(value).to_string()
 }
@@ -519,3 +625,7 @@ fn toml_table_orientation(value: Value, seq: Orientation, map: Orientation) -> S
         .map_orientation(map)
         .to_string()
 }
+
+fn toml_value(text: &str) -> Value {
+    toml::from_str(text).unwrap()
+}
@@ -0,0 +1,79 @@
+#[cfg(any(feature = "serde_json", feature = "toml", feature = "ron"))]
+use std::borrow::Cow;
+
+#[cfg(any(feature = "serde_json", feature = "toml", feature = "ron"))]
+use crate::{Key, Node, ScalarKind, TreeValue};
+
+#[cfg(feature = "serde_json")]
+impl TreeValue for serde_json::Value {
+    fn node(&self) -> Node<'_, Self> {
+        use serde_json::Value;
+
+        match self {
+            Value::Object(map) => Node::Map(
+                map.iter()
+                    .map(|(key, value)| (Key::Text(Cow::Borrowed(key.as_str())), value))
+                    .collect(),
+            ),
+            Value::Array(list) => Node::Seq(list.iter().collect()),
+            Value::Null => Node::Scalar(ScalarKind::Null, Cow::Borrowed("")),
+            Value::Bool(value) => Node::Scalar(ScalarKind::Boolean, Cow::Owned(value.to_string())),
+            Value::Number(value) => Node::Scalar(ScalarKind::Number, Cow::Owned(value.to_string())),
+            Value::String(value) => Node::Scalar(ScalarKind::String, Cow::Borrowed(value)),
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl TreeValue for toml::Value {
+    fn node(&self) -> Node<'_, Self> {
+        use toml::Value;
+
+        match self {
+            Value::Table(map) => Node::Map(
+                map.iter()
+                    .map(|(key, value)| (Key::Text(Cow::Borrowed(key.as_str())), value))
+                    .collect(),
+            ),
+            Value::Array(list) => Node::Seq(list.iter().collect()),
+            Value::Boolean(value) => {
+                Node::Scalar(ScalarKind::Boolean, Cow::Owned(value.to_string()))
+            }
+            Value::Integer(value) => {
+                Node::Scalar(ScalarKind::Number, Cow::Owned(value.to_string()))
+            }
+            Value::Float(value) => Node::Scalar(ScalarKind::Number, Cow::Owned(value.to_string())),
+            Value::String(value) => Node::Scalar(ScalarKind::String, Cow::Borrowed(value)),
+            Value::Datetime(value) => {
+                Node::Scalar(ScalarKind::Other, Cow::Owned(value.to_string()))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ron")]
+impl TreeValue for ron::Value {
+    fn node(&self) -> Node<'_, Self> {
+        use ron::{Number, Value};
+
+        match self {
+            Value::Map(map) => Node::Map(
+                map.iter()
+                    .map(|(key, value)| (Key::Value(key), value))
+                    .collect(),
+            ),
+            Value::Seq(list) => Node::Seq(list.iter().collect()),
+            Value::Option(Some(value)) => value.node(),
+            Value::Option(None) | Value::Unit => Node::Scalar(ScalarKind::Null, Cow::Borrowed("")),
+            Value::Bool(value) => Node::Scalar(ScalarKind::Boolean, Cow::Owned(value.to_string())),
+            Value::Char(value) => Node::Scalar(ScalarKind::String, Cow::Owned(value.to_string())),
+            Value::Number(Number::Integer(value)) => {
+                Node::Scalar(ScalarKind::Number, Cow::Owned(value.to_string()))
+            }
+            Value::Number(Number::Float(value)) => {
+                Node::Scalar(ScalarKind::Number, Cow::Owned(value.get().to_string()))
+            }
+            Value::String(value) => Node::Scalar(ScalarKind::String, Cow::Borrowed(value)),
+        }
+    }
+}
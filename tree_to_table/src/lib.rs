@@ -0,0 +1,42 @@
+//! The library creates a pretty table out of a tree like value,
+//! which is a map, a sequence or a scalar.
+//!
+//! It's a rendering engine shared by `json_to_table`, `toml_to_table`, `ron_to_table` and `serde_to_table`,
+//! so orientation, collapsing, depth limits, key ordering and coloring work the same way for all of them.
+//! You can render your own value types by implementing [`TreeValue`].
+//!
+//! Implementations for [`serde_json::Value`], [`toml::Value`] and [`ron::Value`]
+//! are available under `serde_json`, `toml` and `ron` features.
+//!
+//! The are 2 types of tables you can create.
+//!     1. Embeded (default)
+//!     2. Collapsed
+//!
+//! You can configure table using [`TreeTable`].
+//!
+//! [`serde_json::Value`]: https://docs.rs/serde_json/latest/serde_json/enum.Value.html
+//! [`toml::Value`]: https://docs.rs/toml/latest/toml/enum.Value.html
+//! [`ron::Value`]: https://docs.rs/ron/latest/ron/enum.Value.html
+
+#![deny(unused_must_use)]
+#![warn(
+    missing_docs,
+    rust_2018_idioms,
+    missing_debug_implementations,
+    unreachable_pub
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+mod impls;
+#[cfg(feature = "ansi")]
+mod palette;
+mod table;
+mod value;
+
+#[cfg(feature = "ansi")]
+pub use palette::Palette;
+pub use table::{Keys, Orientation, Records, TreeTable};
+pub use value::{Key, Node, ScalarKind, TreeValue};
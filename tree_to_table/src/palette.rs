@@ -0,0 +1,125 @@
+use tabled::settings::Color;
+
+use crate::ScalarKind;
+
+/// A set of colors which are used to highlight values by their type.
+///
+/// ```
+/// use tabled::settings::Color;
+/// use tree_to_table::{Palette, ScalarKind};
+///
+/// let palette = Palette::new().number(Color::FG_RED);
+///
+/// assert_eq!(palette.paint(ScalarKind::Number, "1"), "\u{1b}[31m1\u{1b}[39m");
+/// assert_eq!(palette.paint_key("id"), "\u{1b}[34mid\u{1b}[39m");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    string: Color,
+    number: Color,
+    boolean: Color,
+    null: Color,
+    other: Color,
+    key: Color,
+}
+
+impl Palette {
+    /// Creates a default palette.
+    ///
+    /// Strings are green, numbers are cyan, booleans are yellow,
+    /// nulls are bright black, other values are magenta and keys are blue.
+    pub fn new() -> Self {
+        Self {
+            string: Color::FG_GREEN,
+            number: Color::FG_CYAN,
+            boolean: Color::FG_YELLOW,
+            null: Color::FG_BRIGHT_BLACK,
+            other: Color::FG_MAGENTA,
+            key: Color::FG_BLUE,
+        }
+    }
+
+    /// Creates a palette which does not color anything.
+    pub fn empty() -> Self {
+        Self {
+            string: Color::empty(),
+            number: Color::empty(),
+            boolean: Color::empty(),
+            null: Color::empty(),
+            other: Color::empty(),
+            key: Color::empty(),
+        }
+    }
+
+    /// Set a color of strings.
+    pub fn string(mut self, color: Color) -> Self {
+        self.string = color;
+        self
+    }
+
+    /// Set a color of numbers.
+    pub fn number(mut self, color: Color) -> Self {
+        self.number = color;
+        self
+    }
+
+    /// Set a color of booleans.
+    pub fn boolean(mut self, color: Color) -> Self {
+        self.boolean = color;
+        self
+    }
+
+    /// Set a color of nulls.
+    pub fn null(mut self, color: Color) -> Self {
+        self.null = color;
+        self
+    }
+
+    /// Set a color of other values, like datetimes.
+    pub fn other(mut self, color: Color) -> Self {
+        self.other = color;
+        self
+    }
+
+    /// Set a color of map keys.
+    pub fn key(mut self, color: Color) -> Self {
+        self.key = color;
+        self
+    }
+
+    /// Colors a text of a scalar of a given kind.
+    pub fn paint(&self, kind: ScalarKind, text: &str) -> String {
+        let color = match kind {
+            ScalarKind::Null => &self.null,
+            ScalarKind::Boolean => &self.boolean,
+            ScalarKind::Number => &self.number,
+            ScalarKind::String => &self.string,
+            ScalarKind::Other => &self.other,
+        };
+
+        colorize(text, color)
+    }
+
+    /// Colors a text of a map key.
+    pub fn paint_key(&self, text: &str) -> String {
+        colorize(text, &self.key)
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn colorize(text: &str, color: &Color) -> String {
+    if color.get_prefix().is_empty() && color.get_suffix().is_empty() {
+        return text.to_owned();
+    }
+
+    // each line is colored separately so a color is not broken by a cell split
+    text.lines()
+        .map(|line| format!("{}{}{}", color.get_prefix(), line, color.get_suffix()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
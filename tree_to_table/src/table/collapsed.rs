@@ -0,0 +1,431 @@
+use std::cmp;
+
+use tabled::{
+    grid::{
+        config::{AlignmentHorizontal, AlignmentVertical},
+        dimension::{DimensionPriority, PoolTableDimension},
+        util::string::{count_lines, get_lines, string_width, string_width_multiline},
+    },
+    tables::{PoolTable, TableValue},
+};
+
+use crate::{Key, Node, TreeValue};
+
+use super::{Orientation, TreeTable};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
+struct CollapseCtx {
+    has_horizontal: bool,
+    has_vertical: bool,
+    padding: usize,
+    alignment_horizontal: AlignmentHorizontal,
+    alignment_vertical: AlignmentVertical,
+}
+
+pub(super) fn table<V>(value: &V, cfg: &TreeTable) -> String
+where
+    V: TreeValue + ?Sized,
+{
+    into_pool_table(value, cfg).to_string()
+}
+
+pub(super) fn into_pool_table<V>(value: &V, cfg: &TreeTable) -> PoolTable
+where
+    V: TreeValue + ?Sized,
+{
+    let ctx = CollapseCtx {
+        has_horizontal: cfg.cfg.get_borders().has_top(),
+        has_vertical: cfg.cfg.get_borders().has_left(),
+        padding: cfg.cfg.get_padding().left.size + cfg.cfg.get_padding().right.size,
+        alignment_horizontal: cfg.cfg.get_alignment_horizontal(),
+        alignment_vertical: cfg.cfg.get_alignment_vertical(),
+    };
+    let value = convert_value_to_table_value(value, cfg, ctx, 0);
+
+    let mut table = PoolTable::from(value);
+    table.with(cfg.cfg).with(PoolTableDimension::new(
+        DimensionPriority::Last,
+        DimensionPriority::Last,
+    ));
+
+    table
+}
+
+fn convert_value_to_table_value<V>(
+    value: &V,
+    cfg: &TreeTable,
+    ctx: CollapseCtx,
+    depth: usize,
+) -> TableValue
+where
+    V: TreeValue + ?Sized,
+{
+    let table = match cfg.node(value, depth) {
+        Node::Map(map) => match cfg.map_orientation {
+            Orientation::Row => convert_map_to_row(map, cfg, ctx, depth),
+            Orientation::Column => convert_map_to_column(map, cfg, ctx, depth),
+        },
+        Node::Seq(list) => convert_list(list, cfg, ctx, depth),
+        Node::Tuple(list) => convert_tuple(list, cfg, ctx, depth),
+        Node::Scalar(kind, text) => return TableValue::Cell(cfg.scalar(kind, text)),
+    };
+
+    match value.name() {
+        Some(name) => TableValue::Column(vec![TableValue::Cell(name.into_owned()), table]),
+        None => table,
+    }
+}
+
+fn convert_list<V>(list: Vec<&V>, cfg: &TreeTable, ctx: CollapseCtx, depth: usize) -> TableValue
+where
+    V: TreeValue + ?Sized,
+{
+    if let Some(table) = convert_records(&list, cfg, ctx, depth) {
+        return table;
+    }
+
+    let list = list
+        .into_iter()
+        .map(|value| convert_value_to_table_value(value, cfg, ctx, depth + 1))
+        .collect();
+
+    match cfg.seq_orientation {
+        Orientation::Row => TableValue::Row(list),
+        Orientation::Column => TableValue::Column(list),
+    }
+}
+
+fn convert_tuple<V>(list: Vec<&V>, cfg: &TreeTable, ctx: CollapseCtx, depth: usize) -> TableValue
+where
+    V: TreeValue + ?Sized,
+{
+    let list = list
+        .into_iter()
+        .map(|value| convert_value_to_table_value(value, cfg, ctx, depth + 1))
+        .collect();
+
+    TableValue::Row(list)
+}
+
+fn convert_records<V>(
+    list: &[&V],
+    cfg: &TreeTable,
+    ctx: CollapseCtx,
+    depth: usize,
+) -> Option<TableValue>
+where
+    V: TreeValue + ?Sized,
+{
+    let records = cfg.records.as_ref()?;
+    let nodes = list
+        .iter()
+        .map(|value| cfg.node(*value, depth + 1))
+        .collect();
+    let data = records.split(nodes, cfg.keys.as_ref())?;
+
+    let mut rows = Vec::with_capacity(data.rows.len() + 1);
+    let header = data
+        .header
+        .iter()
+        .map(|key| {
+            (
+                TableValue::Cell(cfg.paint_key(key)),
+                ctx.alignment_horizontal,
+            )
+        })
+        .collect::<Vec<_>>();
+    rows.push(header);
+
+    for i in 0..data.rows.len() {
+        let row = data
+            .row(i)
+            .map(|value| match value {
+                Some(value) => {
+                    let alignment = match cfg.is_right_aligned(value) {
+                        true => AlignmentHorizontal::Right,
+                        false => ctx.alignment_horizontal,
+                    };
+
+                    let value = match convert_value_to_table_value(value, cfg, ctx, depth + 2) {
+                        TableValue::Row(_) | TableValue::Column(_) if records.is_inline() => {
+                            TableValue::Cell(cfg.inline(value, depth + 2))
+                        }
+                        // an empty cell is used so it can be aligned
+                        TableValue::Row(list) | TableValue::Column(list) if list.is_empty() => {
+                            TableValue::Cell(String::new())
+                        }
+                        value => value,
+                    };
+
+                    (value, alignment)
+                }
+                None => (
+                    TableValue::Cell(records.get_missing().to_owned()),
+                    ctx.alignment_horizontal,
+                ),
+            })
+            .collect();
+
+        rows.push(row);
+    }
+
+    // columns are aligned by hand as a pool table has no notion of columns
+    for col in 0..data.header.len() {
+        let width = rows
+            .iter()
+            .map(|row| table_value_width(&row[col].0, ctx.has_vertical, ctx.padding))
+            .max()
+            .unwrap_or(0);
+
+        for row in &mut rows {
+            let (value, alignment) = &mut row[col];
+            let left = width - table_value_width(value, ctx.has_vertical, ctx.padding);
+            if left > 0 {
+                table_value_increase_width(value, left, *alignment);
+            }
+        }
+    }
+
+    let rows = rows
+        .into_iter()
+        .map(|row| TableValue::Row(row.into_iter().map(|(value, _)| value).collect()))
+        .collect();
+
+    Some(TableValue::Column(rows))
+}
+
+fn convert_map_to_column<V>(
+    map: Vec<(Key<'_, V>, &V)>,
+    cfg: &TreeTable,
+    ctx: CollapseCtx,
+    depth: usize,
+) -> TableValue
+where
+    V: TreeValue + ?Sized,
+{
+    let (keys, values): (Vec<_>, Vec<_>) = map.into_iter().unzip();
+
+    let mut keys = keys
+        .iter()
+        .map(|key| convert_key(key, cfg, ctx, depth))
+        .map(|key| {
+            let width = table_value_width(&key, ctx.has_vertical, ctx.padding);
+            (key, width)
+        })
+        .collect::<Vec<_>>();
+
+    let key_width = keys.iter().map(|v| v.1).max().unwrap_or(0);
+
+    keys.iter_mut().for_each(|(key, width)| {
+        let left = key_width - *width;
+        if left > 0 {
+            table_value_increase_width(key, left, ctx.alignment_horizontal);
+        }
+    });
+
+    let data = keys
+        .into_iter()
+        .zip(values)
+        .map(|((key, _), value)| {
+            (
+                key,
+                convert_value_to_table_value(value, cfg, ctx, depth + 1),
+            )
+        })
+        .map(|(key, value)| TableValue::Row(vec![key, value]))
+        .collect();
+
+    TableValue::Column(data)
+}
+
+fn convert_map_to_row<V>(
+    map: Vec<(Key<'_, V>, &V)>,
+    cfg: &TreeTable,
+    ctx: CollapseCtx,
+    depth: usize,
+) -> TableValue
+where
+    V: TreeValue + ?Sized,
+{
+    let (keys, values): (Vec<_>, Vec<_>) = map.into_iter().unzip();
+
+    let mut keys = keys
+        .iter()
+        .map(|key| convert_key(key, cfg, ctx, depth))
+        .map(|key| {
+            let height = table_value_height(&key, ctx.has_horizontal);
+            (key, height)
+        })
+        .collect::<Vec<_>>();
+
+    let key_height = keys.iter().map(|v| v.1).max().unwrap_or(0);
+
+    keys.iter_mut().for_each(|(key, height)| {
+        let left = key_height - *height;
+        if left > 0 {
+            table_value_increase_height(key, left, ctx.alignment_vertical);
+        }
+    });
+
+    let data = keys
+        .into_iter()
+        .zip(values)
+        .map(|((key, _), value)| {
+            (
+                key,
+                convert_value_to_table_value(value, cfg, ctx, depth + 1),
+            )
+        })
+        .map(|(key, value)| TableValue::Column(vec![key, value]))
+        .collect();
+
+    TableValue::Row(data)
+}
+
+fn convert_key<V>(key: &Key<'_, V>, cfg: &TreeTable, ctx: CollapseCtx, depth: usize) -> TableValue
+where
+    V: TreeValue + ?Sized,
+{
+    match key {
+        Key::Text(text) => TableValue::Cell(cfg.paint_key(text)),
+        Key::Value(key) => convert_value_to_table_value(*key, cfg, ctx, depth + 1),
+    }
+}
+
+fn table_value_width(value: &TableValue, has_vertical: bool, padding: usize) -> usize {
+    match value {
+        TableValue::Row(list) => {
+            list.iter()
+                .map(|value| table_value_width(value, has_vertical, padding))
+                .sum::<usize>()
+                + (cmp::max(list.len(), 1) - 1) * has_vertical as usize
+        }
+        TableValue::Column(list) => list
+            .iter()
+            .map(|value| table_value_width(value, has_vertical, padding))
+            .max()
+            .unwrap_or(0),
+        TableValue::Cell(string) => string_width_multiline(string) + padding,
+    }
+}
+
+fn table_value_height(value: &TableValue, has_horizontal: bool) -> usize {
+    match value {
+        TableValue::Row(list) => list
+            .iter()
+            .map(|value| table_value_height(value, has_horizontal))
+            .max()
+            .unwrap_or(0),
+        TableValue::Column(list) => {
+            list.iter()
+                .map(|value| table_value_height(value, has_horizontal))
+                .sum::<usize>()
+                + (cmp::max(list.len(), 1) - 1) * has_horizontal as usize
+        }
+        TableValue::Cell(string) => count_lines(string),
+    }
+}
+
+fn table_value_increase_width(value: &mut TableValue, by: usize, ah: AlignmentHorizontal) {
+    match value {
+        TableValue::Row(list) => {
+            let mut left = by;
+            while left > 0 {
+                for value in list.iter_mut() {
+                    left -= 1;
+                    table_value_increase_width(value, 1, ah);
+                }
+            }
+        }
+        TableValue::Column(list) => {
+            for value in list.iter_mut() {
+                table_value_increase_width(value, by, ah);
+            }
+        }
+        TableValue::Cell(string) => *string = increase_string_width(string, by, ah),
+    }
+}
+
+fn table_value_increase_height(value: &mut TableValue, by: usize, av: AlignmentVertical) {
+    match value {
+        TableValue::Row(list) => {
+            for value in list.iter_mut() {
+                table_value_increase_height(value, by, av);
+            }
+        }
+        TableValue::Column(list) => {
+            let mut left = by;
+            while left > 0 {
+                for value in list.iter_mut() {
+                    left -= 1;
+                    table_value_increase_height(value, 1, av);
+                }
+            }
+        }
+        TableValue::Cell(string) => *string = increase_string_height(string, by, av),
+    }
+}
+
+fn increase_string_width(text: &str, by: usize, ah: AlignmentHorizontal) -> String {
+    let mut out = Vec::new();
+
+    for line in get_lines(text) {
+        let w = string_width(&line);
+        let (left, right) = indent_horizontal(ah, w + by, w);
+
+        let mut buf = String::new();
+        buf.push_str(&" ".repeat(left));
+        buf.push_str(&line);
+        buf.push_str(&" ".repeat(right));
+
+        out.push(buf);
+    }
+
+    out.join("\n")
+}
+
+fn increase_string_height(text: &str, by: usize, av: AlignmentVertical) -> String {
+    let mut out = Vec::new();
+
+    let count_lines = count_lines(text);
+
+    let (top, bottom) = indent_vertical(av, count_lines + by, count_lines);
+
+    out.resize(top, String::new());
+
+    for line in get_lines(text) {
+        out.push(line.into_owned());
+    }
+
+    out.resize(out.len() + bottom, String::new());
+
+    out.join("\n")
+}
+
+fn indent_vertical(al: AlignmentVertical, available: usize, real: usize) -> (usize, usize) {
+    let top = indent_top(al, available, real);
+    let bottom = available - real - top;
+    (top, bottom)
+}
+
+fn indent_horizontal(al: AlignmentHorizontal, available: usize, real: usize) -> (usize, usize) {
+    let top = indent_left(al, available, real);
+    let right = available - real - top;
+    (top, right)
+}
+
+fn indent_top(al: AlignmentVertical, available: usize, real: usize) -> usize {
+    match al {
+        AlignmentVertical::Top => 0,
+        AlignmentVertical::Bottom => available - real,
+        AlignmentVertical::Center => (available - real) / 2,
+    }
+}
+
+fn indent_left(al: AlignmentHorizontal, available: usize, real: usize) -> usize {
+    match al {
+        AlignmentHorizontal::Left => 0,
+        AlignmentHorizontal::Right => available - real,
+        AlignmentHorizontal::Center => (available - real) / 2,
+    }
+}
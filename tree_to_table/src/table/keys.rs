@@ -0,0 +1,180 @@
+use std::cmp::Ordering;
+
+use crate::Key;
+
+/// A configuration of map keys,
+/// which sets their order and filters them out at every depth.
+///
+/// By default keys follow an order of a map.
+/// Only text keys are ordered and filtered, keys which are values are kept as they are.
+///
+/// Patterns may contain `*` which matches any sequence of characters
+/// and `?` which matches a single character.
+///
+/// ```
+/// # #[cfg(feature = "serde_json")] {
+/// use tree_to_table::{Keys, TreeTable};
+///
+/// let value = serde_json::json!({
+///     "id": 1,
+///     "name": "Rust",
+///     "_internal": true,
+///     "team": {"lead": "-", "_size": 10},
+/// });
+///
+/// let table = TreeTable::new()
+///     .keys(Keys::new().priority(["name", "id"]).exclude(["_*"]))
+///     .collapse()
+///     .build(&value);
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+------+----------+\n",
+///         "| name | Rust     |\n",
+///         "+------+----------+\n",
+///         "| id   | 1        |\n",
+///         "+------+------+---+\n",
+///         "| team | lead | - |\n",
+///         "+------+------+---+",
+///     ),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Keys {
+    sort: bool,
+    priority: Vec<String>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl Keys {
+    /// Creates a default configuration, which keeps an order of a map and all its keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sort keys alphabetically.
+    ///
+    /// Notice that keys from a priority list still go first.
+    pub fn sort(mut self, on: bool) -> Self {
+        self.sort = on;
+        self
+    }
+
+    /// Set keys which are always shown first, in a given order.
+    pub fn priority<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.priority = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set patterns of keys which are shown,
+    /// all other keys are dropped.
+    pub fn include<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.include = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set patterns of keys which are dropped.
+    pub fn exclude<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.exclude = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Drops keys which are not shown and orders the rest.
+    pub(super) fn apply<'a, V>(&self, map: &mut Vec<(Key<'a, V>, &'a V)>)
+    where
+        V: ?Sized,
+    {
+        map.retain(|(key, _)| match key {
+            Key::Text(key) => self.is_shown(key),
+            Key::Value(_) => true,
+        });
+
+        self.order(map, |(key, _)| match key {
+            Key::Text(key) => Some(key),
+            Key::Value(_) => None,
+        });
+    }
+
+    /// Orders keys, keeping an original order of keys which are considered equal.
+    pub(super) fn order<T, F>(&self, list: &mut [T], key: F)
+    where
+        F: Fn(&T) -> Option<&str>,
+    {
+        if !self.sort && self.priority.is_empty() {
+            return;
+        }
+
+        list.sort_by(|a, b| {
+            let (a, b) = match (key(a), key(b)) {
+                (Some(a), Some(b)) => (a, b),
+                _ => return Ordering::Equal,
+            };
+
+            let by_priority = self.rank(a).cmp(&self.rank(b));
+            match by_priority {
+                Ordering::Equal if self.sort => a.cmp(b),
+                ordering => ordering,
+            }
+        });
+    }
+
+    fn rank(&self, key: &str) -> usize {
+        self.priority
+            .iter()
+            .position(|k| k == key)
+            .unwrap_or(self.priority.len())
+    }
+
+    fn is_shown(&self, key: &str) -> bool {
+        let is_included = self.include.is_empty() || self.include.iter().any(|p| is_match(p, key));
+        let is_excluded = self.exclude.iter().any(|p| is_match(p, key));
+
+        is_included && !is_excluded
+    }
+}
+
+// A glob like matching where `*` is any sequence of characters and `?` is any character.
+fn is_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((bp, bt)) => {
+                    backtrack = Some((bp, bt + 1));
+                    p = bp + 1;
+                    t = bt + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
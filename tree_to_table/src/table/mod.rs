@@ -0,0 +1,449 @@
+mod collapsed;
+mod keys;
+mod orientation;
+mod plain;
+mod records;
+
+pub use keys::Keys;
+pub use orientation::Orientation;
+pub use records::Records;
+
+use std::borrow::Cow;
+
+use tabled::{
+    grid::{
+        config::{AlignmentHorizontal, Borders, CompactMultilineConfig, Indent, Sides},
+        dimension::CompleteDimension,
+        records::EmptyRecords,
+    },
+    settings::{style::Style, TableOption},
+    tables::PoolTable,
+    Table,
+};
+
+#[cfg(feature = "ansi")]
+use crate::Palette;
+use crate::{Key, Node, ScalarKind, TreeValue};
+
+/// Converter of a [`TreeValue`] to a table,
+/// with a set of configurations.
+///
+/// ```
+/// # #[cfg(feature = "serde_json")] {
+/// use tree_to_table::{Orientation, TreeTable};
+///
+/// let value = serde_json::json!({"name": "Rust", "versions": ["1.0", "2.0"]});
+///
+/// let table = TreeTable::new()
+///     .seq_orientation(Orientation::Row)
+///     .collapse()
+///     .build(&value);
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+----------+-----------+\n",
+///         "| name     | Rust      |\n",
+///         "+----------+-----+-----+\n",
+///         "| versions | 1.0 | 2.0 |\n",
+///         "+----------+-----+-----+",
+///     ),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TreeTable {
+    cfg: CompactMultilineConfig,
+    plain: bool,
+    map_orientation: Orientation,
+    seq_orientation: Orientation,
+    max_depth: Option<usize>,
+    records: Option<Records>,
+    keys: Option<Keys>,
+    quote_strings: bool,
+    right_align_numbers: bool,
+    #[cfg(feature = "ansi")]
+    palette: Option<Palette>,
+}
+
+impl Default for TreeTable {
+    fn default() -> Self {
+        Self {
+            plain: true,
+            cfg: configure_grid(),
+            map_orientation: Orientation::Column,
+            seq_orientation: Orientation::Column,
+            max_depth: None,
+            records: None,
+            keys: None,
+            quote_strings: false,
+            right_align_numbers: false,
+            #[cfg(feature = "ansi")]
+            palette: None,
+        }
+    }
+}
+
+impl TreeTable {
+    /// Creates a default table configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collapse tables out instead of tables within tables.
+    pub fn collapse(&mut self) -> &mut Self {
+        self.plain = false;
+        self
+    }
+
+    /// Set a table mode for a [`Node::Map`].
+    pub fn map_orientation(&mut self, mode: Orientation) -> &mut Self {
+        self.map_orientation = mode;
+        self
+    }
+
+    /// Set a table mode for a [`Node::Seq`].
+    pub fn seq_orientation(&mut self, mode: Orientation) -> &mut Self {
+        self.seq_orientation = mode;
+        self
+    }
+
+    /// Limit a depth of nested tables.
+    ///
+    /// Deeper maps and sequences are replaced by a placeholder like `{…3 keys}` or `[…12 items]`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde_json")] {
+    /// use tree_to_table::TreeTable;
+    ///
+    /// let value = serde_json::json!({"name": "Rust", "team": {"lead": "-", "size": 10}});
+    ///
+    /// let table = TreeTable::new().max_depth(1).collapse().build(&value);
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+-----------+\n",
+    ///         "| name | Rust      |\n",
+    ///         "+------+-----------+\n",
+    ///         "| team | {…2 keys} |\n",
+    ///         "+------+-----------+",
+    ///     ),
+    /// );
+    /// # }
+    /// ```
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Render sequences of maps as flat tables with keys as a header.
+    ///
+    /// It's applied to any sequence which consists of maps only, at any depth.
+    ///
+    /// See [`Records`].
+    pub fn records(&mut self, records: Records) -> &mut Self {
+        self.records = Some(records);
+        self
+    }
+
+    /// Set an order of map keys and filter them out.
+    ///
+    /// The configuration is applied at every depth, see [`Keys`].
+    pub fn keys(&mut self, keys: Keys) -> &mut Self {
+        self.keys = Some(keys);
+        self
+    }
+
+    /// Render strings quoted, so they can be told apart from numbers, booleans and nulls.
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde_json")] {
+    /// use tree_to_table::TreeTable;
+    ///
+    /// let value = serde_json::json!({"name": "Rust", "year": "2010"});
+    ///
+    /// let table = TreeTable::new().quote_strings(true).collapse().build(&value);
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+--------+\n",
+    ///         "| name | \"Rust\" |\n",
+    ///         "+------+--------+\n",
+    ///         "| year | \"2010\" |\n",
+    ///         "+------+--------+",
+    ///     ),
+    /// );
+    /// # }
+    /// ```
+    pub fn quote_strings(&mut self, on: bool) -> &mut Self {
+        self.quote_strings = on;
+        self
+    }
+
+    /// Align numbers to the right.
+    ///
+    /// In a collapsed mode only columns of [`Records`] are aligned,
+    /// as other cells are sized by a [`PoolTable`] itself.
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde_json")] {
+    /// use tree_to_table::TreeTable;
+    ///
+    /// let value = serde_json::json!({"downloads": 1200, "version": "1.0.0"});
+    ///
+    /// let table = TreeTable::new().right_align_numbers(true).build(&value);
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-----------+---------+\n",
+    ///         "| downloads |   1200  |\n",
+    ///         "+-----------+---------+\n",
+    ///         "| version   |  1.0.0  |\n",
+    ///         "+-----------+---------+",
+    ///     ),
+    /// );
+    /// # }
+    /// ```
+    pub fn right_align_numbers(&mut self, on: bool) -> &mut Self {
+        self.right_align_numbers = on;
+        self
+    }
+
+    /// Color values by their type using a given [`Palette`].
+    #[cfg(feature = "ansi")]
+    pub fn palette(&mut self, palette: Palette) -> &mut Self {
+        self.palette = Some(palette);
+        self
+    }
+
+    /// Apply settings to the table.
+    pub fn with<O>(&mut self, option: O) -> &mut Self
+    where
+        O: TableOption<EmptyRecords, CompactMultilineConfig, CompleteDimension<'static>>,
+    {
+        let mut records = EmptyRecords::default();
+        let mut dims = CompleteDimension::default();
+        option.change(&mut records, &mut self.cfg, &mut dims);
+
+        self
+    }
+
+    /// Build a table.
+    pub fn build<V>(&self, value: &V) -> String
+    where
+        V: TreeValue + ?Sized,
+    {
+        match self.plain {
+            true => plain::table(value, self),
+            false => collapsed::table(value, self),
+        }
+    }
+
+    /// Build a [`Table`], so any of its settings can be applied afterwards.
+    ///
+    /// It does not recognize collapsed mode.
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde_json")] {
+    /// use tabled::settings::{Modify, object::Columns, Alignment, Style};
+    /// use tree_to_table::TreeTable;
+    ///
+    /// let value = serde_json::json!({"name": "Rust", "versions": ["1.0", "2.0"]});
+    ///
+    /// let table = TreeTable::new()
+    ///     .into_table(&value)
+    ///     .with(Style::markdown())
+    ///     .with(Modify::new(Columns::first()).with(Alignment::right()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "|     name |  Rust     |\n",
+    ///         "|----------|-----------|\n",
+    ///         "| versions | +-------+ |\n",
+    ///         "|          | |  1.0  | |\n",
+    ///         "|          | +-------+ |\n",
+    ///         "|          | |  2.0  | |\n",
+    ///         "|          | +-------+ |",
+    ///     ),
+    /// );
+    /// # }
+    /// ```
+    pub fn into_table<V>(&self, value: &V) -> Table
+    where
+        V: TreeValue + ?Sized,
+    {
+        plain::into_table(value, self)
+    }
+
+    /// Build a [`PoolTable`], so any of its settings can be applied afterwards.
+    ///
+    /// It recognizes only collapsed mode.
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde_json")] {
+    /// use tabled::settings::Style;
+    /// use tree_to_table::TreeTable;
+    ///
+    /// let value = serde_json::json!({"name": "Rust", "versions": ["1.0", "2.0"]});
+    ///
+    /// let table = TreeTable::new()
+    ///     .into_pool_table(&value)
+    ///     .with(Style::modern())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "┌──────────┬──────┐\n",
+    ///         "│ name     │ Rust │\n",
+    ///         "├──────────┼──────┤\n",
+    ///         "│ versions │ 1.0  │\n",
+    ///         "│          ├──────┤\n",
+    ///         "│          │ 2.0  │\n",
+    ///         "└──────────┴──────┘",
+    ///     ),
+    /// );
+    /// # }
+    /// ```
+    pub fn into_pool_table<V>(&self, value: &V) -> PoolTable
+    where
+        V: TreeValue + ?Sized,
+    {
+        collapsed::into_pool_table(value, self)
+    }
+
+    /// Returns a view of a value at a given depth,
+    /// replacing maps and sequences by a placeholder if the depth is exceeded.
+    fn node<'a, V>(&self, value: &'a V, depth: usize) -> Node<'a, V>
+    where
+        V: TreeValue + ?Sized,
+    {
+        let mut node = value.node();
+        if let (Node::Map(map), Some(keys)) = (&mut node, &self.keys) {
+            keys.apply(map);
+        }
+
+        let is_limited = matches!(self.max_depth, Some(max) if depth >= max);
+        if !is_limited {
+            return node;
+        }
+
+        let text = match node {
+            Node::Map(map) => placeholder('{', '}', map.len(), "key"),
+            Node::Seq(list) => placeholder('[', ']', list.len(), "item"),
+            Node::Tuple(list) => placeholder('(', ')', list.len(), "item"),
+            node => return node,
+        };
+
+        let text = match value.name() {
+            Some(name) => format!("{}{}", name, text),
+            None => text,
+        };
+
+        Node::Scalar(ScalarKind::Other, Cow::Owned(text))
+    }
+
+    /// Verifies whether a value is a number which must be aligned to the right.
+    fn is_right_aligned<V>(&self, value: &V) -> bool
+    where
+        V: TreeValue + ?Sized,
+    {
+        self.right_align_numbers && matches!(value.node(), Node::Scalar(ScalarKind::Number, _))
+    }
+
+    /// Renders a text of a scalar, quoting and coloring it according to a configuration.
+    fn scalar(&self, kind: ScalarKind, text: Cow<'_, str>) -> String {
+        let text = match kind {
+            ScalarKind::String if self.quote_strings => Cow::Owned(quote(&text)),
+            _ => text,
+        };
+
+        self.paint(kind, text)
+    }
+
+    #[cfg_attr(not(feature = "ansi"), allow(unused_variables))]
+    fn paint(&self, kind: ScalarKind, text: Cow<'_, str>) -> String {
+        #[cfg(feature = "ansi")]
+        if let Some(palette) = &self.palette {
+            return palette.paint(kind, &text);
+        }
+
+        text.into_owned()
+    }
+
+    /// Renders a value as a compact JSON like text.
+    fn inline<V>(&self, value: &V, depth: usize) -> String
+    where
+        V: TreeValue + ?Sized,
+    {
+        let (open, close, list) = match self.node(value, depth) {
+            Node::Map(map) => {
+                let list = map
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let key = match key {
+                            Key::Text(key) => quote(&key),
+                            Key::Value(key) => self.inline(key, depth + 1),
+                        };
+
+                        format!("{}:{}", key, self.inline(value, depth + 1))
+                    })
+                    .collect::<Vec<_>>();
+
+                ('{', '}', list)
+            }
+            Node::Seq(list) | Node::Tuple(list) => {
+                let list = list
+                    .into_iter()
+                    .map(|value| self.inline(value, depth + 1))
+                    .collect::<Vec<_>>();
+
+                ('[', ']', list)
+            }
+            Node::Scalar(ScalarKind::String, text) => return quote(&text),
+            Node::Scalar(ScalarKind::Null, text) if text.is_empty() => return String::from("null"),
+            Node::Scalar(_, text) => return text.into_owned(),
+        };
+
+        format!("{}{}{}", open, list.join(","), close)
+    }
+
+    fn paint_key(&self, text: &str) -> String {
+        #[cfg(feature = "ansi")]
+        if let Some(palette) = &self.palette {
+            return palette.paint_key(text);
+        }
+
+        text.to_owned()
+    }
+}
+
+fn quote(text: &str) -> String {
+    format!("{:?}", text)
+}
+
+fn placeholder(open: char, close: char, count: usize, noun: &str) -> String {
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{}…{} {}{}{}", open, count, noun, plural, close)
+}
+
+fn configure_grid() -> CompactMultilineConfig {
+    let pad = Sides::new(
+        Indent::spaced(1),
+        Indent::spaced(1),
+        Indent::default(),
+        Indent::default(),
+    );
+
+    let mut cfg = CompactMultilineConfig::new();
+    cfg.set_padding(pad);
+    cfg.set_alignment_horizontal(AlignmentHorizontal::Left);
+    cfg.set_borders(Borders::from(Style::ascii()));
+
+    cfg
+}
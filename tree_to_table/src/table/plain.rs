@@ -0,0 +1,260 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    grid::{config::SpannedConfig, util::string::string_width},
+    settings::{
+        object::{Cell, Rows},
+        style::LineText,
+        Alignment, Style, Width,
+    },
+    Table,
+};
+
+use crate::{Key, Node, ScalarKind, TreeValue};
+
+use super::{Orientation, TreeTable};
+
+// a title is put after a corner and a single horizontal character
+const TITLE_OFFSET: usize = 2;
+
+pub(super) fn table<V>(value: &V, cfg: &TreeTable) -> String
+where
+    V: TreeValue + ?Sized,
+{
+    _plain_table(value, cfg, 0, true)
+}
+
+pub(super) fn into_table<V>(value: &V, cfg: &TreeTable) -> Table
+where
+    V: TreeValue + ?Sized,
+{
+    _table(value, cfg, 0, true).unwrap_or_default()
+}
+
+fn _plain_table<V>(value: &V, cfg: &TreeTable, depth: usize, outer: bool) -> String
+where
+    V: TreeValue + ?Sized,
+{
+    match _table(value, cfg, depth, outer) {
+        Some(table) => table.to_string(),
+        None => String::new(),
+    }
+}
+
+fn _table<V>(value: &V, cfg: &TreeTable, depth: usize, outer: bool) -> Option<Table>
+where
+    V: TreeValue + ?Sized,
+{
+    let config: SpannedConfig = cfg.cfg.into();
+
+    let mut table = match cfg.node(value, depth) {
+        Node::Seq(list) => match records_table(&list, cfg, depth, &config) {
+            Some(table) => table,
+            None => seq_table(&list, cfg, depth, config),
+        },
+        Node::Map(map) => match cfg.map_orientation {
+            Orientation::Column => map_column_table(&map, cfg, depth, config),
+            Orientation::Row => map_row_table(&map, cfg, depth, config),
+        },
+        Node::Tuple(list) => seq_row_table(&list, cfg, depth, config),
+        Node::Scalar(ScalarKind::Null, text) if text.is_empty() => return None,
+        Node::Scalar(kind, text) => {
+            return Some(string_table(cfg.scalar(kind, text), config, outer))
+        }
+    };
+
+    if let Some(name) = value.name() {
+        set_title(&mut table, &name);
+    }
+
+    Some(table)
+}
+
+fn seq_table<V>(list: &[&V], cfg: &TreeTable, depth: usize, config: SpannedConfig) -> Table
+where
+    V: TreeValue + ?Sized,
+{
+    match cfg.seq_orientation {
+        Orientation::Column => seq_column_table(list, cfg, depth, config),
+        Orientation::Row => seq_row_table(list, cfg, depth, config),
+    }
+}
+
+fn seq_column_table<V>(list: &[&V], cfg: &TreeTable, depth: usize, config: SpannedConfig) -> Table
+where
+    V: TreeValue + ?Sized,
+{
+    let mut buf = Builder::with_capacity(1, 1);
+    for value in list {
+        let val = _plain_table(*value, cfg, depth + 1, false);
+        buf.push_record([val]);
+    }
+
+    let mut table = buf.build();
+    table.with(config);
+
+    let cells = list.iter().enumerate().map(|(i, value)| ((i, 0), *value));
+    align_numbers(&mut table, cells, cfg);
+
+    table
+}
+
+fn seq_row_table<V>(list: &[&V], cfg: &TreeTable, depth: usize, config: SpannedConfig) -> Table
+where
+    V: TreeValue + ?Sized,
+{
+    let mut buf = Vec::with_capacity(list.len());
+    for value in list {
+        let val = _plain_table(*value, cfg, depth + 1, false);
+        buf.push(val);
+    }
+
+    let mut table = Builder::from(vec![buf]).build();
+    table.with(config);
+
+    let cells = list.iter().enumerate().map(|(i, value)| ((0, i), *value));
+    align_numbers(&mut table, cells, cfg);
+
+    table
+}
+
+fn map_column_table<V>(
+    map: &[(Key<'_, V>, &V)],
+    cfg: &TreeTable,
+    depth: usize,
+    config: SpannedConfig,
+) -> Table
+where
+    V: TreeValue + ?Sized,
+{
+    let mut buf = Builder::with_capacity(map.len(), 2);
+    for (key, value) in map {
+        let key = key_string(key, cfg, depth);
+        let val = _plain_table(*value, cfg, depth + 1, false);
+        buf.push_record([key, val]);
+    }
+
+    let mut table = buf.build();
+    table.with(config);
+
+    let cells = map
+        .iter()
+        .enumerate()
+        .map(|(i, (_, value))| ((i, 1), *value));
+    align_numbers(&mut table, cells, cfg);
+
+    table
+}
+
+fn map_row_table<V>(
+    map: &[(Key<'_, V>, &V)],
+    cfg: &TreeTable,
+    depth: usize,
+    config: SpannedConfig,
+) -> Table
+where
+    V: TreeValue + ?Sized,
+{
+    let mut keys = Vec::with_capacity(map.len());
+    let mut vals = Vec::with_capacity(map.len());
+    for (key, value) in map {
+        let val = _plain_table(*value, cfg, depth + 1, false);
+        vals.push(val);
+        keys.push(key_string(key, cfg, depth));
+    }
+
+    let mut table = Builder::from(vec![keys, vals]).build();
+    table.with(config);
+
+    let cells = map
+        .iter()
+        .enumerate()
+        .map(|(i, (_, value))| ((1, i), *value));
+    align_numbers(&mut table, cells, cfg);
+
+    table
+}
+
+fn records_table<V>(
+    list: &[&V],
+    cfg: &TreeTable,
+    depth: usize,
+    config: &SpannedConfig,
+) -> Option<Table>
+where
+    V: TreeValue + ?Sized,
+{
+    let records = cfg.records.as_ref()?;
+    let nodes = list
+        .iter()
+        .map(|value| cfg.node(*value, depth + 1))
+        .collect();
+    let data = records.split(nodes, cfg.keys.as_ref())?;
+
+    let mut buf = Builder::with_capacity(data.rows.len() + 1, data.header.len());
+    buf.push_record(data.header.iter().map(|key| cfg.paint_key(key)));
+
+    let mut scalars = Vec::new();
+    for i in 0..data.rows.len() {
+        let row = data.row(i).enumerate().map(|(col, value)| match value {
+            Some(value) => match cfg.node(value, depth + 2) {
+                Node::Scalar(kind, text) => {
+                    scalars.push(((i + 1, col), value));
+                    cfg.scalar(kind, text)
+                }
+                _ if records.is_inline() => cfg.inline(value, depth + 2),
+                _ => _plain_table(value, cfg, depth + 2, false),
+            },
+            None => records.get_missing().to_owned(),
+        });
+
+        buf.push_record(row.collect::<Vec<_>>());
+    }
+
+    let mut table = buf.build();
+    table.with(config.clone());
+    align_numbers(&mut table, scalars, cfg);
+
+    Some(table)
+}
+
+fn align_numbers<'a, V, I>(table: &mut Table, cells: I, cfg: &TreeTable)
+where
+    V: TreeValue + ?Sized + 'a,
+    I: IntoIterator<Item = ((usize, usize), &'a V)>,
+{
+    for ((row, col), value) in cells {
+        if cfg.is_right_aligned(value) {
+            table.modify(Cell::new(row, col), Alignment::right());
+        }
+    }
+}
+
+fn key_string<V>(key: &Key<'_, V>, cfg: &TreeTable, depth: usize) -> String
+where
+    V: TreeValue + ?Sized,
+{
+    match key {
+        Key::Text(text) => cfg.paint_key(text),
+        Key::Value(key) => _plain_table(*key, cfg, depth + 1, false),
+    }
+}
+
+fn set_title(table: &mut Table, title: &str) {
+    // a table is widened so a title is not cut
+    let width = string_width(title) + TITLE_OFFSET * 2;
+    table.with(Width::increase(width));
+    table.with(LineText::new(title, Rows::first()).offset(TITLE_OFFSET));
+}
+
+fn string_table(val: String, config: SpannedConfig, outer: bool) -> Table {
+    let mut table = Table::from_iter([[val]]);
+    table.with(config);
+
+    if !outer {
+        table.with(Style::empty());
+    }
+
+    table
+}
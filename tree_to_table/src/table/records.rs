@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+
+use crate::{Key, Node, TreeValue};
+
+use super::Keys;
+
+/// A configuration of a records mode,
+/// in which a sequence of maps is rendered as a single table
+/// with a header built out of the maps keys.
+///
+/// A sequence is rendered this way only if it's not empty
+/// and all its elements are maps with text keys.
+///
+/// ```
+/// # #[cfg(feature = "serde_json")] {
+/// use tree_to_table::{Records, TreeTable};
+///
+/// let value = serde_json::json!([
+///     {"host": "alpha", "port": 8080},
+///     {"host": "omega", "port": 8081, "role": "backup"},
+/// ]);
+///
+/// let table = TreeTable::new()
+///     .records(Records::new().missing("-"))
+///     .build(&value);
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+-------+------+--------+\n",
+///         "| host  | port | role   |\n",
+///         "+-------+------+--------+\n",
+///         "| alpha | 8080 | -      |\n",
+///         "+-------+------+--------+\n",
+///         "| omega | 8081 | backup |\n",
+///         "+-------+------+--------+",
+///     ),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Records {
+    sort_keys: bool,
+    inline_nested: bool,
+    missing: String,
+}
+
+impl Records {
+    /// Creates a default configuration.
+    ///
+    /// Keys are ordered by first appearance,
+    /// missing keys are rendered as empty cells,
+    /// nested values are rendered as nested tables.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sort header keys alphabetically instead of ordering them by first appearance.
+    pub fn sort_keys(mut self, on: bool) -> Self {
+        self.sort_keys = on;
+        self
+    }
+
+    /// Render nested maps and sequences as compact JSON like text instead of nested tables.
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde_json")] {
+    /// use tree_to_table::{Records, TreeTable};
+    ///
+    /// let value = serde_json::json!([
+    ///     {"name": "C", "tags": ["static", "weak"]},
+    ///     {"name": "Rust", "tags": ["static", "strong"]},
+    /// ]);
+    ///
+    /// let table = TreeTable::new()
+    ///     .records(Records::new().inline_nested(true))
+    ///     .build(&value);
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+---------------------+\n",
+    ///         "| name | tags                |\n",
+    ///         "+------+---------------------+\n",
+    ///         "| C    | [\"static\",\"weak\"]   |\n",
+    ///         "+------+---------------------+\n",
+    ///         "| Rust | [\"static\",\"strong\"] |\n",
+    ///         "+------+---------------------+",
+    ///     ),
+    /// );
+    /// # }
+    /// ```
+    pub fn inline_nested(mut self, on: bool) -> Self {
+        self.inline_nested = on;
+        self
+    }
+
+    /// Set a text which is used for keys missing in a map.
+    pub fn missing<S>(mut self, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.missing = text.into();
+        self
+    }
+
+    pub(super) fn get_missing(&self) -> &str {
+        &self.missing
+    }
+
+    pub(super) fn is_inline(&self) -> bool {
+        self.inline_nested
+    }
+
+    /// Returns a header and rows of a sequence if it consists of maps with text keys only.
+    ///
+    /// A header is ordered by [`Keys`] if they're set.
+    pub(super) fn split<'a, V>(
+        &self,
+        list: Vec<Node<'a, V>>,
+        keys: Option<&Keys>,
+    ) -> Option<Table<'a, V>>
+    where
+        V: TreeValue + ?Sized,
+    {
+        if list.is_empty() {
+            return None;
+        }
+
+        let mut header: Vec<Cow<'a, str>> = Vec::new();
+        let mut rows = Vec::with_capacity(list.len());
+        for node in list {
+            let map = match node {
+                Node::Map(map) => map,
+                _ => return None,
+            };
+
+            let mut row = Vec::with_capacity(map.len());
+            for (key, value) in map {
+                let key = match key {
+                    Key::Text(key) => key,
+                    Key::Value(_) => return None,
+                };
+
+                if !header.contains(&key) {
+                    header.push(key.clone());
+                }
+
+                row.push((key, value));
+            }
+
+            rows.push(row);
+        }
+
+        if self.sort_keys {
+            header.sort_unstable();
+        }
+
+        if let Some(keys) = keys {
+            keys.order(&mut header, |key| Some(key));
+        }
+
+        Some(Table { header, rows })
+    }
+}
+
+/// A sequence of maps split into a header and rows.
+pub(super) struct Table<'a, V: ?Sized> {
+    pub(super) header: Vec<Cow<'a, str>>,
+    pub(super) rows: Vec<Vec<(Cow<'a, str>, &'a V)>>,
+}
+
+impl<'a, V: ?Sized> Table<'a, V> {
+    /// Returns values of a row in an order of a header,
+    /// where `None` is a missing key.
+    pub(super) fn row(&self, i: usize) -> impl Iterator<Item = Option<&'a V>> + '_ {
+        let row = &self.rows[i];
+        self.header
+            .iter()
+            .map(move |key| row.iter().find(|(k, _)| k == key).map(|(_, value)| *value))
+    }
+}
@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+
+/// A tree like value which can be rendered as a table.
+///
+/// A value is either a map, a sequence or a scalar.
+/// Implement it to render your own value types by [`TreeTable`].
+///
+/// ```
+/// use std::borrow::Cow;
+/// use tree_to_table::{Key, Node, ScalarKind, TreeTable, TreeValue};
+///
+/// enum Tree {
+///     Leaf(u64),
+///     Branch(Vec<Tree>),
+/// }
+///
+/// impl TreeValue for Tree {
+///     fn node(&self) -> Node<'_, Self> {
+///         match self {
+///             Tree::Leaf(n) => Node::Scalar(ScalarKind::Number, Cow::Owned(n.to_string())),
+///             Tree::Branch(list) => Node::Seq(list.iter().collect()),
+///         }
+///     }
+/// }
+///
+/// let tree = Tree::Branch(vec![Tree::Leaf(1), Tree::Branch(vec![Tree::Leaf(2)])]);
+///
+/// let table = TreeTable::new().build(&tree);
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+---------+\n",
+///         "|  1      |\n",
+///         "+---------+\n",
+///         "| +-----+ |\n",
+///         "| |  2  | |\n",
+///         "| +-----+ |\n",
+///         "+---------+",
+///     ),
+/// );
+/// ```
+///
+/// [`TreeTable`]: crate::TreeTable
+pub trait TreeValue {
+    /// Returns a view of the value.
+    fn node(&self) -> Node<'_, Self>;
+
+    /// Returns a name of the value, like a name of a struct.
+    ///
+    /// A name of a map, a sequence or a tuple is rendered as a title of its table.
+    fn name(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+}
+
+/// A view of a [`TreeValue`].
+#[derive(Debug)]
+pub enum Node<'a, V: ?Sized> {
+    /// A list of key value pairs.
+    Map(Vec<(Key<'a, V>, &'a V)>),
+    /// A list of values.
+    Seq(Vec<&'a V>),
+    /// A list of positional values, like fields of a tuple struct,
+    /// which is always rendered as a row.
+    Tuple(Vec<&'a V>),
+    /// A single value with its text.
+    Scalar(ScalarKind, Cow<'a, str>),
+}
+
+/// A key of a [`Node::Map`].
+#[derive(Debug)]
+pub enum Key<'a, V: ?Sized> {
+    /// A key which is rendered as it is.
+    Text(Cow<'a, str>),
+    /// A key which is a value itself,
+    /// so it's rendered the same way as values are.
+    Value(&'a V),
+}
+
+/// A type of a scalar value, which is used to color it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScalarKind {
+    /// An absent value, like `null` or `()`.
+    ///
+    /// An empty null is rendered as an empty cell without an inner table.
+    Null,
+    /// A boolean.
+    Boolean,
+    /// An integer or a float.
+    Number,
+    /// A string or a character.
+    String,
+    /// Any other value, like a datetime.
+    Other,
+}
@@ -0,0 +1,244 @@
+use std::borrow::Cow;
+
+use testing_table::test_table;
+use tree_to_table::{Key, Keys, Node, Orientation, Records, ScalarKind, TreeTable, TreeValue};
+
+test_table!(
+    plain_test,
+    TreeTable::new().build(&tree()),
+    "+----------+----------------+"
+    "| name     |  Rust          |"
+    "+----------+----------------+"
+    "| versions | +-----+        |"
+    "|          | |  1  |        |"
+    "|          | +-----+        |"
+    "|          | |  2  |        |"
+    "|          | +-----+        |"
+    "+----------+----------------+"
+    "| owner    |                |"
+    "+----------+----------------+"
+    "| team     | +------+-----+ |"
+    "|          | | lead |  -  | |"
+    "|          | +------+-----+ |"
+    "+----------+----------------+"
+);
+
+test_table!(
+    collapsed_test,
+    TreeTable::new().collapse().build(&tree()),
+    "+----------+----------+"
+    "| name     | Rust     |"
+    "+----------+----------+"
+    "| versions | 1        |"
+    "|          +----------+"
+    "|          | 2        |"
+    "+----------+----------+"
+    "| owner    |          |"
+    "+----------+------+---+"
+    "| team     | lead | - |"
+    "+----------+------+---+"
+);
+
+test_table!(
+    collapsed_row_orientation_test,
+    TreeTable::new()
+        .map_orientation(Orientation::Row)
+        .seq_orientation(Orientation::Row)
+        .collapse()
+        .build(&tree()),
+    "+------+----------+-------+------+"
+    "| name | versions | owner | team |"
+    "+------+---+------+-------+------+"
+    "| Rust | 1 | 2    |       | lead |"
+    "|      |   |      |       +------+"
+    "|      |   |      |       | -    |"
+    "+------+---+------+-------+------+"
+);
+
+test_table!(
+    max_depth_test,
+    TreeTable::new().max_depth(1).build(&tree()),
+    "+----------+--------------+"
+    "| name     |  Rust        |"
+    "+----------+--------------+"
+    "| versions |  […2 items]  |"
+    "+----------+--------------+"
+    "| owner    |              |"
+    "+----------+--------------+"
+    "| team     |  {…1 key}    |"
+    "+----------+--------------+"
+);
+
+test_table!(
+    value_keys_test,
+    TreeTable::new().build(&value_keys()),
+    "+---------+--------------+"
+    "|  1      |  one         |"
+    "+---------+--------------+"
+    "| +-----+ |  two, three  |"
+    "| |  2  | |              |"
+    "| +-----+ |              |"
+    "| |  3  | |              |"
+    "| +-----+ |              |"
+    "+---------+--------------+"
+);
+
+test_table!(
+    value_keys_collapsed_test,
+    TreeTable::new().collapse().build(&value_keys()),
+    "+---+------------+"
+    "| 1 | one        |"
+    "+---+------------+"
+    "| 2 | two, three |"
+    "+---+            |"
+    "| 3 |            |"
+    "+---+------------+"
+);
+
+test_table!(
+    records_test,
+    TreeTable::new().records(Records::new()).build(&records()),
+    "+-----------+-------+--------+"
+    "| ports     | name  | role   |"
+    "+-----------+-------+--------+"
+    "| +-------+ | alpha |        |"
+    "| |  80   | |       |        |"
+    "| +-------+ |       |        |"
+    "| |  443  | |       |        |"
+    "| +-------+ |       |        |"
+    "+-----------+-------+--------+"
+    "|           | omega | backup |"
+    "+-----------+-------+--------+"
+);
+
+test_table!(
+    records_collapsed_test,
+    TreeTable::new()
+        .records(Records::new().missing("-").sort_keys(true))
+        .collapse()
+        .build(&records()),
+    "+-------+-------+--------+"
+    "| name  | ports | role   |"
+    "+-------+-------+--------+"
+    "| alpha | 80    | -      |"
+    "|       +-------+        |"
+    "|       | 443   |        |"
+    "+-------+-------+--------+"
+    "| omega |       | backup |"
+    "+-------+-------+--------+"
+);
+
+test_table!(
+    records_value_keys_test,
+    TreeTable::new()
+        .records(Records::new())
+        .collapse()
+        .build(&Value::List(vec![value_keys()])),
+    "+---+------------+"
+    "| 1 | one        |"
+    "+---+------------+"
+    "| 2 | two, three |"
+    "+---+            |"
+    "| 3 |            |"
+    "+---+------------+"
+);
+
+test_table!(
+    records_keys_test,
+    TreeTable::new()
+        .records(Records::new().inline_nested(true))
+        .keys(Keys::new().priority(["name"]).exclude(["r*"]))
+        .quote_strings(true)
+        .build(&records()),
+    "+---------+----------+"
+    "| name    | ports    |"
+    "+---------+----------+"
+    "| \"alpha\" | [80,443] |"
+    "+---------+----------+"
+    "| \"omega\" | []       |"
+    "+---------+----------+"
+);
+
+test_table!(
+    keys_value_keys_test,
+    TreeTable::new()
+        .keys(Keys::new().sort(true).exclude(["*"]))
+        .collapse()
+        .build(&value_keys()),
+    "+---+------------+"
+    "| 1 | one        |"
+    "+---+------------+"
+    "| 2 | two, three |"
+    "+---+            |"
+    "| 3 |            |"
+    "+---+------------+"
+);
+
+enum Value {
+    Number(i64),
+    Text(&'static str),
+    Nil,
+    List(Vec<Value>),
+    Map(Vec<(&'static str, Value)>),
+    KeyedMap(Vec<(Value, Value)>),
+}
+
+impl TreeValue for Value {
+    fn node(&self) -> Node<'_, Self> {
+        match self {
+            Value::Number(n) => Node::Scalar(ScalarKind::Number, Cow::Owned(n.to_string())),
+            Value::Text(text) => Node::Scalar(ScalarKind::String, Cow::Borrowed(text)),
+            Value::Nil => Node::Scalar(ScalarKind::Null, Cow::Borrowed("")),
+            Value::List(list) => Node::Seq(list.iter().collect()),
+            Value::Map(map) => Node::Map(
+                map.iter()
+                    .map(|(key, value)| (Key::Text(Cow::Borrowed(*key)), value))
+                    .collect(),
+            ),
+            Value::KeyedMap(map) => Node::Map(
+                map.iter()
+                    .map(|(key, value)| (Key::Value(key), value))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn tree() -> Value {
+    Value::Map(vec![
+        ("name", Value::Text("Rust")),
+        (
+            "versions",
+            Value::List(vec![Value::Number(1), Value::Number(2)]),
+        ),
+        ("owner", Value::Nil),
+        ("team", Value::Map(vec![("lead", Value::Text("-"))])),
+    ])
+}
+
+fn value_keys() -> Value {
+    Value::KeyedMap(vec![
+        (Value::Number(1), Value::Text("one")),
+        (
+            Value::List(vec![Value::Number(2), Value::Number(3)]),
+            Value::Text("two, three"),
+        ),
+    ])
+}
+
+fn records() -> Value {
+    Value::List(vec![
+        Value::Map(vec![
+            (
+                "ports",
+                Value::List(vec![Value::Number(80), Value::Number(443)]),
+            ),
+            ("name", Value::Text("alpha")),
+        ]),
+        Value::Map(vec![
+            ("name", Value::Text("omega")),
+            ("role", Value::Text("backup")),
+            ("ports", Value::List(vec![])),
+        ]),
+    ])
+}